@@ -1,7 +1,7 @@
 use serde::Deserialize;
 
 /// The available attributes that can be returned by the API.
-#[derive(Debug, PartialEq, Deserialize, Hash, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Hash, Eq)]
 pub enum Attribute {
     AgonyResistance,
     // Also known as Concentration
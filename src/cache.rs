@@ -0,0 +1,613 @@
+//! Caching of responses, so that static data such as `/v1/files` or `/v2/items` is not downloaded
+//! again every time it is requested. Responses are only cached for as long as the API allows
+//! through its `Cache-Control` and `Expires` headers, and every cached response is dropped when
+//! the API reports a new game build.
+use serde::{Deserialize, Serialize};
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::endpoint::Endpoint;
+use crate::v2::build::Build;
+
+/// Storage for responses, set on a client with `Client::set_cache`. Implementations only have to
+/// store what they are given; deciding what may be cached and for how long is up to the client.
+pub trait Cache: Send + Sync {
+    /// Returns the response stored for the key, if any. Expired responses may be returned, the
+    /// client ignores them.
+    fn get(&self, key: &CacheKey) -> Option<CachedResponse>;
+    /// Stores the response for the key, replacing any previous one.
+    fn insert(&self, key: CacheKey, response: CachedResponse);
+    /// Removes every stored response.
+    fn clear(&self);
+    /// Returns the game build the stored responses belong to.
+    fn build_id(&self) -> Option<u32>;
+    /// Remembers the game build the stored responses belong to.
+    fn set_build_id(&self, build_id: u32);
+}
+
+impl<C: Cache + ?Sized> Cache for Arc<C> {
+    fn get(&self, key: &CacheKey) -> Option<CachedResponse> {
+        (**self).get(key)
+    }
+
+    fn insert(&self, key: CacheKey, response: CachedResponse) {
+        (**self).insert(key, response)
+    }
+
+    fn clear(&self) {
+        (**self).clear()
+    }
+
+    fn build_id(&self) -> Option<u32> {
+        (**self).build_id()
+    }
+
+    fn set_build_id(&self, build_id: u32) {
+        (**self).set_build_id(build_id)
+    }
+}
+
+/// Identifies a response by the server, url, language, schema version and API key it was
+/// requested with. The API key is only kept as a hash, so that it does not end up on disk.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    base_url: String,
+    url: String,
+    lang: String,
+    schema_version: Option<String>,
+    api_key: Option<u64>,
+}
+
+impl CacheKey {
+    pub(crate) fn new(base_url: &str, url: &str, lang: &str, schema_version: Option<&str>,
+        api_key: Option<&str>) -> CacheKey {
+        CacheKey {
+            base_url: base_url.to_string(),
+            url: url.to_string(),
+            lang: lang.to_string(),
+            schema_version: schema_version.map(String::from),
+            api_key: api_key.map(|key| fnv1a(key.as_bytes())),
+        }
+    }
+
+    /// Base url of the server the request was sent to, e.g. `https://api.guildwars2.com`.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Url of the request, including the version but not the base url.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Language suffix the request was made with, e.g. `en`.
+    pub fn lang(&self) -> &str {
+        &self.lang
+    }
+
+    /// Value of the `X-Schema-Version` header the request was made with, if any.
+    pub fn schema_version(&self) -> Option<&str> {
+        self.schema_version.as_deref()
+    }
+
+    /// A hash of the key that stays the same between runs, suitable for use as a file name.
+    pub fn digest(&self) -> u64 {
+        let schema_version = self.schema_version.as_deref().unwrap_or_default();
+        let api_key = self.api_key.map(|key| key.to_string()).unwrap_or_default();
+        let key = format!("{}\0{}\0{}\0{}\0{}", self.base_url, self.url, self.lang,
+            schema_version, api_key);
+        fnv1a(key.as_bytes())
+    }
+}
+
+/// A response of the API, as it is passed around by the clients and stored by a `Cache`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedResponse {
+    /// HTTP status code of the response.
+    pub status: u16,
+    /// Headers of the response, with lowercase names.
+    pub headers: HashMap<String, String>,
+    /// The body of the response.
+    pub body: Vec<u8>,
+    /// Until when the response may be served from a cache.
+    pub expires: SystemTime,
+}
+
+impl CachedResponse {
+    /// Creates a response, working out how long it may be cached from its headers.
+    pub(crate) fn new(status: u16, headers: HashMap<String, String>, body: Vec<u8>)
+        -> CachedResponse {
+        let now = SystemTime::now();
+        let expires = expires_at(&headers, now).unwrap_or(now);
+        CachedResponse { status, headers, body, expires }
+    }
+
+    /// Returns the value of the header with the given (lowercase) name.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(String::as_str)
+    }
+
+    /// Whether the response may still be served from a cache.
+    pub fn is_fresh(&self) -> bool {
+        self.expires > SystemTime::now()
+    }
+}
+
+/// Returns a fresh response for the key, if the cache has one.
+pub(crate) fn lookup(cache: Option<&dyn Cache>, key: &CacheKey) -> Option<CachedResponse> {
+    if key.url == Build::URL {
+        return None;
+    }
+    cache?.get(key).filter(CachedResponse::is_fresh)
+}
+
+/// Stores the response if the API allows it to be cached. Responses of `v2::build::Build` are
+/// never cached, instead they clear the cache if the build id changed.
+pub(crate) fn store(cache: Option<&dyn Cache>, key: CacheKey, response: &CachedResponse) {
+    let cache = match cache {
+        Some(cache) if response.status == 200 || response.status == 206 => cache,
+        _ => return,
+    };
+
+    if key.url == Build::URL {
+        if let Ok(build) = serde_json::from_slice::<Build>(&response.body) {
+            if cache.build_id() != Some(build.id) {
+                cache.clear();
+                cache.set_build_id(build.id);
+            }
+        }
+    } else if response.is_fresh() {
+        cache.insert(key, response.clone());
+    }
+}
+
+/// A cache that keeps up to `capacity` responses in memory, dropping the least recently used one
+/// when it is full.
+#[derive(Debug)]
+pub struct MemoryCache {
+    capacity: usize,
+    state: Mutex<MemoryState>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryState {
+    /// The responses along with when they were last used.
+    entries: HashMap<CacheKey, (u64, CachedResponse)>,
+    /// The keys of `entries`, ordered from least to most recently used.
+    recency: BTreeMap<u64, CacheKey>,
+    /// Incremented on every use of an entry.
+    tick: u64,
+    build_id: Option<u32>,
+}
+
+impl MemoryCache {
+    /// Creates an empty cache that holds at most `capacity` responses.
+    pub fn new(capacity: usize) -> MemoryCache {
+        MemoryCache { capacity, state: Mutex::new(MemoryState::default()) }
+    }
+
+    /// Amount of responses currently in the cache.
+    pub fn len(&self) -> usize {
+        self.state().entries.len()
+    }
+
+    /// Whether the cache holds no responses.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, MemoryState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Cache for MemoryCache {
+    fn get(&self, key: &CacheKey) -> Option<CachedResponse> {
+        let mut state = self.state();
+        state.tick += 1;
+        let tick = state.tick;
+        let (used, response) = state.entries.get_mut(key)?;
+        let previous = std::mem::replace(used, tick);
+        let response = response.clone();
+        state.recency.remove(&previous);
+        state.recency.insert(tick, key.clone());
+        Some(response)
+    }
+
+    fn insert(&self, key: CacheKey, response: CachedResponse) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut state = self.state();
+        state.tick += 1;
+        let tick = state.tick;
+        if let Some((previous, _)) = state.entries.insert(key.clone(), (tick, response)) {
+            state.recency.remove(&previous);
+        }
+        state.recency.insert(tick, key);
+
+        while state.entries.len() > self.capacity {
+            let oldest = match state.recency.pop_first() {
+                Some((_, oldest)) => oldest,
+                None => break,
+            };
+            state.entries.remove(&oldest);
+        }
+    }
+
+    fn clear(&self) {
+        let mut state = self.state();
+        state.entries.clear();
+        state.recency.clear();
+    }
+
+    fn build_id(&self) -> Option<u32> {
+        self.state().build_id
+    }
+
+    fn set_build_id(&self, build_id: u32) {
+        self.state().build_id = Some(build_id);
+    }
+}
+
+/// A cache that stores every response as a file in a directory, so that it survives restarts of
+/// the program. Failing to read or write a file is treated as a cache miss.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+}
+
+/// Extension of the files holding the cached responses.
+const EXTENSION: &str = "cache";
+/// Name of the file holding the build id of the cached responses.
+const BUILD_ID_FILE: &str = "build_id";
+/// Number of the next temporary file written by this process.
+static NEXT_TMP: AtomicU64 = AtomicU64::new(0);
+
+/// Everything of a `CachedResponse` apart from the body, written as a line of json in front of the
+/// body.
+#[derive(Serialize, Deserialize)]
+struct Metadata {
+    /// The fields of the `CacheKey`, since different keys may share a digest and so a file.
+    base_url: String,
+    url: String,
+    lang: String,
+    schema_version: Option<String>,
+    api_key: Option<u64>,
+    status: u16,
+    headers: HashMap<String, String>,
+    /// Seconds since the unix epoch.
+    expires: u64,
+}
+
+impl DiskCache {
+    /// Creates a cache that stores its files in `dir`, creating the directory if needed.
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<DiskCache> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(DiskCache { dir })
+    }
+
+    /// The directory the responses are stored in.
+    pub fn dir(&self) -> &std::path::Path {
+        &self.dir
+    }
+
+    fn path(&self, key: &CacheKey) -> PathBuf {
+        self.dir.join(format!("{:016x}.{}", key.digest(), EXTENSION))
+    }
+
+    fn read(&self, key: &CacheKey) -> Option<CachedResponse> {
+        let contents = fs::read(self.path(key)).ok()?;
+        let split = contents.iter().position(|&b| b == b'\n')?;
+        let metadata: Metadata = serde_json::from_slice(&contents[..split]).ok()?;
+        let stored_key = CacheKey {
+            base_url: metadata.base_url,
+            url: metadata.url,
+            lang: metadata.lang,
+            schema_version: metadata.schema_version,
+            api_key: metadata.api_key,
+        };
+        if stored_key != *key {
+            return None;
+        }
+        Some(CachedResponse {
+            status: metadata.status,
+            headers: metadata.headers,
+            body: contents[split + 1..].to_vec(),
+            expires: UNIX_EPOCH + Duration::from_secs(metadata.expires),
+        })
+    }
+
+    fn write(&self, key: &CacheKey, response: CachedResponse) -> io::Result<()> {
+        let metadata = Metadata {
+            base_url: key.base_url.clone(),
+            url: key.url.clone(),
+            lang: key.lang.clone(),
+            schema_version: key.schema_version.clone(),
+            api_key: key.api_key,
+            status: response.status,
+            headers: response.headers,
+            expires: response.expires.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs(),
+        };
+        let mut contents = serde_json::to_vec(&metadata)?;
+        contents.push(b'\n');
+        contents.extend_from_slice(&response.body);
+
+        // Write to a temporary file first, so that readers never see half a response. Every write
+        // gets its own file, so that concurrent writers of the same key do not mix their responses
+        let path = self.path(key);
+        let tmp = path.with_extension(format!("{}.{}.tmp", std::process::id(),
+            NEXT_TMP.fetch_add(1, Ordering::Relaxed)));
+        if let Err(err) = fs::write(&tmp, contents).and_then(|()| fs::rename(&tmp, path)) {
+            let _ = fs::remove_file(tmp);
+            return Err(err);
+        }
+        Ok(())
+    }
+}
+
+impl Cache for DiskCache {
+    fn get(&self, key: &CacheKey) -> Option<CachedResponse> {
+        let response = self.read(key);
+        if response.is_none() {
+            let _ = fs::remove_file(self.path(key));
+        }
+        response
+    }
+
+    fn insert(&self, key: CacheKey, response: CachedResponse) {
+        let _ = self.write(&key, response);
+    }
+
+    fn clear(&self) {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+        for path in entries.filter_map(|entry| entry.ok()).map(|entry| entry.path()) {
+            if path.extension().is_some_and(|extension| extension == EXTENSION) {
+                let _ = fs::remove_file(path);
+            }
+        }
+    }
+
+    fn build_id(&self) -> Option<u32> {
+        fs::read_to_string(self.dir.join(BUILD_ID_FILE)).ok()?.trim().parse().ok()
+    }
+
+    fn set_build_id(&self, build_id: u32) {
+        let _ = fs::write(self.dir.join(BUILD_ID_FILE), build_id.to_string());
+    }
+}
+
+/// Works out until when a response may be cached from its `Cache-Control` header, or its
+/// `Expires` header if there is none. Returns `None` if it may not be cached at all.
+fn expires_at(headers: &HashMap<String, String>, now: SystemTime) -> Option<SystemTime> {
+    let header = |name: &str| headers.get(name).map(String::as_str);
+
+    if let Some(cache_control) = header("cache-control") {
+        let mut max_age = None;
+        for directive in cache_control.split(',').map(str::trim) {
+            let directive = directive.to_ascii_lowercase();
+            if directive == "no-store" || directive == "no-cache" {
+                return None;
+            }
+            if let Some(secs) = directive.strip_prefix("max-age=") {
+                max_age = secs.trim_matches('"').parse::<u64>().ok();
+            }
+        }
+        if let Some(max_age) = max_age {
+            // The response may already have spent some time in a cache on the way
+            let age = header("age").and_then(|age| age.trim().parse().ok()).unwrap_or(0);
+            return max_age.checked_sub(age)
+                .filter(|&ttl| ttl > 0)
+                .map(|ttl| now + Duration::from_secs(ttl));
+        }
+    }
+
+    let expires = parse_http_date(header("expires")?)?;
+    // Measure against the clock of the server, if it told us what time it was
+    let date = header("date").and_then(parse_http_date).unwrap_or(now);
+    expires.duration_since(date).ok()
+        .filter(|ttl| !ttl.is_zero())
+        .map(|ttl| now + ttl)
+}
+
+/// Parses a date in the format used by HTTP headers, e.g. `Sun, 06 Nov 1994 08:49:37 GMT`.
+fn parse_http_date(date: &str) -> Option<SystemTime> {
+    const MONTHS: [&str; 12] =
+        ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+    let parts: Vec<&str> = date.split_whitespace().collect();
+    let (day, month, year, time) = match parts.as_slice() {
+        [_, day, month, year, time, "GMT"] => (day, month, year, time),
+        _ => return None,
+    };
+    let day: u64 = day.parse().ok()?;
+    let month = MONTHS.iter().position(|m| m == month)? as u64 + 1;
+    let year: u64 = year.parse().ok()?;
+    let mut time = time.split(':').map(|part| part.parse::<u64>().ok());
+    let (hours, minutes, seconds) = (time.next()??, time.next()??, time.next()??);
+    if year < 1970 || !(1..=31).contains(&day) || hours > 23 || minutes > 59 || seconds > 60 {
+        return None;
+    }
+
+    // Days since the unix epoch, see http://howardhinnant.github.io/date_algorithms.html
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y / 400;
+    let year_of_era = y - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = (era * 146_097 + day_of_era).checked_sub(719_468)?;
+
+    Some(UNIX_EPOCH + Duration::from_secs(days * 86_400 + hours * 3600 + minutes * 60 + seconds))
+}
+
+/// 64-bit FNV-1a, used instead of the `DefaultHasher` since its output has to stay the same
+/// between runs and Rust versions.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cache::*;
+    use crate::client::BASE_URL;
+
+    fn headers(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect()
+    }
+
+    fn response(body: &str) -> CachedResponse {
+        let headers = headers(&[("cache-control", "public, max-age=300")]);
+        CachedResponse::new(200, headers, body.as_bytes().to_vec())
+    }
+
+    fn key(url: &str) -> CacheKey {
+        CacheKey::new(BASE_URL, url, "en", None, None)
+    }
+
+    #[test]
+    fn parse_dates() {
+        let date = parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT").unwrap();
+        assert_eq!(784_111_777, date.duration_since(UNIX_EPOCH).unwrap().as_secs());
+        assert_eq!(None, parse_http_date("0"));
+        assert_eq!(None, parse_http_date("Sun, 06 Foo 1994 08:49:37 GMT"));
+    }
+
+    #[test]
+    fn freshness_from_headers() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let secs = |headers| expires_at(&headers, now)
+            .map(|expires| expires.duration_since(now).unwrap().as_secs());
+
+        assert_eq!(Some(300), secs(headers(&[("cache-control", "public, max-age=300")])));
+        assert_eq!(Some(240), secs(headers(&[("cache-control", "max-age=300"), ("age", "60")])));
+        assert_eq!(None, secs(headers(&[("cache-control", "no-cache")])));
+        assert_eq!(None, secs(headers(&[("cache-control", "max-age=0")])));
+        assert_eq!(Some(60), secs(headers(&[
+            ("expires", "Sun, 06 Nov 1994 08:50:37 GMT"),
+            ("date", "Sun, 06 Nov 1994 08:49:37 GMT"),
+        ])));
+        assert_eq!(None, secs(headers(&[("expires", "-1")])));
+        assert_eq!(None, secs(HashMap::new()));
+    }
+
+    #[test]
+    fn request_settings_change_the_key() {
+        let anonymous = key("/v2/account");
+        let authenticated = CacheKey::new(BASE_URL, "/v2/account", "en", None, Some("key"));
+        assert_ne!(anonymous, authenticated);
+        assert_ne!(anonymous.digest(), authenticated.digest());
+        let german = CacheKey::new(BASE_URL, "/v2/items", "de", None, None);
+        assert_ne!(key("/v2/items").digest(), german.digest());
+        let schema_version = Some("2019-12-19T00:00:00.000Z");
+        let schema = CacheKey::new(BASE_URL, "/v2/items", "en", schema_version, None);
+        assert_ne!(key("/v2/items").digest(), schema.digest());
+        let proxy = CacheKey::new("http://localhost:8080", "/v2/items", "en", None, None);
+        assert_ne!(key("/v2/items"), proxy);
+        assert_ne!(key("/v2/items").digest(), proxy.digest());
+    }
+
+    #[test]
+    fn memory_cache_evicts_least_recently_used() {
+        let cache = MemoryCache::new(2);
+        cache.insert(key("/a"), response("a"));
+        cache.insert(key("/b"), response("b"));
+        cache.get(&key("/a")).unwrap();
+        cache.insert(key("/c"), response("c"));
+
+        assert_eq!(2, cache.len());
+        assert!(cache.get(&key("/b")).is_none());
+        assert_eq!(b"a".to_vec(), cache.get(&key("/a")).unwrap().body);
+        assert_eq!(b"c".to_vec(), cache.get(&key("/c")).unwrap().body);
+    }
+
+    #[test]
+    fn new_build_clears_cache() {
+        let cache = MemoryCache::new(10);
+        let build = |id: u32| {
+            CachedResponse::new(200, HashMap::new(), format!(r#"{{"id": {}}}"#, id).into_bytes())
+        };
+        store(Some(&cache), key("/v2/items"), &response("[]"));
+        store(Some(&cache), key(Build::URL), &build(1));
+        assert_eq!(Some(1), cache.build_id());
+        assert!(cache.is_empty());
+
+        store(Some(&cache), key("/v2/items"), &response("[]"));
+        store(Some(&cache), key(Build::URL), &build(1));
+        assert!(lookup(Some(&cache), &key("/v2/items")).is_some());
+        store(Some(&cache), key(Build::URL), &build(2));
+        assert!(lookup(Some(&cache), &key("/v2/items")).is_none());
+    }
+
+    #[test]
+    fn disk_cache_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("gw2api-cache-test-{}", std::process::id()));
+        let cache = DiskCache::new(&dir).unwrap();
+        cache.insert(key("/v1/files"), response(r#"{"map_complete": {}}"#));
+        cache.set_build_id(115267);
+
+        let reopened = DiskCache::new(&dir).unwrap();
+        assert_eq!(Some(115267), reopened.build_id());
+        let cached = reopened.get(&key("/v1/files")).unwrap();
+        assert_eq!(br#"{"map_complete": {}}"#.to_vec(), cached.body);
+        assert_eq!(Some("public, max-age=300"), cached.header("cache-control"));
+        assert!(cached.is_fresh());
+
+        reopened.clear();
+        assert!(reopened.get(&key("/v1/files")).is_none());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn disk_cache_concurrent_writes() {
+        let dir = std::env::temp_dir()
+            .join(format!("gw2api-cache-concurrent-test-{}", std::process::id()));
+        let cache = Arc::new(DiskCache::new(&dir).unwrap());
+        let bodies: Vec<String> =
+            (0..8).map(|i| format!("[{}0]", "1,".repeat(i * 1000))).collect();
+        let writers: Vec<_> = bodies.iter().cloned().map(|body| {
+            let cache = Arc::clone(&cache);
+            std::thread::spawn(move || {
+                for _ in 0..10 {
+                    cache.write(&key("/v2/items"), response(&body)).unwrap();
+                }
+            })
+        }).collect();
+        for writer in writers {
+            writer.join().unwrap();
+        }
+
+        let cached = cache.get(&key("/v2/items")).unwrap();
+        assert!(bodies.iter().any(|body| body.as_bytes() == cached.body.as_slice()));
+        let leftover = fs::read_dir(&dir).unwrap()
+            .filter(|entry| entry.as_ref().unwrap().path().extension().is_some_and(|e| e == "tmp"))
+            .count();
+        assert_eq!(0, leftover);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn disk_cache_checks_key() {
+        let dir = std::env::temp_dir()
+            .join(format!("gw2api-cache-key-test-{}", std::process::id()));
+        let cache = DiskCache::new(&dir).unwrap();
+        cache.insert(key("/v2/items"), response("[]"));
+
+        // Pretend the digests of both keys collide
+        fs::copy(cache.path(&key("/v2/items")), cache.path(&key("/v2/skins"))).unwrap();
+        assert!(cache.get(&key("/v2/skins")).is_none());
+        assert!(cache.get(&key("/v2/items")).is_some());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
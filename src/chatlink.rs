@@ -0,0 +1,661 @@
+//! Encoding and decoding of in-game chat links such as `[&AgEAWgAA]`. A chat link is a base64
+//! encoded blob wrapped in `[&` and `]`, whose first byte tells what kind of object it links to.
+//!
+//! ```
+//! use gw2api::chatlink::{ChatLink, ItemLink};
+//! use gw2api::coins::Coins;
+//!
+//! let link: ChatLink = "[&AdsnAAA=]".parse().unwrap();
+//! assert_eq!(ChatLink::Coins(Coins::from_copper(10203)), link);
+//!
+//! let item = ChatLink::Item(ItemLink::new(46762));
+//! assert_eq!("[&AgGqtgAA]", item.to_string());
+//! ```
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::coins::Coins;
+use crate::utils::Profession;
+
+const COINS: u8 = 0x01;
+const ITEM: u8 = 0x02;
+const NPC_TEXT: u8 = 0x03;
+const MAP: u8 = 0x04;
+const SKILL: u8 = 0x06;
+const TRAIT: u8 = 0x07;
+const RECIPE: u8 = 0x09;
+const SKIN: u8 = 0x0A;
+const OUTFIT: u8 = 0x0B;
+const WVW_OBJECTIVE: u8 = 0x0C;
+const BUILD_TEMPLATE: u8 = 0x0D;
+
+const ITEM_SKIN: u8 = 0x80;
+const ITEM_UPGRADE: u8 = 0x40;
+const ITEM_SECOND_UPGRADE: u8 = 0x20;
+const ITEM_UPGRADE_SLOTS: [u8; 2] = [ITEM_UPGRADE, ITEM_SECOND_UPGRADE];
+
+/// A decoded chat link. The ids can be passed to the matching endpoints, e.g. the id of
+/// `ChatLink::Recipe` to `v1::recipe_details::Recipe::get_id`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChatLink {
+    /// An amount of coins.
+    Coins(Coins),
+    /// A stack of items.
+    Item(ItemLink),
+    /// Id of an NPC dialogue text.
+    NpcText(u32),
+    /// Id of a point of interest, waypoint or vista, see `v1::map_floor::Poi`.
+    Map(u32),
+    /// Id of a skill.
+    Skill(u32),
+    /// Id of a trait.
+    Trait(u32),
+    /// Id of a recipe.
+    Recipe(u32),
+    /// Id of a skin, resolvable against `/v2/skins`.
+    Skin(u32),
+    /// Id of an outfit, resolvable against `/v2/outfits`.
+    Outfit(u32),
+    /// A World vs. World objective.
+    WvwObjective(WvwObjectiveLink),
+    /// A build template.
+    BuildTemplate(BuildTemplate),
+}
+
+/// A stack of items, along with the skin and upgrades applied to them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemLink {
+    /// Item id, resolvable against `/v2/items`. Only the lower 24 bits fit into a chat link.
+    pub id: u32,
+    /// Amount of items in the stack.
+    pub quantity: u8,
+    /// Id of the skin applied to the item.
+    pub skin: Option<u32>,
+    /// Item ids of the upgrade components in the first and second upgrade slot of the item.
+    pub upgrades: [Option<u32>; 2],
+}
+
+impl ItemLink {
+    /// A single item without skin or upgrades.
+    pub fn new(id: u32) -> ItemLink {
+        ItemLink {
+            id,
+            quantity: 1,
+            skin: None,
+            upgrades: [None; 2],
+        }
+    }
+}
+
+/// A World vs. World objective, identified by its map and its id on that map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WvwObjectiveLink {
+    pub map_id: u32,
+    pub objective_id: u32,
+}
+
+impl WvwObjectiveLink {
+    /// The id of the objective as used by `/v2/wvw/objectives`, e.g. `38-6`.
+    pub fn id(&self) -> String {
+        format!("{}-{}", self.map_id, self.objective_id)
+    }
+}
+
+/// A build template as shared through chat. Skills are given as skill palette ids and traits as
+/// the position of the chosen trait, since that is what the chat link stores.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BuildTemplate {
+    pub profession: Profession,
+    /// The three specializations of the build.
+    pub specializations: [TemplateSpecialization; 3],
+    /// Skill palette ids of the heal, three utility and elite skills, alternating between the
+    /// terrestrial and the aquatic skill.
+    pub skills: [u16; 10],
+    /// Pets for rangers or legends for revenants, zero for other professions.
+    pub profession_data: [u8; 16],
+    /// Weapon types picked through weapon mastery.
+    pub weapons: Vec<u16>,
+    /// Ids of skills that replace the defaults of weapons.
+    pub skill_overrides: Vec<u32>,
+}
+
+/// A specialization of a build template.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TemplateSpecialization {
+    /// Specialization id, resolvable against `/v2/specializations`, 0 if unset.
+    pub id: u8,
+    /// The chosen adept, master and grandmaster traits: 1 for the top, 2 for the middle and 3 for
+    /// the bottom trait, 0 if none is chosen.
+    pub traits: [u8; 3],
+}
+
+impl BuildTemplate {
+    /// Skill palette ids of the terrestrial heal, utility and elite skills.
+    pub fn terrestrial_skills(&self) -> [u16; 5] {
+        std::array::from_fn(|i| self.skills[2 * i])
+    }
+
+    /// Skill palette ids of the aquatic heal, utility and elite skills.
+    pub fn aquatic_skills(&self) -> [u16; 5] {
+        std::array::from_fn(|i| self.skills[2 * i + 1])
+    }
+
+    /// Pet ids of a ranger: the two terrestrial pets, then the two aquatic ones.
+    pub fn pets(&self) -> Option<[u8; 4]> {
+        match self.profession {
+            Profession::Ranger => Some([
+                self.profession_data[0],
+                self.profession_data[1],
+                self.profession_data[2],
+                self.profession_data[3],
+            ]),
+            _ => None,
+        }
+    }
+
+    /// Legend codes of a revenant: the active and inactive terrestrial legends, then the aquatic
+    /// ones.
+    pub fn legends(&self) -> Option<[u8; 4]> {
+        match self.profession {
+            Profession::Revenant => Some([
+                self.profession_data[0],
+                self.profession_data[1],
+                self.profession_data[2],
+                self.profession_data[3],
+            ]),
+            _ => None,
+        }
+    }
+}
+
+/// Why a string could not be decoded as a chat link.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChatLinkError {
+    /// The string is not wrapped in `[&` and `]`.
+    MissingBrackets,
+    /// The content is not valid base64.
+    InvalidBase64,
+    /// The link is empty or ends before all of its fields.
+    TooShort,
+    /// The first byte does not name a supported kind of link.
+    UnknownType(u8),
+    /// The build template names a profession that does not exist.
+    UnknownProfession(u8),
+}
+
+impl fmt::Display for ChatLinkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingBrackets => f.write_str("chat link is not wrapped in [& and ]"),
+            Self::InvalidBase64 => f.write_str("chat link is not valid base64"),
+            Self::TooShort => f.write_str("chat link ends too early"),
+            Self::UnknownType(header) => write!(f, "unknown chat link type 0x{:02x}", header),
+            Self::UnknownProfession(code) => write!(f, "unknown profession code {}", code),
+        }
+    }
+}
+
+impl Error for ChatLinkError {}
+
+impl ChatLink {
+    /// Decodes the raw bytes of a chat link, without the base64 and brackets.
+    pub fn from_bytes(bytes: &[u8]) -> Result<ChatLink, ChatLinkError> {
+        let mut reader = Reader { bytes };
+        let header = reader.u8()?;
+        let link = match header {
+            COINS => ChatLink::Coins(Coins::from_copper(u64::from(reader.u32()?))),
+            ITEM => {
+                let quantity = reader.u8()?;
+                let id = reader.u24()?;
+                let flags = reader.u8()?;
+                let skin = if flags & ITEM_SKIN != 0 {
+                    Some(reader.u32()?)
+                } else {
+                    None
+                };
+                let mut upgrades = [None; 2];
+                for (upgrade, flag) in upgrades.iter_mut().zip(ITEM_UPGRADE_SLOTS) {
+                    if flags & flag != 0 {
+                        *upgrade = Some(reader.u32()?);
+                    }
+                }
+                ChatLink::Item(ItemLink {
+                    id,
+                    quantity,
+                    skin,
+                    upgrades,
+                })
+            }
+            NPC_TEXT => ChatLink::NpcText(reader.u32()?),
+            MAP => ChatLink::Map(reader.u32()?),
+            SKILL => ChatLink::Skill(reader.u32()?),
+            TRAIT => ChatLink::Trait(reader.u32()?),
+            RECIPE => ChatLink::Recipe(reader.u32()?),
+            SKIN => ChatLink::Skin(reader.u32()?),
+            OUTFIT => ChatLink::Outfit(reader.u32()?),
+            WVW_OBJECTIVE => {
+                let objective_id = reader.u32()?;
+                let map_id = reader.u32()?;
+                ChatLink::WvwObjective(WvwObjectiveLink {
+                    map_id,
+                    objective_id,
+                })
+            }
+            BUILD_TEMPLATE => ChatLink::BuildTemplate(BuildTemplate::read(&mut reader)?),
+            header => return Err(ChatLinkError::UnknownType(header)),
+        };
+        Ok(link)
+    }
+
+    /// Encodes the chat link into its raw bytes, without the base64 and brackets.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut id_link = |header: u8, id: u32| {
+            bytes.push(header);
+            bytes.extend_from_slice(&id.to_le_bytes());
+        };
+        match self {
+            // Chat links only hold 32 bits of copper, larger amounts are capped.
+            ChatLink::Coins(coins) => {
+                id_link(COINS, u32::try_from(coins.copper()).unwrap_or(u32::MAX))
+            }
+            ChatLink::NpcText(id) => id_link(NPC_TEXT, *id),
+            ChatLink::Map(id) => id_link(MAP, *id),
+            ChatLink::Skill(id) => id_link(SKILL, *id),
+            ChatLink::Trait(id) => id_link(TRAIT, *id),
+            ChatLink::Recipe(id) => id_link(RECIPE, *id),
+            ChatLink::Skin(id) => id_link(SKIN, *id),
+            ChatLink::Outfit(id) => id_link(OUTFIT, *id),
+            ChatLink::Item(item) => {
+                let mut flags = 0;
+                if item.skin.is_some() {
+                    flags |= ITEM_SKIN;
+                }
+                for (upgrade, flag) in item.upgrades.iter().zip(ITEM_UPGRADE_SLOTS) {
+                    if upgrade.is_some() {
+                        flags |= flag;
+                    }
+                }
+                bytes.extend_from_slice(&[ITEM, item.quantity]);
+                bytes.extend_from_slice(&item.id.to_le_bytes()[..3]);
+                bytes.push(flags);
+                for id in item.skin.iter().chain(item.upgrades.iter().flatten()) {
+                    bytes.extend_from_slice(&id.to_le_bytes());
+                }
+            }
+            ChatLink::WvwObjective(objective) => {
+                bytes.push(WVW_OBJECTIVE);
+                bytes.extend_from_slice(&objective.objective_id.to_le_bytes());
+                bytes.extend_from_slice(&objective.map_id.to_le_bytes());
+            }
+            ChatLink::BuildTemplate(template) => {
+                bytes.push(BUILD_TEMPLATE);
+                template.write(&mut bytes);
+            }
+        }
+        bytes
+    }
+}
+
+impl BuildTemplate {
+    fn read(reader: &mut Reader) -> Result<BuildTemplate, ChatLinkError> {
+        let code = reader.u8()?;
+        let profession =
+            profession_from_code(code).ok_or(ChatLinkError::UnknownProfession(code))?;
+        let mut specializations = [TemplateSpecialization::default(); 3];
+        for specialization in &mut specializations {
+            specialization.id = reader.u8()?;
+            let traits = reader.u8()?;
+            specialization.traits = [traits & 0b11, traits >> 2 & 0b11, traits >> 4 & 0b11];
+        }
+        let mut skills = [0; 10];
+        for skill in &mut skills {
+            *skill = reader.u16()?;
+        }
+        let mut profession_data = [0; 16];
+        profession_data.copy_from_slice(reader.take(16)?);
+
+        // Templates from before weapon mastery end here.
+        let mut weapons = Vec::new();
+        let mut skill_overrides = Vec::new();
+        if !reader.bytes.is_empty() {
+            for _ in 0..reader.u8()? {
+                weapons.push(reader.u16()?);
+            }
+            for _ in 0..reader.u8()? {
+                skill_overrides.push(reader.u32()?);
+            }
+        }
+
+        Ok(BuildTemplate {
+            profession,
+            specializations,
+            skills,
+            profession_data,
+            weapons,
+            skill_overrides,
+        })
+    }
+
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.push(profession_code(&self.profession));
+        for specialization in &self.specializations {
+            let [adept, master, grandmaster] = specialization.traits.map(|choice| choice & 0b11);
+            bytes.extend_from_slice(&[specialization.id, adept | master << 2 | grandmaster << 4]);
+        }
+        for skill in &self.skills {
+            bytes.extend_from_slice(&skill.to_le_bytes());
+        }
+        bytes.extend_from_slice(&self.profession_data);
+
+        if !self.weapons.is_empty() || !self.skill_overrides.is_empty() {
+            let weapons = &self.weapons[..self.weapons.len().min(u8::MAX.into())];
+            bytes.push(weapons.len() as u8);
+            for weapon in weapons {
+                bytes.extend_from_slice(&weapon.to_le_bytes());
+            }
+            let overrides = &self.skill_overrides[..self.skill_overrides.len().min(u8::MAX.into())];
+            bytes.push(overrides.len() as u8);
+            for skill in overrides {
+                bytes.extend_from_slice(&skill.to_le_bytes());
+            }
+        }
+    }
+}
+
+/// Profession codes used by build templates.
+const PROFESSIONS: [(u8, Profession); 9] = [
+    (1, Profession::Guardian),
+    (2, Profession::Warrior),
+    (3, Profession::Engineer),
+    (4, Profession::Ranger),
+    (5, Profession::Thief),
+    (6, Profession::Elementalist),
+    (7, Profession::Mesmer),
+    (8, Profession::Necromancer),
+    (9, Profession::Revenant),
+];
+
+fn profession_from_code(code: u8) -> Option<Profession> {
+    PROFESSIONS
+        .iter()
+        .find(|(c, _)| *c == code)
+        .map(|(_, profession)| *profession)
+}
+
+fn profession_code(profession: &Profession) -> u8 {
+    PROFESSIONS
+        .iter()
+        .find(|(_, p)| p == profession)
+        .map_or(0, |(code, _)| *code)
+}
+
+/// Reads little-endian numbers off the front of a byte slice.
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], ChatLinkError> {
+        if self.bytes.len() < n {
+            return Err(ChatLinkError::TooShort);
+        }
+        let (taken, rest) = self.bytes.split_at(n);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn u8(&mut self) -> Result<u8, ChatLinkError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, ChatLinkError> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u24(&mut self) -> Result<u32, ChatLinkError> {
+        let bytes = self.take(3)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0]))
+    }
+
+    fn u32(&mut self) -> Result<u32, ChatLinkError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
+
+/// Formats the link as it is pasted into chat, e.g. `[&AgEAWgAA]`.
+impl fmt::Display for ChatLink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[&{}]", base64_encode(&self.to_bytes()))
+    }
+}
+
+/// Parses a link as it is pasted into chat, e.g. `[&AgEAWgAA]`. Surrounding whitespace is
+/// ignored.
+impl FromStr for ChatLink {
+    type Err = ChatLinkError;
+
+    fn from_str(s: &str) -> Result<ChatLink, ChatLinkError> {
+        let encoded = s
+            .trim()
+            .strip_prefix("[&")
+            .and_then(|s| s.strip_suffix(']'))
+            .ok_or(ChatLinkError::MissingBrackets)?;
+        let bytes = base64_decode(encoded).ok_or(ChatLinkError::InvalidBase64)?;
+        ChatLink::from_bytes(&bytes)
+    }
+}
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (i, &byte)| {
+            group | u32::from(byte) << (16 - 8 * i)
+        });
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(BASE64[(group >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+fn base64_decode(encoded: &str) -> Option<Vec<u8>> {
+    let encoded = encoded.as_bytes();
+    if !encoded.len().is_multiple_of(4) {
+        return None;
+    }
+    let mut bytes = Vec::with_capacity(encoded.len() / 4 * 3);
+    for (index, chunk) in encoded.chunks(4).enumerate() {
+        let padding = chunk.iter().rev().take_while(|&&c| c == b'=').count();
+        let is_last = index == encoded.len() / 4 - 1;
+        if padding > 2 || (padding > 0 && !is_last) {
+            return None;
+        }
+        let mut group = 0u32;
+        for &c in &chunk[..4 - padding] {
+            let value = BASE64.iter().position(|&b| b == c)? as u32;
+            group = group << 6 | value;
+        }
+        group <<= 6 * padding as u32;
+        bytes.extend_from_slice(&group.to_be_bytes()[1..4 - padding]);
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::chatlink::*;
+
+    fn round_trip(link: &str) -> ChatLink {
+        let decoded = link.parse::<ChatLink>().unwrap();
+        assert_eq!(link, decoded.to_string());
+        decoded
+    }
+
+    #[test]
+    fn base64() {
+        for bytes in [&b""[..], b"f", b"fo", b"foo", b"foob", b"fooba", b"foobar"] {
+            assert_eq!(Some(bytes.to_vec()), base64_decode(&base64_encode(bytes)));
+        }
+        assert_eq!("Zm9vYg==", base64_encode(b"foob"));
+        assert_eq!(None, base64_decode("Zm9vY"));
+        assert_eq!(None, base64_decode("Zm=vYg=="));
+        assert_eq!(None, base64_decode("Zm9v!g=="));
+    }
+
+    #[test]
+    fn coins() {
+        assert_eq!(
+            ChatLink::Coins(Coins::from_copper(10203)),
+            round_trip("[&AdsnAAA=]")
+        );
+    }
+
+    #[test]
+    fn items() {
+        assert_eq!(
+            ChatLink::Item(ItemLink::new(46762)),
+            round_trip("[&AgGqtgAA]")
+        );
+
+        // A stack of 250 Mithril Ore.
+        let ore = ItemLink {
+            quantity: 250,
+            ..ItemLink::new(19700)
+        };
+        assert_eq!(ChatLink::Item(ore), round_trip("[&Avr0TAAA]"));
+
+        // Zojja's Claymore with the Dreamthistle Greatsword skin and two Superior Sigils.
+        let claymore = ItemLink {
+            id: 46762,
+            quantity: 1,
+            skin: Some(3709),
+            upgrades: [Some(24575), Some(24615)],
+        };
+        assert_eq!(
+            ChatLink::Item(claymore),
+            round_trip("[&AgGqtgDgfQ4AAP9fAAAnYAAA]")
+        );
+
+        // Only the second upgrade slot is filled.
+        let claymore = ItemLink {
+            upgrades: [None, Some(24615)],
+            ..ItemLink::new(46762)
+        };
+        assert_eq!(
+            ChatLink::Item(claymore),
+            round_trip("[&AgGqtgAgJ2AAAA==]")
+        );
+    }
+
+    #[test]
+    fn ids() {
+        assert_eq!(ChatLink::Map(56), round_trip("[&BDgAAAA=]"));
+        assert_eq!(ChatLink::Skill(743), round_trip("[&BucCAAA=]"));
+        assert_eq!(ChatLink::Trait(1010), round_trip("[&B/IDAAA=]"));
+        assert_eq!(ChatLink::Recipe(1), round_trip("[&CQEAAAA=]"));
+        assert_eq!(ChatLink::Skin(4), round_trip("[&CgQAAAA=]"));
+        assert_eq!(ChatLink::Outfit(1), round_trip("[&CwEAAAA=]"));
+        assert_eq!(ChatLink::NpcText(10000), round_trip("[&AxAnAAA=]"));
+    }
+
+    #[test]
+    fn wvw_objective() {
+        let link = round_trip("[&DAYAAAAmAAAA]");
+        let objective = WvwObjectiveLink {
+            map_id: 38,
+            objective_id: 6,
+        };
+        assert_eq!(ChatLink::WvwObjective(objective), link);
+        assert_eq!("38-6", objective.id());
+    }
+
+    #[test]
+    fn build_template() {
+        let link = round_trip("[&DQQZGjcmHhUmDyYPvQC9AAEBAQEdAB0AdAB0ADsFFS8AAAAAAAAAAAAAAAA=]");
+        let template = match link {
+            ChatLink::BuildTemplate(template) => template,
+            link => panic!("expected a build template, got {:?}", link),
+        };
+        assert_eq!(Profession::Ranger, template.profession);
+        assert_eq!(
+            TemplateSpecialization {
+                id: 25,
+                traits: [2, 2, 1]
+            },
+            template.specializations[0]
+        );
+        assert_eq!(
+            TemplateSpecialization {
+                id: 55,
+                traits: [2, 1, 2]
+            },
+            template.specializations[1]
+        );
+        assert_eq!(
+            TemplateSpecialization {
+                id: 30,
+                traits: [1, 1, 1]
+            },
+            template.specializations[2]
+        );
+        assert_eq!(
+            [0x0f26, 0xbd, 0x101, 0x1d, 0x74],
+            template.terrestrial_skills()
+        );
+        assert_eq!(Some([59, 5, 21, 47]), template.pets());
+        assert_eq!(None, template.legends());
+        assert!(template.weapons.is_empty());
+    }
+
+    #[test]
+    fn build_template_with_weapons() {
+        let mut template = BuildTemplate {
+            profession: Profession::Revenant,
+            specializations: [TemplateSpecialization {
+                id: 15,
+                traits: [1, 2, 3],
+            }; 3],
+            skills: [1, 2, 3, 4, 5, 6, 7, 8, 9, 10],
+            profession_data: [0; 16],
+            weapons: vec![0x66, 0x23],
+            skill_overrides: vec![72079],
+        };
+        template.profession_data[..4].copy_from_slice(&[14, 4, 0, 0]);
+        let link = ChatLink::BuildTemplate(template.clone());
+        assert_eq!(Ok(link.clone()), link.to_string().parse());
+        assert_eq!(Some([14, 4, 0, 0]), template.legends());
+        assert_eq!([2, 4, 6, 8, 10], template.aquatic_skills());
+    }
+
+    #[test]
+    fn invalid_links() {
+        assert_eq!(
+            Err(ChatLinkError::MissingBrackets),
+            "AgGqtgAA".parse::<ChatLink>()
+        );
+        assert_eq!(
+            Err(ChatLinkError::InvalidBase64),
+            "[&AgGqtgA]".parse::<ChatLink>()
+        );
+        assert_eq!(Err(ChatLinkError::TooShort), "[&AgGq]".parse::<ChatLink>());
+        assert_eq!(Err(ChatLinkError::TooShort), "[&]".parse::<ChatLink>());
+        assert_eq!(
+            Err(ChatLinkError::UnknownType(0xff)),
+            "[&/wAAAAA=]".parse::<ChatLink>()
+        );
+        assert_eq!(
+            Err(ChatLinkError::UnknownProfession(0)),
+            "[&DQA=]".parse::<ChatLink>()
+        );
+    }
+}
@@ -1,19 +1,36 @@
-use minreq::Response;
 use serde::de::DeserializeOwned;
 
 use std::fmt::{self, Display};
 use std::borrow::Cow::{self, Borrowed, Owned};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
 
+#[cfg(feature = "async")]
+use futures_util::stream::{self, Stream, StreamExt, TryStreamExt};
+
+use crate::cache::{self, Cache, CacheKey, CachedResponse};
+use crate::endpoint::{self, BulkEndpoint, BulkResult, Endpoint};
 use crate::error::{ApiError, ApiErrorKind};
+use crate::pagination::{self, Page, Pages};
+use crate::rate_limit::{self, RateLimiter};
+use crate::retry::{Retries, RetryPolicy};
 
 // Base url to the GW2 API.
 pub const BASE_URL: &str = "https://api.guildwars2.com";
-// Wait max 10 seconds for a response from the server.
+/// Base url of the render service, which serves the icons referred to by file ids and signatures.
+pub const RENDER_URL: &str = "https://render.guildwars2.com";
+/// Base url of the tile service, which serves the world map.
+pub const TILES_URL: &str = "https://tiles.guildwars2.com";
+// By default, wait max 10 seconds for a response from the server.
 pub const TIMEOUT: u64 = 10;
+/// The `X-Schema-Version` the v2 types of this crate model, which clients send by default.
+pub const SCHEMA_VERSION: &str = "2022-03-23T19:00:00.000Z";
 
 /// All available localisations that are supported by the official Guild Wars 2 API.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub enum Localisation {
+    #[default]
     English,
     Spanish,
     German,
@@ -34,14 +51,8 @@ impl Display for Localisation {
     }
 }
 
-impl Default for Localisation {
-    fn default() -> Self {
-        Localisation::English
-    }
-}
-
-/// Client that performs requests to the API
-#[derive(Default)]
+/// Client that performs requests to the API. Clones share the rate limiter, if one is set.
+#[derive(Clone)]
 pub struct Client<'a> {
     /// The API key used for endpoints that require authentication.
     api_key: Option<String>,
@@ -50,7 +61,22 @@ pub struct Client<'a> {
     lang: Localisation,
     /// Base url of the API.
     base_url: Cow<'a, str>,
-
+    /// Base url of the render service.
+    render_url: Cow<'a, str>,
+    /// Base url of the tile service.
+    tiles_url: Cow<'a, str>,
+    /// How many chunks of a bulk request may be in flight at the same time.
+    concurrent_requests: usize,
+    /// Throttles requests to stay within the rate limit of the API, if set.
+    rate_limiter: Option<RateLimiter>,
+    /// Decides which failed requests are sent again.
+    retry_policy: RetryPolicy,
+    /// How long to wait for a response before giving up on a request.
+    timeout: Duration,
+    /// Stores responses so that they do not have to be requested again, if set.
+    cache: Option<Arc<dyn Cache>>,
+    /// Value of the `X-Schema-Version` header, which decides the shape of some v2 responses.
+    schema_version: Option<String>,
 }
 
 impl<'a> Client<'a> {
@@ -60,6 +86,14 @@ impl<'a> Client<'a> {
             api_key: None,
             lang: Localisation::default(),
             base_url: Borrowed(BASE_URL),
+            render_url: Borrowed(RENDER_URL),
+            tiles_url: Borrowed(TILES_URL),
+            concurrent_requests: 1,
+            rate_limiter: None,
+            retry_policy: RetryPolicy::never(),
+            timeout: Duration::from_secs(TIMEOUT),
+            cache: None,
+            schema_version: Some(SCHEMA_VERSION.to_string()),
         }
     }
 
@@ -81,16 +115,78 @@ impl<'a> Client<'a> {
         self
     }
 
+    /// Sets the base url of the render service, used by `render::RenderFile::download`.
+    pub fn set_render_url(mut self, render_url: String) -> Client<'a> {
+        self.render_url = Owned(render_url);
+        self
+    }
+
+    /// Sets the base url of the tile service, used by `tiles::TileLayer::download`.
+    pub fn set_tiles_url(mut self, tiles_url: String) -> Client<'a> {
+        self.tiles_url = Owned(tiles_url);
+        self
+    }
+
+    /// Sets how many chunks of a bulk request (see `get_many`) are requested in parallel, each on
+    /// its own thread. Defaults to 1, which requests the chunks one after another.
+    pub fn set_concurrent_requests(mut self, concurrent_requests: usize) -> Client<'a> {
+        self.concurrent_requests = concurrent_requests;
+        self
+    }
+
+    /// Throttles the requests of the client (and its clones) with the given rate limiter, e.g.
+    /// `RateLimiter::default()` for the limits of the official API. Requests that are answered
+    /// with a 429 are retried after backing off, up to `MAX_RATE_LIMIT_RETRIES` times.
+    pub fn set_rate_limiter(mut self, rate_limiter: RateLimiter) -> Client<'a> {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Sets which failed requests are sent again and how long to wait in between, by default
+    /// requests are not retried.
+    pub fn set_retry_policy(mut self, retry_policy: RetryPolicy) -> Client<'a> {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets how long to wait for a response, defaults to `TIMEOUT` seconds. The blocking client
+    /// only supports whole seconds, so the timeout is rounded up.
+    pub fn set_timeout(mut self, timeout: Duration) -> Client<'a> {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Caches responses for as long as the API allows in the given cache, e.g.
+    /// `cache::MemoryCache` or `cache::DiskCache`. Clones of the client share the cache, and to
+    /// share it between clients pass an `Arc` of it.
+    ///
+    /// Requests for `v2::build::Build` are never cached. Whenever one reports a different build
+    /// than the cached responses belong to, the cache is cleared, so request it now and then to
+    /// pick up new game builds.
+    pub fn set_cache(mut self, cache: impl Cache + 'static) -> Client<'a> {
+        self.cache = Some(Arc::new(cache));
+        self
+    }
+
+    /// Sets the `X-Schema-Version` sent with every request, defaults to `SCHEMA_VERSION`. The v2
+    /// types of this crate model that schema, so other versions may fail to deserialize.
+    pub fn set_schema_version(mut self, schema_version: String) -> Client<'a> {
+        self.schema_version = Some(schema_version);
+        self
+    }
+
+    /// Returns a copy of the client that sends the given `X-Schema-Version` instead, for a single
+    /// request, e.g. `client.with_schema_version("2019-12-19T00:00:00.000Z").fetch::<Build>()`.
+    /// The copy shares the rate limiter and cache of the client.
+    pub fn with_schema_version(&self, schema_version: &str) -> Client<'a> {
+        self.clone().set_schema_version(schema_version.to_string())
+    }
+
     /// Make a request to the Guild Wars 2 API with the given url (which has to include version)
     /// as endpoint.
     pub fn request<T>(&self, url: &str) -> Result<T, ApiError>
     where T: DeserializeOwned {
-        let full_url = format!("{base_url}/{url}", base_url=self.base_url, url=url);
-        let response = minreq::get(&full_url)
-            .with_header("Accept-Language", self.lang.to_string())
-            .with_timeout(TIMEOUT)
-            .send()?;
-        Client::handle_response(response)
+        Client::handle_response(self.send(url, false)?)
     }
 
     /// Make an authenticated request to the Guild Wars 2 API with the given url (which has to
@@ -102,38 +198,209 @@ impl<'a> Client<'a> {
     /// an error.
     pub fn authenticated_request<T>(&self, url: &str) -> Result<T, ApiError>
     where T: DeserializeOwned {
-        let full_url = format!("{base_url}/{url}", base_url=self.base_url, url=url);
+        Client::handle_response(self.send(url, true)?)
+    }
 
-        let authorization_msg = match self.api_key.as_ref() {
-            Some(key) => format!("Bearer {}", key),
-            None => return Err(ApiError::new(ApiErrorKind::ApiKeyNotSet)),
-        };
+    /// Request a single page of the given url (which has to include version), see
+    /// `pagination::Page` for the information that is returned alongside the objects. The
+    /// `page_size` may be at most `pagination::MAX_PAGE_SIZE`.
+    pub fn request_page<T>(&self, url: &str, page: usize, page_size: usize)
+        -> Result<Page<T>, ApiError>
+    where T: DeserializeOwned {
+        self.page(url, page, page_size, false)
+    }
+
+    /// Authenticated version of `request_page`.
+    pub fn authenticated_request_page<T>(&self, url: &str, page: usize, page_size: usize)
+        -> Result<Page<T>, ApiError>
+    where T: DeserializeOwned {
+        self.page(url, page, page_size, true)
+    }
+
+    fn page<T>(&self, url: &str, page: usize, page_size: usize, authenticated: bool)
+        -> Result<Page<T>, ApiError>
+    where T: DeserializeOwned {
+        let response = self.send(&pagination::page_url(url, page, page_size), authenticated)?;
+        let items = parse_response(response.status, &response.body)?;
+        Ok(Page::new(items, page, |name| response.header(name).map(String::from)))
+    }
+
+    /// Sends a GET request for the url, including the API key if the request is authenticated,
+    /// unless the cache holds a fresh response for it.
+    fn send(&self, url: &str, authenticated: bool) -> Result<CachedResponse, ApiError> {
+        let authorization = if authenticated { Some(self.authorization()?) } else { None };
+        let key = CacheKey::new(&self.base_url, url, &self.lang.to_string(),
+            self.schema_version.as_deref(), authorization.as_deref());
+        if let Some(response) = cache::lookup(self.cache.as_deref(), &key) {
+            return Ok(response);
+        }
+
+        let response = self.send_uncached(&join_url(&self.base_url, url), authorization)?;
+        cache::store(self.cache.as_deref(), key, &response);
+        Ok(response)
+    }
 
-        let response = minreq::get(&full_url)
+    /// Downloads a file from outside the API, such as an icon of the render service. The request
+    /// follows the retry policy, but does not count against the API's rate limit, is sent without
+    /// the API's headers and is never cached.
+    pub fn download(&self, url: &str) -> Result<Vec<u8>, ApiError> {
+        let response = self.send_external(url)?;
+        download_body(response)
+    }
+
+    /// Sends a GET request for the full url, retrying it as the rate limiter and retry policy
+    /// allow.
+    fn send_uncached(&self, full_url: &str, authorization: Option<String>)
+        -> Result<CachedResponse, ApiError> {
+        let mut request = minreq::get(full_url)
             .with_header("Accept-Language", self.lang.to_string())
-            .with_header("Authorization", authorization_msg)
-            .with_timeout(TIMEOUT)
-            .send()?;
-        Client::handle_response(response)
+            .with_timeout(timeout_secs(self.timeout));
+
+        if let Some(authorization) = authorization {
+            request = request.with_header("Authorization", authorization);
+        }
+        if let Some(schema_version) = &self.schema_version {
+            request = request.with_header("X-Schema-Version", schema_version);
+        }
+        self.send_with_retries(request, self.rate_limiter.as_ref())
+    }
+
+    /// Sends a GET request for a url outside the API, retrying it as the retry policy allows.
+    fn send_external(&self, full_url: &str) -> Result<CachedResponse, ApiError> {
+        let request = minreq::get(full_url).with_timeout(timeout_secs(self.timeout));
+        self.send_with_retries(request, None)
+    }
+
+    /// Sends the request, taking a token from the rate limiter for every attempt if there is one.
+    fn send_with_retries(&self, request: minreq::Request, rate_limiter: Option<&RateLimiter>)
+        -> Result<CachedResponse, ApiError> {
+        let mut retries = Retries::new(&self.retry_policy, rate_limiter);
+        loop {
+            if let Some(limiter) = rate_limiter {
+                limiter.acquire();
+            }
+            let result = request.clone().send();
+            let delay = match &result {
+                Ok(response) => {
+                    let retry_after = response.headers.get("retry-after").map(String::as_str);
+                    retries.next_delay(Some(response.status_code as u16),
+                        rate_limit::retry_after(retry_after))
+                }
+                Err(_) => retries.next_delay(None, None),
+            };
+            match delay {
+                Some(delay) => thread::sleep(delay),
+                None => {
+                    let mut response = result?;
+                    let headers = std::mem::take(&mut response.headers);
+                    let status = response.status_code as u16;
+                    return Ok(CachedResponse::new(status, headers, response.into_bytes()));
+                }
+            }
+        }
+    }
+
+    /// The value of the Authorization header, if the API key is set.
+    fn authorization(&self) -> Result<String, ApiError> {
+        match self.api_key.as_ref() {
+            Some(key) => Ok(format!("Bearer {}", key)),
+            None => Err(ApiError::new(ApiErrorKind::ApiKeyNotSet)),
+        }
+    }
+
+    /// Retrieve an endpoint that returns a single object, such as `v2::build::Build`.
+    pub fn fetch<T: Endpoint>(&self) -> Result<T, ApiError> {
+        self.endpoint_request::<T, _>(T::URL)
+    }
+
+    /// Retrieve an object by its id, e.g. `client.get::<Amulet>(&4)`.
+    pub fn get<T: BulkEndpoint>(&self, id: &T::Id) -> Result<T, ApiError> {
+        self.endpoint_request::<T, _>(&endpoint::id_url::<T>(id))
+    }
+
+    /// Retrieve all ids that are available for the endpoint of `T`.
+    pub fn get_ids<T: BulkEndpoint>(&self) -> Result<Vec<T::Id>, ApiError> {
+        self.endpoint_request::<T, _>(T::URL)
+    }
+
+    /// Retrieve multiple objects by their ids, in the same order as the ids. Ids that do not exist
+    /// are left out of the returned `Vec`.
+    ///
+    /// Any amount of ids may be given, they are split up into requests of at most
+    /// `MAX_IDS_PER_REQUEST` ids behind the scenes.
+    pub fn get_many<T: BulkEndpoint>(&self, ids: &[T::Id]) -> Result<Vec<T>, ApiError> {
+        Ok(self.get_many_with_missing::<T>(ids)?.found)
+    }
+
+    /// Same as `get_many`, but also reports which of the requested ids did not exist.
+    pub fn get_many_with_missing<T: BulkEndpoint>(&self, ids: &[T::Id])
+        -> Result<BulkResult<T>, ApiError> {
+        let chunks = endpoint::chunk_ids::<T>(ids);
+        let fetch = |chunk: &Vec<T::Id>| {
+            endpoint::none_found(self.endpoint_request::<T, _>(&endpoint::ids_url::<T>(chunk)))
+        };
+
+        let responses = if self.concurrent_requests > 1 && chunks.len() > 1 {
+            thread::scope(|scope| {
+                chunks.chunks(self.concurrent_requests)
+                    .flat_map(|batch| {
+                        let handles: Vec<_> = batch.iter()
+                            .map(|chunk| scope.spawn(move || fetch(chunk)))
+                            .collect();
+                        handles.into_iter()
+                            .map(|handle| handle.join().expect("bulk request thread panicked"))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })?
+        } else {
+            chunks.iter().map(fetch).collect::<Result<Vec<_>, _>>()?
+        };
+
+        Ok(endpoint::merge_chunks(chunks, responses))
+    }
+
+    /// Retrieve every object that is available for the endpoint of `T`.
+    pub fn get_all<T: BulkEndpoint>(&self) -> Result<Vec<T>, ApiError> {
+        self.endpoint_request::<T, _>(&endpoint::all_url::<T>())
+    }
+
+    /// Retrieve a single page of objects from the endpoint of `T`.
+    pub fn get_page<T: Endpoint>(&self, page: usize, page_size: usize)
+        -> Result<Page<T>, ApiError> {
+        self.page(T::URL, page, page_size, T::AUTHENTICATED)
+    }
+
+    /// Returns an iterator that walks over every page of the endpoint of `T`, e.g.
+    ///
+    /// ```no_run
+    /// # use gw2api::client::Client;
+    /// # use gw2api::v2::pvp::amulets::Amulet;
+    /// let client = Client::new();
+    /// for page in client.pages::<Amulet>(50) {
+    ///     println!("{} amulets", page.unwrap().items.len());
+    /// }
+    /// ```
+    pub fn pages<T: Endpoint>(&self, page_size: usize) -> Pages<'_, 'a, T> {
+        Pages::new(self, page_size)
+    }
+
+    /// Requests the url with or without authentication, depending on what the endpoint `E`
+    /// requires.
+    fn endpoint_request<E: Endpoint, T: DeserializeOwned>(&self, url: &str) -> Result<T, ApiError> {
+        if E::AUTHENTICATED {
+            self.authenticated_request(url)
+        } else {
+            self.request(url)
+        }
     }
 
     /// Handles the initial response of a request by looking at the status codes or if the request
     /// timed out. Returns the deserialized type or raises an `ApiError` upon a receiving an error,
     /// respectively.
-    fn handle_response<T>(response: Response) -> Result<T, ApiError>
+    fn handle_response<T>(response: CachedResponse) -> Result<T, ApiError>
     where T: DeserializeOwned {
-        match response.status_code {
-            // Ok
-            200 => Ok(response.json::<T>()?),
-            // Forbidden
-            403 => Err(ApiError::new(ApiErrorKind::Forbidden)),
-            // Not Found
-            404 => Err(ApiError::new(ApiErrorKind::NotFound)),
-            // Timeout
-            408 =>
-            Err(ApiError::new(ApiErrorKind::ApiTimeout)),
-            _ => Err(ApiError::new(ApiErrorKind::Custom(String::new()))),
-        }
+        parse_response(response.status, &response.body)
     }
 
     /// Returns an `Option` containing a string slice of the Guild Wars 2 API key for the
@@ -151,6 +418,437 @@ impl<'a> Client<'a> {
     pub fn base_url(&self) -> &str {
         &self.base_url
     }
+
+    /// Returns the base url of the render service.
+    pub fn render_url(&self) -> &str {
+        &self.render_url
+    }
+
+    /// Returns the base url of the tile service.
+    pub fn tiles_url(&self) -> &str {
+        &self.tiles_url
+    }
+
+    /// Returns the `X-Schema-Version` the client sends, if any.
+    pub fn schema_version(&self) -> Option<&str> {
+        self.schema_version.as_deref()
+    }
+}
+
+/// Asynchronous counterpart of [`Client`], available behind the `async` feature. It is configured
+/// the same way and every endpoint function has an `_async` variant that takes this client
+/// instead.
+#[cfg(feature = "async")]
+#[derive(Clone)]
+pub struct AsyncClient<'a> {
+    /// The API key used for endpoints that require authentication.
+    api_key: Option<String>,
+    /// The language that the response will be in. Defaults to English if left empty as per the
+    /// official Guild Wars 2 API behvaiour.
+    lang: Localisation,
+    /// Base url of the API.
+    base_url: Cow<'a, str>,
+    /// Base url of the render service.
+    render_url: Cow<'a, str>,
+    /// Base url of the tile service.
+    tiles_url: Cow<'a, str>,
+    /// How many chunks of a bulk request may be in flight at the same time.
+    concurrent_requests: usize,
+    /// Throttles requests to stay within the rate limit of the API, if set.
+    rate_limiter: Option<RateLimiter>,
+    /// Decides which failed requests are sent again.
+    retry_policy: RetryPolicy,
+    /// How long to wait for a response before giving up on a request.
+    timeout: Duration,
+    /// Stores responses so that they do not have to be requested again, if set.
+    cache: Option<Arc<dyn Cache>>,
+    /// Value of the `X-Schema-Version` header, which decides the shape of some v2 responses.
+    schema_version: Option<String>,
+    /// Underlying HTTP client, which keeps a connection pool around between requests.
+    inner: reqwest::Client,
+}
+
+#[cfg(feature = "async")]
+impl<'a> AsyncClient<'a> {
+    /// Creates a new `AsyncClient` to interface with the Guild Wars 2 API.
+    pub fn new() -> AsyncClient<'a> {
+        let inner = reqwest::Client::builder()
+            .build()
+            .expect("unable to initialise the TLS backend");
+        AsyncClient {
+            api_key: None,
+            lang: Localisation::default(),
+            base_url: Borrowed(BASE_URL),
+            render_url: Borrowed(RENDER_URL),
+            tiles_url: Borrowed(TILES_URL),
+            concurrent_requests: 1,
+            rate_limiter: None,
+            retry_policy: RetryPolicy::never(),
+            timeout: Duration::from_secs(TIMEOUT),
+            cache: None,
+            schema_version: Some(SCHEMA_VERSION.to_string()),
+            inner,
+        }
+    }
+
+    /// Sets the API key of the client with a valid Guild Wars 2 API key.
+    pub fn set_api_key(mut self, api_key: String) -> AsyncClient<'a> {
+        self.api_key = Some(api_key);
+        self
+    }
+
+    /// Sets the language to be used in responses, applies to item names and what not.
+    pub fn set_lang(mut self, lang: Localisation) -> AsyncClient<'a> {
+        self.lang = lang;
+        self
+    }
+
+    /// Sets the base url for the API.
+    pub fn set_base_url(mut self, base_url: String) -> AsyncClient<'a> {
+        self.base_url = Owned(base_url);
+        self
+    }
+
+    /// Sets the base url of the render service, used by `render::RenderFile::download`.
+    pub fn set_render_url(mut self, render_url: String) -> AsyncClient<'a> {
+        self.render_url = Owned(render_url);
+        self
+    }
+
+    /// Sets the base url of the tile service, used by `tiles::TileLayer::download`.
+    pub fn set_tiles_url(mut self, tiles_url: String) -> AsyncClient<'a> {
+        self.tiles_url = Owned(tiles_url);
+        self
+    }
+
+    /// Sets how many chunks of a bulk request (see `get_many`) are requested concurrently.
+    /// Defaults to 1, which requests the chunks one after another.
+    pub fn set_concurrent_requests(mut self, concurrent_requests: usize) -> AsyncClient<'a> {
+        self.concurrent_requests = concurrent_requests;
+        self
+    }
+
+    /// Throttles the requests of the client with the given rate limiter, see
+    /// `Client::set_rate_limiter`.
+    pub fn set_rate_limiter(mut self, rate_limiter: RateLimiter) -> AsyncClient<'a> {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Sets which failed requests are sent again, see `Client::set_retry_policy`.
+    pub fn set_retry_policy(mut self, retry_policy: RetryPolicy) -> AsyncClient<'a> {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets how long to wait for a response, defaults to `TIMEOUT` seconds.
+    pub fn set_timeout(mut self, timeout: Duration) -> AsyncClient<'a> {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Caches responses in the given cache, see `Client::set_cache`.
+    pub fn set_cache(mut self, cache: impl Cache + 'static) -> AsyncClient<'a> {
+        self.cache = Some(Arc::new(cache));
+        self
+    }
+
+    /// Sets the `X-Schema-Version` sent with every request, see `Client::set_schema_version`.
+    pub fn set_schema_version(mut self, schema_version: String) -> AsyncClient<'a> {
+        self.schema_version = Some(schema_version);
+        self
+    }
+
+    /// Returns a copy of the client that sends the given `X-Schema-Version` instead, for a single
+    /// request.
+    pub fn with_schema_version(&self, schema_version: &str) -> AsyncClient<'a> {
+        self.clone().set_schema_version(schema_version.to_string())
+    }
+
+    /// Make a request to the Guild Wars 2 API with the given url (which has to include version)
+    /// as endpoint.
+    pub async fn request<T>(&self, url: &str) -> Result<T, ApiError>
+    where T: DeserializeOwned {
+        Client::handle_response(self.send(url, false).await?)
+    }
+
+    /// Make an authenticated request to the Guild Wars 2 API with the given url (which has to
+    /// include version) as endpoint. This requires that the `api_key` field of the client is set,
+    /// otherwise an `ApiKeyNotSet` error is returned.
+    pub async fn authenticated_request<T>(&self, url: &str) -> Result<T, ApiError>
+    where T: DeserializeOwned {
+        Client::handle_response(self.send(url, true).await?)
+    }
+
+    /// Request a single page of the given url (which has to include version), see
+    /// `pagination::Page` for the information that is returned alongside the objects.
+    pub async fn request_page<T>(&self, url: &str, page: usize, page_size: usize)
+        -> Result<Page<T>, ApiError>
+    where T: DeserializeOwned {
+        self.page(url, page, page_size, false).await
+    }
+
+    /// Authenticated version of `request_page`.
+    pub async fn authenticated_request_page<T>(&self, url: &str, page: usize, page_size: usize)
+        -> Result<Page<T>, ApiError>
+    where T: DeserializeOwned {
+        self.page(url, page, page_size, true).await
+    }
+
+    async fn page<T>(&self, url: &str, page: usize, page_size: usize, authenticated: bool)
+        -> Result<Page<T>, ApiError>
+    where T: DeserializeOwned {
+        let url = pagination::page_url(url, page, page_size);
+        let response = self.send(&url, authenticated).await?;
+        let items = parse_response(response.status, &response.body)?;
+        Ok(Page::new(items, page, |name| response.header(name).map(String::from)))
+    }
+
+    /// Sends a GET request for the url, including the API key if the request is authenticated,
+    /// unless the cache holds a fresh response for it.
+    async fn send(&self, url: &str, authenticated: bool) -> Result<CachedResponse, ApiError> {
+        let authorization = if authenticated {
+            let key = self.api_key.as_ref()
+                .ok_or_else(|| ApiError::new(ApiErrorKind::ApiKeyNotSet))?;
+            Some(format!("Bearer {}", key))
+        } else {
+            None
+        };
+        let key = CacheKey::new(&self.base_url, url, &self.lang.to_string(),
+            self.schema_version.as_deref(), authorization.as_deref());
+        if let Some(response) = cache::lookup(self.cache.as_deref(), &key) {
+            return Ok(response);
+        }
+
+        let response = self.send_uncached(&join_url(&self.base_url, url), authorization).await?;
+        cache::store(self.cache.as_deref(), key, &response);
+        Ok(response)
+    }
+
+    /// Asynchronous variant of [`Client::download`].
+    pub async fn download(&self, url: &str) -> Result<Vec<u8>, ApiError> {
+        let response = self.send_external(url).await?;
+        download_body(response)
+    }
+
+    /// Sends a GET request for the full url, retrying it as the rate limiter and retry policy
+    /// allow.
+    async fn send_uncached(&self, full_url: &str, authorization: Option<String>)
+        -> Result<CachedResponse, ApiError> {
+        let mut request = self.inner.get(full_url)
+            .header("Accept-Language", self.lang.to_string())
+            .timeout(self.timeout);
+
+        if let Some(authorization) = authorization {
+            request = request.header("Authorization", authorization);
+        }
+        if let Some(schema_version) = &self.schema_version {
+            request = request.header("X-Schema-Version", schema_version);
+        }
+        self.send_with_retries(request, self.rate_limiter.as_ref()).await
+    }
+
+    /// Sends a GET request for a url outside the API, retrying it as the retry policy allows.
+    async fn send_external(&self, full_url: &str) -> Result<CachedResponse, ApiError> {
+        let request = self.inner.get(full_url).timeout(self.timeout);
+        self.send_with_retries(request, None).await
+    }
+
+    /// Sends the request, taking a token from the rate limiter for every attempt if there is one.
+    async fn send_with_retries(&self, request: reqwest::RequestBuilder,
+        rate_limiter: Option<&RateLimiter>) -> Result<CachedResponse, ApiError> {
+        let mut retries = Retries::new(&self.retry_policy, rate_limiter);
+        loop {
+            if let Some(limiter) = rate_limiter {
+                tokio::time::sleep(limiter.reserve()).await;
+            }
+            let attempt = request.try_clone().expect("GET requests have no streaming body");
+            let result = attempt.send().await;
+            let delay = match &result {
+                Ok(response) => {
+                    let retry_after = response.headers().get("retry-after")
+                        .and_then(|value| value.to_str().ok());
+                    retries.next_delay(Some(response.status().as_u16()),
+                        rate_limit::retry_after(retry_after))
+                }
+                Err(_) => retries.next_delay(None, None),
+            };
+            match delay {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => {
+                    let response = result?;
+                    let status = response.status().as_u16();
+                    let headers = response.headers().iter()
+                        .filter_map(|(name, value)| {
+                            Some((name.as_str().to_string(), value.to_str().ok()?.to_string()))
+                        })
+                        .collect();
+                    let body = response.bytes().await?.to_vec();
+                    return Ok(CachedResponse::new(status, headers, body));
+                }
+            }
+        }
+    }
+
+    /// Retrieve an endpoint that returns a single object, such as `v2::build::Build`.
+    pub async fn fetch<T: Endpoint>(&self) -> Result<T, ApiError> {
+        self.endpoint_request::<T, _>(T::URL).await
+    }
+
+    /// Retrieve an object by its id, e.g. `client.get::<Amulet>(&4).await`.
+    pub async fn get<T: BulkEndpoint>(&self, id: &T::Id) -> Result<T, ApiError> {
+        self.endpoint_request::<T, _>(&endpoint::id_url::<T>(id)).await
+    }
+
+    /// Retrieve all ids that are available for the endpoint of `T`.
+    pub async fn get_ids<T: BulkEndpoint>(&self) -> Result<Vec<T::Id>, ApiError> {
+        self.endpoint_request::<T, _>(T::URL).await
+    }
+
+    /// Retrieve multiple objects by their ids, in the same order as the ids. Ids that do not exist
+    /// are left out of the returned `Vec`.
+    ///
+    /// Any amount of ids may be given, they are split up into requests of at most
+    /// `MAX_IDS_PER_REQUEST` ids behind the scenes.
+    pub async fn get_many<T: BulkEndpoint>(&self, ids: &[T::Id]) -> Result<Vec<T>, ApiError> {
+        Ok(self.get_many_with_missing::<T>(ids).await?.found)
+    }
+
+    /// Same as `get_many`, but also reports which of the requested ids did not exist.
+    pub async fn get_many_with_missing<T: BulkEndpoint>(&self, ids: &[T::Id])
+        -> Result<BulkResult<T>, ApiError> {
+        let chunks = endpoint::chunk_ids::<T>(ids);
+        let responses = stream::iter(chunks.iter())
+            .map(|chunk| async move {
+                let url = endpoint::ids_url::<T>(chunk);
+                endpoint::none_found(self.endpoint_request::<T, _>(&url).await)
+            })
+            .buffered(self.concurrent_requests.max(1))
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        Ok(endpoint::merge_chunks(chunks, responses))
+    }
+
+    /// Retrieve every object that is available for the endpoint of `T`.
+    pub async fn get_all<T: BulkEndpoint>(&self) -> Result<Vec<T>, ApiError> {
+        self.endpoint_request::<T, _>(&endpoint::all_url::<T>()).await
+    }
+
+    /// Retrieve a single page of objects from the endpoint of `T`.
+    pub async fn get_page<T: Endpoint>(&self, page: usize, page_size: usize)
+        -> Result<Page<T>, ApiError> {
+        self.page(T::URL, page, page_size, T::AUTHENTICATED).await
+    }
+
+    /// Returns a stream that walks over every page of the endpoint of `T`, stopping after the last
+    /// page or the first error.
+    pub fn pages<T: Endpoint>(&self, page_size: usize)
+        -> impl Stream<Item = Result<Page<T>, ApiError>> + '_ {
+        self.pages_from_url(T::URL, T::AUTHENTICATED, page_size)
+    }
+
+    /// Walks over the pages of a url that is not tied to an `Endpoint` implementation.
+    pub(crate) fn pages_from_url<T: DeserializeOwned>(&self, url: &'static str,
+        authenticated: bool, page_size: usize)
+        -> impl Stream<Item = Result<Page<T>, ApiError>> + '_ {
+        stream::unfold(Some(0), move |next_page| async move {
+            let page = next_page?;
+            let result = self.page::<T>(url, page, page_size, authenticated).await;
+            let next_page = match &result {
+                Ok(page) if page.has_next() => Some(page.page + 1),
+                _ => None,
+            };
+            Some((result, next_page))
+        })
+    }
+
+    /// Requests the url with or without authentication, depending on what the endpoint `E`
+    /// requires.
+    async fn endpoint_request<E: Endpoint, T: DeserializeOwned>(&self, url: &str)
+        -> Result<T, ApiError> {
+        if E::AUTHENTICATED {
+            self.authenticated_request(url).await
+        } else {
+            self.request(url).await
+        }
+    }
+
+    /// Returns an `Option` containing a string slice of the Guild Wars 2 API key for the
+    /// client if it exists, otherwise None is returned in the Option.
+    pub fn api_key(&self) -> Option<&String> {
+        self.api_key.as_ref()
+    }
+
+    /// Returns a reference to the `Localisation` enum object.
+    pub fn lang(&self) -> &Localisation {
+        &self.lang
+    }
+
+    /// Returns the base url.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Returns the base url of the render service.
+    pub fn render_url(&self) -> &str {
+        &self.render_url
+    }
+
+    /// Returns the base url of the tile service.
+    pub fn tiles_url(&self) -> &str {
+        &self.tiles_url
+    }
+
+    /// Returns the `X-Schema-Version` the client sends, if any.
+    pub fn schema_version(&self) -> Option<&str> {
+        self.schema_version.as_deref()
+    }
+}
+
+impl Default for Client<'_> {
+    fn default() -> Self {
+        Client::new()
+    }
+}
+
+#[cfg(feature = "async")]
+impl Default for AsyncClient<'_> {
+    fn default() -> Self {
+        AsyncClient::new()
+    }
+}
+
+/// Joins the base url and an endpoint url, regardless of whether the latter starts with a slash.
+fn join_url(base_url: &str, url: &str) -> String {
+    format!("{base_url}/{url}", base_url=base_url, url=url.trim_start_matches('/'))
+}
+
+/// Converts the timeout to the whole seconds minreq expects, rounding up so that a short timeout
+/// does not become no time at all.
+fn timeout_secs(timeout: Duration) -> u64 {
+    timeout.as_secs() + u64::from(timeout.subsec_nanos() > 0)
+}
+
+/// Deserializes the body of a successful response, or turns an unsuccessful one into an
+/// `ApiError` carrying the status code and message of the API. Shared between the blocking and
+/// the async client so that both report failures the same way.
+fn parse_response<T>(status: u16, body: &[u8]) -> Result<T, ApiError>
+where T: DeserializeOwned {
+    match status {
+        // Ok, or Partial Content when only some of the ids of a bulk request exist, in which case
+        // the missing ids are left out of the response
+        200 | 206 => Ok(serde_json::from_slice(body)?),
+        _ => Err(ApiError::from_response(status, body)),
+    }
+}
+
+/// The body of a successful download, or an `ApiError` with the status code of a failed one.
+fn download_body(response: CachedResponse) -> Result<Vec<u8>, ApiError> {
+    match response.status {
+        200 => Ok(response.body),
+        status => Err(ApiError::from_response(status, &response.body)),
+    }
 }
 
 #[cfg(test)]
@@ -168,5 +866,18 @@ mod tests {
         let client = Client::new().set_api_key(api_key.clone()).set_lang(Localisation::French);
         assert_eq!(&api_key, client.api_key().unwrap());
         assert_eq!(&Localisation::French, client.lang());
+        assert_eq!(RENDER_URL, client.render_url());
+        assert_eq!(TILES_URL, client.tiles_url());
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn create_async_client() {
+        let api_key = "ABCDEFGH-1324-5678-9012-IJKLMNOPQRSTUVXYZABC-1234-5678-9012-ABCDEFGHIJKL"
+            .to_string();
+        let client = AsyncClient::new().set_api_key(api_key.clone()).set_lang(Localisation::German);
+        assert_eq!(&api_key, client.api_key().unwrap());
+        assert_eq!(&Localisation::German, client.lang());
+        assert_eq!(BASE_URL, client.base_url());
     }
 }
@@ -0,0 +1,384 @@
+//! In-game money. Every amount of coins is stored in copper, with 100 copper to a silver and 100
+//! silver to a gold, and is written as e.g. `12g 34s 56c`.
+//!
+//! ```
+//! use gw2api::coins::Coins;
+//!
+//! let price: Coins = "1g 50s".parse().unwrap();
+//! assert_eq!(15000, price.copper());
+//! assert_eq!("1g 27s 50c", price.after_trading_post_fees().to_string());
+//! ```
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+
+use std::error::Error;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+use std::str::FromStr;
+
+/// Copper coins in a silver coin.
+pub const COPPER_PER_SILVER: u64 = 100;
+/// Copper coins in a gold coin.
+pub const COPPER_PER_GOLD: u64 = 100 * COPPER_PER_SILVER;
+/// Percentage of the asking price the trading post charges up front for listing an item.
+pub const LISTING_FEE_PERCENT: u64 = 5;
+/// Percentage of the sale price the trading post keeps once an item is sold.
+pub const EXCHANGE_FEE_PERCENT: u64 = 10;
+
+/// An amount of coins, stored in copper.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coins(u64);
+
+impl Coins {
+    /// No coins at all.
+    pub const ZERO: Coins = Coins(0);
+
+    /// Creates an amount from copper.
+    pub const fn from_copper(copper: u64) -> Coins {
+        Coins(copper)
+    }
+
+    /// Creates an amount from its gold, silver and copper parts, returning `None` if the total
+    /// does not fit into a `u64`. Silver and copper above 99 carry over into the next unit.
+    pub fn from_parts(gold: u64, silver: u64, copper: u64) -> Option<Coins> {
+        gold.checked_mul(COPPER_PER_GOLD)?
+            .checked_add(silver.checked_mul(COPPER_PER_SILVER)?)?
+            .checked_add(copper)
+            .map(Coins)
+    }
+
+    /// The whole amount in copper.
+    pub const fn copper(self) -> u64 {
+        self.0
+    }
+
+    /// The gold part of the amount.
+    pub const fn gold_part(self) -> u64 {
+        self.0 / COPPER_PER_GOLD
+    }
+
+    /// The silver part of the amount, between 0 and 99.
+    pub const fn silver_part(self) -> u64 {
+        self.0 % COPPER_PER_GOLD / COPPER_PER_SILVER
+    }
+
+    /// The copper part of the amount, between 0 and 99.
+    pub const fn copper_part(self) -> u64 {
+        self.0 % COPPER_PER_SILVER
+    }
+
+    /// Adds two amounts, returning `None` on overflow.
+    pub fn checked_add(self, other: Coins) -> Option<Coins> {
+        self.0.checked_add(other.0).map(Coins)
+    }
+
+    /// Subtracts `other`, returning `None` if it is larger than `self`.
+    pub fn checked_sub(self, other: Coins) -> Option<Coins> {
+        self.0.checked_sub(other.0).map(Coins)
+    }
+
+    /// Multiplies the amount, e.g. a unit price by a quantity, returning `None` on overflow.
+    pub fn checked_mul(self, factor: u64) -> Option<Coins> {
+        self.0.checked_mul(factor).map(Coins)
+    }
+
+    /// Subtracts `other`, stopping at zero.
+    pub fn saturating_sub(self, other: Coins) -> Coins {
+        Coins(self.0.saturating_sub(other.0))
+    }
+
+    /// The fee the trading post charges for listing an item at this price: 5%, at least 1 copper.
+    pub fn listing_fee(self) -> Coins {
+        self.fee(LISTING_FEE_PERCENT)
+    }
+
+    /// The fee the trading post keeps once an item sells at this price: 10%, at least 1 copper.
+    pub fn exchange_fee(self) -> Coins {
+        self.fee(EXCHANGE_FEE_PERCENT)
+    }
+
+    /// Both trading post fees for selling an item at this price.
+    pub fn trading_post_fees(self) -> Coins {
+        self.listing_fee() + self.exchange_fee()
+    }
+
+    /// What the seller is left with after selling an item at this price on the trading post.
+    pub fn after_trading_post_fees(self) -> Coins {
+        self.saturating_sub(self.trading_post_fees())
+    }
+
+    /// A percentage of the amount rounded to the nearest copper, but never less than 1 copper
+    /// for a non-zero amount.
+    fn fee(self, percent: u64) -> Coins {
+        if self.0 == 0 {
+            return Coins::ZERO;
+        }
+        let fee = (u128::from(self.0) * u128::from(percent) + 50) / 100;
+        Coins((fee as u64).max(1))
+    }
+}
+
+impl From<u64> for Coins {
+    fn from(copper: u64) -> Coins {
+        Coins(copper)
+    }
+}
+
+impl From<Coins> for u64 {
+    fn from(coins: Coins) -> u64 {
+        coins.0
+    }
+}
+
+impl Add for Coins {
+    type Output = Coins;
+
+    fn add(self, other: Coins) -> Coins {
+        self.checked_add(other).expect("attempt to add coins with overflow")
+    }
+}
+
+impl AddAssign for Coins {
+    fn add_assign(&mut self, other: Coins) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Coins {
+    type Output = Coins;
+
+    fn sub(self, other: Coins) -> Coins {
+        self.checked_sub(other).expect("attempt to subtract coins with overflow")
+    }
+}
+
+impl SubAssign for Coins {
+    fn sub_assign(&mut self, other: Coins) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<u64> for Coins {
+    type Output = Coins;
+
+    fn mul(self, factor: u64) -> Coins {
+        self.checked_mul(factor).expect("attempt to multiply coins with overflow")
+    }
+}
+
+impl Sum for Coins {
+    fn sum<I: Iterator<Item = Coins>>(iter: I) -> Coins {
+        iter.fold(Coins::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Coins> for Coins {
+    fn sum<I: Iterator<Item = &'a Coins>>(iter: I) -> Coins {
+        iter.copied().sum()
+    }
+}
+
+/// Formats the amount as `12g 34s 56c`, leaving out units that are zero. No coins at all are
+/// written as `0c`.
+impl fmt::Display for Coins {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts = [(self.gold_part(), 'g'), (self.silver_part(), 's'), (self.copper_part(), 'c')];
+        let mut written = false;
+        for (amount, unit) in parts {
+            if amount == 0 {
+                continue;
+            }
+            if written {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}{}", amount, unit)?;
+            written = true;
+        }
+        if !written {
+            f.write_str("0c")?;
+        }
+        Ok(())
+    }
+}
+
+/// Error returned when a string is not a valid amount of coins.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCoinsError {
+    input: String,
+}
+
+impl fmt::Display for ParseCoinsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid amount of coins: {:?}", self.input)
+    }
+}
+
+impl Error for ParseCoinsError {}
+
+/// Parses amounts written as `12g 34s 56c`, where every unit is optional but they have to appear
+/// in that order, or a plain number of copper such as `123456`. Whitespace between the parts is
+/// optional.
+impl FromStr for Coins {
+    type Err = ParseCoinsError;
+
+    fn from_str(s: &str) -> Result<Coins, ParseCoinsError> {
+        let error = || ParseCoinsError { input: s.to_string() };
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            return Err(error());
+        }
+        if let Ok(copper) = trimmed.parse() {
+            return Ok(Coins(copper));
+        }
+
+        let mut rest = trimmed;
+        let mut parts = [None; 3];
+        let mut last_unit = None;
+        while !rest.is_empty() {
+            let digits = rest.find(|c: char| !c.is_ascii_digit()).ok_or_else(error)?;
+            let amount: u64 = rest[..digits].parse().map_err(|_| error())?;
+            let unit = match rest[digits..].chars().next() {
+                Some('g') | Some('G') => 0,
+                Some('s') | Some('S') => 1,
+                Some('c') | Some('C') => 2,
+                _ => return Err(error()),
+            };
+            if last_unit.is_some_and(|last| unit <= last) {
+                return Err(error());
+            }
+            parts[unit] = Some(amount);
+            last_unit = Some(unit);
+            rest = rest[digits + 1..].trim_start();
+        }
+
+        let [gold, silver, copper] = parts.map(|part| part.unwrap_or(0));
+        Coins::from_parts(gold, silver, copper).ok_or_else(error)
+    }
+}
+
+/// Serialized as a plain number of copper, the way the API sends it.
+impl Serialize for Coins {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.0)
+    }
+}
+
+/// Accepts a number of copper, as sent by the v2 API, as well as a string, as sent by the v1 API,
+/// holding either a number or an amount in the `12g 34s 56c` format.
+impl<'de> Deserialize<'de> for Coins {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Coins, D::Error> {
+        struct CoinsVisitor;
+
+        impl Visitor<'_> for CoinsVisitor {
+            type Value = Coins;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an amount of coins")
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Coins, E> {
+                Ok(Coins(value))
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Coins, E> {
+                u64::try_from(value)
+                    .map(Coins)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Coins, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(CoinsVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::coins::*;
+
+    #[test]
+    fn parts() {
+        let coins = Coins::from_copper(123456);
+        assert_eq!((12, 34, 56), (coins.gold_part(), coins.silver_part(), coins.copper_part()));
+        assert_eq!(Some(coins), Coins::from_parts(12, 34, 56));
+        assert_eq!(Some(Coins::from_copper(10100)), Coins::from_parts(0, 100, 100));
+        assert_eq!(None, Coins::from_parts(u64::MAX, 0, 0));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("12g 34s 56c", Coins::from_copper(123456).to_string());
+        assert_eq!("1g 5c", Coins::from_copper(10005).to_string());
+        assert_eq!("99s", Coins::from_copper(9900).to_string());
+        assert_eq!("0c", Coins::ZERO.to_string());
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Ok(Coins::from_copper(123456)), "12g 34s 56c".parse());
+        assert_eq!(Ok(Coins::from_copper(123456)), "12g34s56c".parse());
+        assert_eq!(Ok(Coins::from_copper(10005)), "1g 5c".parse());
+        assert_eq!(Ok(Coins::from_copper(350)), " 3S 50C ".parse());
+        assert_eq!(Ok(Coins::from_copper(125000)), "125000".parse());
+        for invalid in ["", "g", "5c 1g", "1g 1g", "1x", "-5c", "1.5g", "1g 2"] {
+            assert!(invalid.parse::<Coins>().is_err(), "{:?}", invalid);
+        }
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for copper in [0, 1, 99, 100, 10000, 10001, 123456, u64::MAX] {
+            let coins = Coins::from_copper(copper);
+            assert_eq!(Ok(coins), coins.to_string().parse());
+        }
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let coins = Coins::from_copper(100);
+        assert_eq!(Some(Coins::from_copper(150)), coins.checked_add(Coins::from_copper(50)));
+        assert_eq!(None, coins.checked_sub(Coins::from_copper(101)));
+        assert_eq!(None, Coins::from_copper(u64::MAX).checked_add(coins));
+        assert_eq!(None, Coins::from_copper(u64::MAX).checked_mul(2));
+        assert_eq!(Coins::ZERO, coins.saturating_sub(Coins::from_copper(200)));
+        assert_eq!(Coins::from_copper(300), coins * 3);
+        let total: Coins = [coins, coins].iter().sum();
+        assert_eq!(Coins::from_copper(200), total);
+    }
+
+    #[test]
+    #[should_panic]
+    fn subtraction_overflow_panics() {
+        let _ = Coins::ZERO - Coins::from_copper(1);
+    }
+
+    #[test]
+    fn trading_post_fees() {
+        let price = Coins::from_copper(10000);
+        assert_eq!(Coins::from_copper(500), price.listing_fee());
+        assert_eq!(Coins::from_copper(1000), price.exchange_fee());
+        assert_eq!(Coins::from_copper(8500), price.after_trading_post_fees());
+
+        // Every fee is at least 1 copper, rounding to the nearest copper otherwise.
+        assert_eq!(Coins::from_copper(1), Coins::from_copper(1).listing_fee());
+        assert_eq!(Coins::from_copper(1), Coins::from_copper(1).exchange_fee());
+        assert_eq!(Coins::ZERO, Coins::from_copper(1).after_trading_post_fees());
+        assert_eq!(Coins::from_copper(2), Coins::from_copper(30).listing_fee());
+        assert_eq!(Coins::from_copper(1), Coins::from_copper(29).listing_fee());
+        assert_eq!(Coins::ZERO, Coins::ZERO.trading_post_fees());
+    }
+
+    #[test]
+    fn serde() {
+        assert_eq!(Coins::from_copper(2474), serde_json::from_str::<Coins>("2474").unwrap());
+        assert_eq!(Coins::from_copper(125000), serde_json::from_str(r#""125000""#).unwrap());
+        assert_eq!(Coins::from_copper(10005), serde_json::from_str(r#""1g 5c""#).unwrap());
+        assert!(serde_json::from_str::<Coins>("-1").is_err());
+        assert!(serde_json::from_str::<Coins>(r#""abc""#).is_err());
+        assert_eq!("123456", serde_json::to_string(&Coins::from_copper(123456)).unwrap());
+    }
+}
@@ -0,0 +1,215 @@
+//! Colors as used by dyes, with conversions to CIELAB to tell how different two colors look.
+//!
+//! ```
+//! use gw2api::color::Rgb;
+//!
+//! let color: Rgb = "#7a2f45".parse().unwrap();
+//! assert_eq!(Rgb::new(122, 47, 69), color);
+//! assert_eq!(0.0, color.delta_e(color));
+//! ```
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A color in sRGB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    pub fn new(r: u8, g: u8, b: u8) -> Rgb {
+        Rgb { r, g, b }
+    }
+
+    /// The color given by the first three values of a list, such as `Material::rgb`.
+    pub fn from_slice(rgb: &[u8]) -> Option<Rgb> {
+        match rgb {
+            [r, g, b, ..] => Some(Rgb::new(*r, *g, *b)),
+            _ => None,
+        }
+    }
+
+    /// The color in CIELAB, relative to the D65 white point.
+    pub fn to_lab(&self) -> Lab {
+        // sRGB to linear RGB
+        let linear = |channel: u8| {
+            let c = f64::from(channel) / 255.0;
+            if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+        };
+        let (r, g, b) = (linear(self.r), linear(self.g), linear(self.b));
+
+        // Linear RGB to XYZ, scaled by the D65 white point
+        let x = (0.4124564 * r + 0.3575761 * g + 0.1804375 * b) / 0.95047;
+        let y = 0.2126729 * r + 0.7151522 * g + 0.0721750 * b;
+        let z = (0.0193339 * r + 0.119192 * g + 0.9503041 * b) / 1.08883;
+
+        let f = |t: f64| {
+            if t > 216.0 / 24389.0 { t.cbrt() } else { (24389.0 / 27.0 * t + 16.0) / 116.0 }
+        };
+        let (fx, fy, fz) = (f(x), f(y), f(z));
+        Lab { l: 116.0 * fy - 16.0, a: 500.0 * (fx - fy), b: 200.0 * (fy - fz) }
+    }
+
+    /// How different the two colors look, as the CIEDE2000 color difference. Colors closer than
+    /// about 1 are hard to tell apart.
+    pub fn delta_e(&self, other: Rgb) -> f64 {
+        self.to_lab().delta_e(other.to_lab())
+    }
+}
+
+/// Formats the color as a hex code, e.g. `#7a2f45`.
+impl fmt::Display for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// Error returned when a string is not a hex color code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRgbError {
+    input: String,
+}
+
+impl fmt::Display for ParseRgbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid hex color: {:?}", self.input)
+    }
+}
+
+impl Error for ParseRgbError {}
+
+/// Parses hex color codes such as `#7a2f45`, `7A2F45` or `#fff`.
+impl FromStr for Rgb {
+    type Err = ParseRgbError;
+
+    fn from_str(s: &str) -> Result<Rgb, ParseRgbError> {
+        let error = || ParseRgbError { input: s.to_string() };
+        let hex = s.trim();
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        if !hex.is_ascii() {
+            return Err(error());
+        }
+        let channel = |digits: &str| u8::from_str_radix(digits, 16).map_err(|_| error());
+        match hex.len() {
+            6 => Ok(Rgb::new(channel(&hex[0..2])?, channel(&hex[2..4])?, channel(&hex[4..6])?)),
+            // Every digit of the short form stands for two, e.g. `f` for `ff`.
+            3 => {
+                let short = |i: usize| channel(&hex[i..i + 1]).map(|c| c * 17);
+                Ok(Rgb::new(short(0)?, short(1)?, short(2)?))
+            }
+            _ => Err(error()),
+        }
+    }
+}
+
+/// A color in CIELAB, where distances roughly match how different colors look.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Lab {
+    /// Lightness, from 0 to 100.
+    pub l: f64,
+    /// Green (negative) to red (positive).
+    pub a: f64,
+    /// Blue (negative) to yellow (positive).
+    pub b: f64,
+}
+
+impl Lab {
+    /// The CIEDE2000 color difference between the two colors.
+    pub fn delta_e(&self, other: Lab) -> f64 {
+        let (l1, a1, b1) = (self.l, self.a, self.b);
+        let (l2, a2, b2) = (other.l, other.a, other.b);
+
+        let c_mean = (a1.hypot(b1) + a2.hypot(b2)) / 2.0;
+        let g = 0.5 * (1.0 - (c_mean.powi(7) / (c_mean.powi(7) + 25f64.powi(7))).sqrt());
+        let (a1, a2) = (a1 * (1.0 + g), a2 * (1.0 + g));
+        let (c1, c2) = (a1.hypot(b1), a2.hypot(b2));
+        let hue = |a: f64, b: f64| {
+            if a == 0.0 && b == 0.0 { 0.0 } else { b.atan2(a).to_degrees().rem_euclid(360.0) }
+        };
+        let (h1, h2) = (hue(a1, b1), hue(a2, b2));
+
+        let delta_l = l2 - l1;
+        let delta_c = c2 - c1;
+        let delta_h = if c1 * c2 == 0.0 {
+            0.0
+        } else if (h2 - h1).abs() <= 180.0 {
+            h2 - h1
+        } else if h2 <= h1 {
+            h2 - h1 + 360.0
+        } else {
+            h2 - h1 - 360.0
+        };
+        let delta_h = 2.0 * (c1 * c2).sqrt() * (delta_h / 2.0).to_radians().sin();
+
+        let l_mean = (l1 + l2) / 2.0;
+        let c_mean = (c1 + c2) / 2.0;
+        let h_mean = if c1 * c2 == 0.0 {
+            h1 + h2
+        } else if (h1 - h2).abs() <= 180.0 {
+            (h1 + h2) / 2.0
+        } else if h1 + h2 < 360.0 {
+            (h1 + h2 + 360.0) / 2.0
+        } else {
+            (h1 + h2 - 360.0) / 2.0
+        };
+
+        let t = 1.0 - 0.17 * (h_mean - 30.0).to_radians().cos()
+            + 0.24 * (2.0 * h_mean).to_radians().cos()
+            + 0.32 * (3.0 * h_mean + 6.0).to_radians().cos()
+            - 0.20 * (4.0 * h_mean - 63.0).to_radians().cos();
+        let delta_theta = 30.0 * (-((h_mean - 275.0) / 25.0).powi(2)).exp();
+        let r_c = 2.0 * (c_mean.powi(7) / (c_mean.powi(7) + 25f64.powi(7))).sqrt();
+        let s_l = 1.0 + 0.015 * (l_mean - 50.0).powi(2) / (20.0 + (l_mean - 50.0).powi(2)).sqrt();
+        let s_c = 1.0 + 0.045 * c_mean;
+        let s_h = 1.0 + 0.015 * c_mean * t;
+        let r_t = -(2.0 * delta_theta).to_radians().sin() * r_c;
+
+        let (l, c, h) = (delta_l / s_l, delta_c / s_c, delta_h / s_h);
+        (l * l + c * c + h * h + r_t * c * h).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::color::*;
+
+    #[test]
+    fn parse_hex() {
+        assert_eq!(Ok(Rgb::new(122, 47, 69)), "#7a2f45".parse());
+        assert_eq!(Ok(Rgb::new(122, 47, 69)), "7A2F45".parse());
+        assert_eq!(Ok(Rgb::new(255, 255, 0)), "#ff0".parse());
+        assert!("#7a2f4".parse::<Rgb>().is_err());
+        assert!("#7a2f4g".parse::<Rgb>().is_err());
+        assert!("#7a2fé".parse::<Rgb>().is_err());
+        assert_eq!("#7a2f45", Rgb::new(122, 47, 69).to_string());
+    }
+
+    #[test]
+    fn rgb_to_lab() {
+        let white = Rgb::new(255, 255, 255).to_lab();
+        assert!((white.l - 100.0).abs() < 1e-3 && white.a.abs() < 1e-3 && white.b.abs() < 1e-3);
+        let red = Rgb::new(255, 0, 0).to_lab();
+        assert!((red.l - 53.24).abs() < 0.01 && (red.a - 80.09).abs() < 0.01);
+        assert!((red.b - 67.20).abs() < 0.01);
+    }
+
+    #[test]
+    fn ciede2000() {
+        // Test data from Sharma, Wu and Dalal, "The CIEDE2000 Color-Difference Formula".
+        let pairs = [
+            ((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), 2.0425),
+            ((50.0, -1.3802, -84.2814), (50.0, 0.0, -82.7485), 1.0000),
+            ((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), 27.1492),
+            ((50.0, 2.5, 0.0), (50.0, 3.2592, 0.3350), 1.0000),
+            ((22.7233, 20.0904, -46.6940), (23.0331, 14.9730, -42.5619), 2.0373),
+            ((2.0776, 0.0795, -1.1350), (0.9033, -0.0636, -0.5514), 0.9082),
+        ];
+        for ((l1, a1, b1), (l2, a2, b2), expected) in pairs {
+            let delta_e = Lab { l: l1, a: a1, b: b1 }.delta_e(Lab { l: l2, a: a2, b: b2 });
+            assert!((delta_e - expected).abs() < 1e-4, "{} != {}", delta_e, expected);
+        }
+    }
+}
@@ -0,0 +1,378 @@
+//! Crafting planner built on top of the recipe endpoints. A `RecipeBook` holds the recipes of an
+//! item and everything it is made of, expands the item into its full recipe tree and, given
+//! trading post prices, works out whether every part of the tree is cheaper to buy or to craft.
+//!
+//! ```no_run
+//! use gw2api::client::Client;
+//! use gw2api::crafting::{self, RecipeBook};
+//! use gw2api::v2::commerce::prices::Price;
+//!
+//! let client = Client::new();
+//! let mut book = RecipeBook::new();
+//! book.load(&client, 46742).unwrap();
+//!
+//! let tree = book.tree(46742, 1);
+//! let items: Vec<u32> = tree.items().into_iter().collect();
+//! let prices = crafting::buy_prices(&client.get_many::<Price>(&items).unwrap());
+//! let plan = tree.cost(&prices);
+//! println!("{:?} to make, buy {:?}", plan.best(), plan.shopping_list());
+//! ```
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use crate::client::Client;
+#[cfg(feature = "async")]
+use crate::client::AsyncClient;
+use crate::coins::Coins;
+use crate::error::ApiError;
+use crate::v1::recipe_details::Recipe;
+use crate::v2::commerce::prices::Price;
+use crate::v2::recipes::search::RecipeSearch;
+
+/// Known recipes, keyed by the item they produce.
+#[derive(Debug, Default)]
+pub struct RecipeBook {
+    recipes: HashMap<u32, Recipe>,
+    /// Items that were looked up and turned out not to be craftable.
+    uncraftable: HashSet<u32>,
+}
+
+impl RecipeBook {
+    pub fn new() -> RecipeBook {
+        RecipeBook::default()
+    }
+
+    /// Adds a recipe, replacing any other recipe for the same item.
+    pub fn insert(&mut self, recipe: Recipe) {
+        self.uncraftable.remove(&recipe.output_item_id);
+        self.recipes.insert(recipe.output_item_id, recipe);
+    }
+
+    /// The recipe producing the item with the given id, if it is known.
+    pub fn get(&self, item_id: u32) -> Option<&Recipe> {
+        self.recipes.get(&item_id)
+    }
+
+    /// Looks up the recipes of the item with the given id and, recursively, of all its
+    /// ingredients, skipping items that were already looked up. When several recipes produce the
+    /// same item, the first one the API returns is used.
+    pub fn load(&mut self, client: &Client, item_id: u32) -> Result<(), ApiError> {
+        let mut pending = vec![item_id];
+        while let Some(item_id) = pending.pop() {
+            if self.is_known(item_id) {
+                continue;
+            }
+            match RecipeSearch::by_output(client, item_id)?.ids.first() {
+                Some(&recipe_id) => {
+                    let recipe = Recipe::get_id(client, recipe_id)?;
+                    pending.extend(recipe.ingredients.iter().map(|ingredient| ingredient.id));
+                    self.insert(recipe);
+                }
+                None => {
+                    self.uncraftable.insert(item_id);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Asynchronous variant of [`load`](Self::load).
+    #[cfg(feature = "async")]
+    pub async fn load_async(&mut self, client: &AsyncClient<'_>, item_id: u32)
+        -> Result<(), ApiError> {
+        let mut pending = vec![item_id];
+        while let Some(item_id) = pending.pop() {
+            if self.is_known(item_id) {
+                continue;
+            }
+            match RecipeSearch::by_output_async(client, item_id).await?.ids.first() {
+                Some(&recipe_id) => {
+                    let recipe = Recipe::get_id_async(client, recipe_id).await?;
+                    pending.extend(recipe.ingredients.iter().map(|ingredient| ingredient.id));
+                    self.insert(recipe);
+                }
+                None => {
+                    self.uncraftable.insert(item_id);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Expands `quantity` of the item with the given id into its recipe tree. Items without a
+    /// known recipe become leaves, as do items whose recipe would need the item itself, such as
+    /// Mystic Forge promotions. Quantities too large for a `u64` are capped at `u64::MAX`.
+    pub fn tree(&self, item_id: u32, quantity: u64) -> CraftingNode {
+        self.expand(item_id, quantity, &mut Vec::new())
+    }
+
+    fn is_known(&self, item_id: u32) -> bool {
+        self.recipes.contains_key(&item_id) || self.uncraftable.contains(&item_id)
+    }
+
+    fn expand(&self, item_id: u32, quantity: u64, path: &mut Vec<u32>) -> CraftingNode {
+        let recipe = match self.recipes.get(&item_id) {
+            Some(recipe) if !path.contains(&item_id) => recipe,
+            _ => return CraftingNode::leaf(item_id, quantity),
+        };
+
+        let crafts = quantity.div_ceil(u64::from(recipe.output_item_count.max(1)));
+        path.push(item_id);
+        let ingredients = recipe.ingredients
+            .iter()
+            .map(|ingredient| {
+                self.expand(ingredient.id, u64::from(ingredient.count).saturating_mul(crafts), path)
+            })
+            .collect();
+        path.pop();
+
+        CraftingNode { item_id, quantity, recipe_id: Some(recipe.id), crafts, ingredients }
+    }
+}
+
+/// A node of a recipe tree: an amount of an item, along with what it is crafted from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CraftingNode {
+    /// Id of the item.
+    pub item_id: u32,
+    /// Amount of the item needed.
+    pub quantity: u64,
+    /// Id of the recipe crafting the item, `None` for items that can not be crafted.
+    pub recipe_id: Option<u32>,
+    /// How many times the recipe has to be crafted. Can produce more than `quantity` for recipes
+    /// that output several items at once.
+    pub crafts: u64,
+    /// The ingredients needed for all crafts, empty for items that can not be crafted.
+    pub ingredients: Vec<CraftingNode>,
+}
+
+impl CraftingNode {
+    fn leaf(item_id: u32, quantity: u64) -> CraftingNode {
+        CraftingNode { item_id, quantity, recipe_id: None, crafts: 0, ingredients: Vec::new() }
+    }
+
+    /// Whether the item is crafted rather than a raw material.
+    pub fn is_craftable(&self) -> bool {
+        self.recipe_id.is_some()
+    }
+
+    /// Total amount of every raw material needed when crafting everything in the tree.
+    pub fn raw_materials(&self) -> BTreeMap<u32, u64> {
+        let mut materials = BTreeMap::new();
+        self.collect_raw_materials(&mut materials);
+        materials
+    }
+
+    /// Ids of every item in the tree, e.g. to request their prices in one go.
+    pub fn items(&self) -> BTreeSet<u32> {
+        let mut items = BTreeSet::new();
+        let mut pending = vec![self];
+        while let Some(node) = pending.pop() {
+            items.insert(node.item_id);
+            pending.extend(&node.ingredients);
+        }
+        items
+    }
+
+    /// Works out the cost of buying and of crafting every node of the tree, using the given
+    /// price per item. Items without a price can only be crafted.
+    pub fn cost(&self, prices: &HashMap<u32, Coins>) -> CostNode {
+        let ingredients: Vec<CostNode> = self.ingredients
+            .iter()
+            .map(|ingredient| ingredient.cost(prices))
+            .collect();
+        let buy = prices.get(&self.item_id).and_then(|price| price.checked_mul(self.quantity));
+        let craft = if self.is_craftable() {
+            ingredients.iter().try_fold(Coins::ZERO, |total, ingredient| {
+                total.checked_add(ingredient.best()?)
+            })
+        } else {
+            None
+        };
+        CostNode { item_id: self.item_id, quantity: self.quantity, buy, craft, ingredients }
+    }
+
+    fn collect_raw_materials(&self, materials: &mut BTreeMap<u32, u64>) {
+        if self.ingredients.is_empty() {
+            let amount = materials.entry(self.item_id).or_default();
+            *amount = self.quantity.saturating_add(*amount);
+        }
+        for ingredient in &self.ingredients {
+            ingredient.collect_raw_materials(materials);
+        }
+    }
+}
+
+/// Whether a node of a recipe tree is cheaper to buy or to craft.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    Buy,
+    Craft,
+}
+
+/// The buy and craft costs of a node of a recipe tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CostNode {
+    /// Id of the item.
+    pub item_id: u32,
+    /// Amount of the item needed.
+    pub quantity: u64,
+    /// Price of buying the whole quantity, `None` if the item has no price.
+    pub buy: Option<Coins>,
+    /// Price of crafting the whole quantity from the cheapest ingredients, `None` if the item can
+    /// not be crafted or an ingredient can neither be bought nor crafted.
+    pub craft: Option<Coins>,
+    /// Costs of the ingredients.
+    pub ingredients: Vec<CostNode>,
+}
+
+impl CostNode {
+    /// Whether to buy or to craft the item, preferring to buy when both cost the same. `None` if
+    /// the item can neither be bought nor crafted.
+    pub fn decision(&self) -> Option<Decision> {
+        match (self.buy, self.craft) {
+            (Some(buy), Some(craft)) if craft < buy => Some(Decision::Craft),
+            (Some(_), _) => Some(Decision::Buy),
+            (None, Some(_)) => Some(Decision::Craft),
+            (None, None) => None,
+        }
+    }
+
+    /// The cheaper of buying and crafting.
+    pub fn best(&self) -> Option<Coins> {
+        match self.decision()? {
+            Decision::Buy => self.buy,
+            Decision::Craft => self.craft,
+        }
+    }
+
+    /// The items to buy, and how many, when following the cheapest decision at every node. Items
+    /// that can neither be bought nor crafted are left out.
+    pub fn shopping_list(&self) -> BTreeMap<u32, u64> {
+        let mut list = BTreeMap::new();
+        self.collect_shopping_list(&mut list);
+        list
+    }
+
+    fn collect_shopping_list(&self, list: &mut BTreeMap<u32, u64>) {
+        match self.decision() {
+            Some(Decision::Buy) => {
+                let amount = list.entry(self.item_id).or_default();
+                *amount = self.quantity.saturating_add(*amount);
+            }
+            Some(Decision::Craft) => {
+                for ingredient in &self.ingredients {
+                    ingredient.collect_shopping_list(list);
+                }
+            }
+            None => (),
+        }
+    }
+}
+
+/// Instant buy prices of the given trading post prices, i.e. their lowest sell offers. Items
+/// nobody is selling are left out.
+pub fn buy_prices(prices: &[Price]) -> HashMap<u32, Coins> {
+    prices
+        .iter()
+        .filter(|price| price.sells.quantity > 0)
+        .map(|price| (price.id, price.sells.unit_price))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crafting::*;
+
+    // Simplified Mithril Ingot and Deldrimor Steel Ingot recipes, plus a promotion of Mithril Ore
+    // into itself to check that cycles end up as leaves.
+    const JSON_RECIPES: &str = r#"
+    [
+      {
+        "recipe_id": "19", "type": "Refinement", "output_item_id": "19684",
+        "output_item_count": "1", "min_rating": "225", "time_to_craft_ms": "1000",
+        "ingredients": [{ "item_id": "19700", "count": "2" }]
+      },
+      {
+        "recipe_id": "7314", "type": "Refinement", "output_item_id": "46738",
+        "output_item_count": "1", "min_rating": "450", "time_to_craft_ms": "5000",
+        "ingredients": [
+          { "item_id": "19684", "count": "5" },
+          { "item_id": "19721", "count": "1" }
+        ]
+      },
+      {
+        "recipe_id": "9000", "type": "Component", "output_item_id": "19700",
+        "output_item_count": "5", "min_rating": "0", "time_to_craft_ms": "1000",
+        "ingredients": [{ "item_id": "19684", "count": "1" }]
+      }
+    ]"#;
+
+    fn book() -> RecipeBook {
+        let mut book = RecipeBook::new();
+        for recipe in serde_json::from_str::<Vec<Recipe>>(JSON_RECIPES).unwrap() {
+            book.insert(recipe);
+        }
+        book
+    }
+
+    fn prices(prices: &[(u32, u64)]) -> HashMap<u32, Coins> {
+        prices.iter().map(|&(id, copper)| (id, Coins::from_copper(copper))).collect()
+    }
+
+    #[test]
+    fn expand_tree() {
+        let tree = book().tree(46738, 2);
+        assert_eq!(Some(7314), tree.recipe_id);
+        assert_eq!(2, tree.crafts);
+        let ingot = &tree.ingredients[0];
+        assert_eq!((19684, 10), (ingot.item_id, ingot.quantity));
+        let ore = &ingot.ingredients[0];
+        assert_eq!((19700, 20), (ore.item_id, ore.quantity));
+        // 20 ore at 5 per craft, with the ingot needed by the ore's own recipe ending the cycle.
+        assert_eq!(4, ore.crafts);
+        assert_eq!(CraftingNode::leaf(19684, 4), ore.ingredients[0]);
+    }
+
+    #[test]
+    fn aggregate_raw_materials() {
+        let materials = book().tree(46738, 2).raw_materials();
+        assert_eq!(BTreeMap::from([(19684, 4), (19721, 2)]), materials);
+        assert_eq!(BTreeSet::from([19684, 19700, 19721, 46738]), book().tree(46738, 2).items());
+    }
+
+    #[test]
+    fn craft_when_cheaper() {
+        let prices = prices(&[(46738, 5000), (19684, 600), (19700, 100), (19721, 2500)]);
+        let plan = book().tree(46738, 1).cost(&prices);
+        let ingot = &plan.ingredients[0];
+        // Buying 10 ore is cheaper than buying 5 ingots.
+        assert_eq!(Some(Decision::Craft), ingot.decision());
+        assert_eq!(Some(Coins::from_copper(1000)), ingot.best());
+        assert_eq!(Some(Coins::from_copper(3500)), plan.craft);
+        assert_eq!(Some(Decision::Craft), plan.decision());
+        assert_eq!(BTreeMap::from([(19700, 10), (19721, 1)]), plan.shopping_list());
+    }
+
+    #[test]
+    fn buy_when_cheaper_or_uncraftable() {
+        let prices = prices(&[(46738, 3000), (19684, 300), (19700, 100)]);
+        let plan = book().tree(46738, 1).cost(&prices);
+        // Glob of Ectoplasm has no price, so the steel can't be crafted from bought materials.
+        assert_eq!(None, plan.craft);
+        assert_eq!(Some(Decision::Buy), plan.decision());
+        assert_eq!(BTreeMap::from([(46738, 1)]), plan.shopping_list());
+
+        let plan = book().tree(19721, 1).cost(&prices);
+        assert_eq!(None, plan.decision());
+        assert!(plan.shopping_list().is_empty());
+    }
+
+    #[test]
+    fn huge_quantities_saturate() {
+        let tree = book().tree(46738, u64::MAX / 2);
+        assert_eq!(u64::MAX, tree.ingredients[0].ingredients[0].quantity);
+
+        let prices = prices(&[(19684, 600), (19700, 100), (19721, 2500)]);
+        assert_eq!(None, tree.cost(&prices).craft);
+    }
+}
@@ -0,0 +1,237 @@
+//! Renders guild emblems, available behind the `emblem` feature. The background and foreground
+//! layers are fetched from `/v2/emblem` and the render service, tinted with the cloth color of
+//! the emblem's dyes and flipped as the emblem's flags say.
+//!
+//! ```no_run
+//! use gw2api::client::Client;
+//! use gw2api::v1::colors::Colors;
+//! use gw2api::v1::guild_details::Guild;
+//!
+//! let client = Client::new();
+//! let colors = Colors::get_all_dyes(&client).unwrap();
+//! let guild = Guild::get_by_name(&client, "Veterans Of Lions Arch".to_string()).unwrap();
+//! let image = gw2api::emblem::render(&client, &guild.emblem.unwrap(), &colors).unwrap();
+//! image.save("emblem.png").unwrap();
+//! ```
+use image::{imageops, ImageError, Rgba, RgbaImage};
+
+use std::error::Error;
+use std::fmt;
+
+#[cfg(feature = "async")]
+use crate::client::AsyncClient;
+use crate::client::{Client, RENDER_URL};
+use crate::error::ApiError;
+use crate::v1::colors::Colors;
+use crate::v1::guild_details::{Emblem, Flag};
+use crate::v2::emblem::{EmblemBackground, EmblemForeground};
+
+/// Why an emblem could not be rendered.
+#[derive(Debug)]
+pub enum EmblemError {
+    /// Fetching the emblem layers failed.
+    Api(ApiError),
+    /// A layer is not a valid image.
+    Image(ImageError),
+    /// The emblem uses a dye that is not in the given colors.
+    UnknownColor(u32),
+    /// The background or foreground with the given id lacks one of its layers.
+    MissingLayer(u32),
+}
+
+impl fmt::Display for EmblemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Api(e) => write!(f, "unable to fetch emblem layer: {}", e),
+            Self::Image(e) => write!(f, "unable to decode emblem layer: {}", e),
+            Self::UnknownColor(id) => write!(f, "unknown emblem color {}", id),
+            Self::MissingLayer(id) => write!(f, "emblem part {} is missing a layer", id),
+        }
+    }
+}
+
+impl Error for EmblemError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Api(e) => Some(e),
+            Self::Image(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ApiError> for EmblemError {
+    fn from(e: ApiError) -> Self {
+        Self::Api(e)
+    }
+}
+
+impl From<ImageError> for EmblemError {
+    fn from(e: ImageError) -> Self {
+        Self::Image(e)
+    }
+}
+
+/// Fetch the layers of the emblem and render it, with the dyes looked up in `colors`.
+pub fn render(client: &Client, emblem: &Emblem, colors: &Colors)
+    -> Result<RgbaImage, EmblemError> {
+    let background = client.get::<EmblemBackground>(&emblem.background_id)?;
+    let foreground = client.get::<EmblemForeground>(&emblem.foreground_id)?;
+    let [background, primary, secondary] = layer_urls(&background, &foreground)?;
+    let download = |url| client.download(&render_url(client.render_url(), url));
+    composite(emblem, colors, &download(background)?, &download(primary)?, &download(secondary)?)
+}
+
+/// Asynchronous variant of [`render`].
+#[cfg(feature = "async")]
+pub async fn render_async(client: &AsyncClient<'_>, emblem: &Emblem, colors: &Colors)
+    -> Result<RgbaImage, EmblemError> {
+    let background = client.get::<EmblemBackground>(&emblem.background_id).await?;
+    let foreground = client.get::<EmblemForeground>(&emblem.foreground_id).await?;
+    let [background, primary, secondary] = layer_urls(&background, &foreground)?
+        .map(|url| render_url(client.render_url(), url));
+    let background = client.download(&background).await?;
+    let primary = client.download(&primary).await?;
+    let secondary = client.download(&secondary).await?;
+    composite(emblem, colors, &background, &primary, &secondary)
+}
+
+/// Render an emblem from the already downloaded images of its background layer and its primary
+/// and secondary foreground layers.
+pub fn composite(emblem: &Emblem, colors: &Colors, background: &[u8], primary: &[u8],
+    secondary: &[u8]) -> Result<RgbaImage, EmblemError> {
+    let flags = &emblem.flags;
+    let mut background = tinted(background, colors, emblem.background_color_id)?;
+    flip(&mut background, flags.contains(&Flag::FlipBackgroundHorizontal),
+        flags.contains(&Flag::FlipBackgroundVertical));
+
+    for (layer, color_id) in [
+        (primary, emblem.foreground_primary_color_id),
+        (secondary, emblem.foreground_secondary_color_id),
+    ] {
+        let mut layer = tinted(layer, colors, color_id)?;
+        flip(&mut layer, flags.contains(&Flag::FlipForegroundHorizontal),
+            flags.contains(&Flag::FlipForegroundVertical));
+        if layer.dimensions() != background.dimensions() {
+            let (width, height) = background.dimensions();
+            layer = imageops::resize(&layer, width, height, imageops::FilterType::Triangle);
+        }
+        imageops::overlay(&mut background, &layer, 0, 0);
+    }
+    Ok(background)
+}
+
+/// The urls of the background, primary and secondary layers.
+fn layer_urls<'a>(background: &'a EmblemBackground, foreground: &'a EmblemForeground)
+    -> Result<[&'a str; 3], EmblemError> {
+    Ok([
+        background.layers.first().ok_or(EmblemError::MissingLayer(background.id))?,
+        foreground.primary_layer().ok_or(EmblemError::MissingLayer(foreground.id))?,
+        foreground.secondary_layer().ok_or(EmblemError::MissingLayer(foreground.id))?,
+    ])
+}
+
+/// Points the layer url at the render service the client is configured with.
+fn render_url(base_url: &str, url: &str) -> String {
+    match url.strip_prefix(RENDER_URL) {
+        Some(path) => format!("{}{}", base_url, path),
+        None => url.to_string(),
+    }
+}
+
+/// Decodes the layer and multiplies it with the cloth color of the dye, keeping its alpha.
+fn tinted(layer: &[u8], colors: &Colors, color_id: u32) -> Result<RgbaImage, EmblemError> {
+    let rgb = match colors.colors.get(&color_id).map(|dye| dye.cloth.rgb.as_slice()) {
+        Some(&[r, g, b, ..]) => [r, g, b],
+        _ => return Err(EmblemError::UnknownColor(color_id)),
+    };
+    let mut image = image::load_from_memory(layer)?.to_rgba8();
+    for Rgba(pixel) in image.pixels_mut() {
+        for (channel, tint) in pixel.iter_mut().zip(rgb) {
+            *channel = (u16::from(*channel) * u16::from(tint) / 255) as u8;
+        }
+    }
+    Ok(image)
+}
+
+fn flip(image: &mut RgbaImage, horizontal: bool, vertical: bool) {
+    if horizontal {
+        imageops::flip_horizontal_in_place(image);
+    }
+    if vertical {
+        imageops::flip_vertical_in_place(image);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emblem::*;
+    use crate::v1::colors::{Dye, Material};
+
+    use image::ImageFormat;
+    use std::collections::HashMap;
+    use std::io::Cursor;
+
+    fn dye(rgb: [u8; 3]) -> Dye {
+        Dye {
+            name: String::new(),
+            rgb: rgb.to_vec(),
+            cloth: Material { rgb: rgb.to_vec(), ..Material::default() },
+            leather: Material::default(),
+            metal: Material::default(),
+            fur: Material::default(),
+            item_id: 0,
+            categories: Vec::new(),
+        }
+    }
+
+    fn png(image: &RgbaImage) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        image.write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn composite_layers() {
+        let colors = Colors {
+            colors: HashMap::from([
+                (1, dye([255, 0, 0])),
+                (2, dye([0, 255, 0])),
+                (3, dye([0, 0, 255])),
+            ]),
+        };
+        let mut emblem = Emblem {
+            background_id: 1,
+            foreground_id: 1,
+            flags: vec![Flag::FlipForegroundHorizontal],
+            background_color_id: 1,
+            foreground_primary_color_id: 2,
+            foreground_secondary_color_id: 3,
+        };
+
+        let background = png(&RgbaImage::from_pixel(4, 4, Rgba([255, 255, 255, 255])));
+        // The primary layer covers the left half, the secondary one the top right pixel.
+        let mut primary = RgbaImage::new(4, 4);
+        for (_, _, pixel) in primary.enumerate_pixels_mut().filter(|(x, _, _)| *x < 2) {
+            *pixel = Rgba([255, 255, 255, 255]);
+        }
+        let mut secondary = RgbaImage::new(4, 4);
+        secondary.put_pixel(3, 0, Rgba([128, 128, 128, 255]));
+
+        let image = composite(&emblem, &colors, &background, &png(&primary), &png(&secondary))
+            .unwrap();
+        assert_eq!(&Rgba([255, 0, 0, 255]), image.get_pixel(0, 3));
+        assert_eq!(&Rgba([0, 255, 0, 255]), image.get_pixel(3, 3));
+        assert_eq!(&Rgba([0, 0, 128, 255]), image.get_pixel(0, 0));
+
+        emblem.foreground_secondary_color_id = 4;
+        let err = composite(&emblem, &colors, &background, &png(&primary), &png(&secondary));
+        assert!(matches!(err, Err(EmblemError::UnknownColor(4))));
+    }
+
+    #[test]
+    fn layer_render_url() {
+        let url = "https://render.guildwars2.com/file/ABC/59577.png";
+        assert_eq!("http://localhost/file/ABC/59577.png", render_url("http://localhost", url));
+    }
+}
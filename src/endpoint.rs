@@ -0,0 +1,140 @@
+//! Traits describing the endpoints of the API, which lets the `Client` retrieve any type in the
+//! same way, e.g. `client.get::<Amulet>(&4)` or `client.get_all::<Hero>()`.
+use serde::de::DeserializeOwned;
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+
+use crate::error::{ApiError, ApiErrorKind};
+use crate::utils::{ids_to_string, percent_encode};
+
+/// The maximum amount of ids the official API accepts in a single `ids=` request.
+pub const MAX_IDS_PER_REQUEST: usize = 200;
+
+/// Message the API responds with when none of the requested ids exist.
+const ALL_IDS_INVALID: &str = "all ids provided are invalid";
+
+/// A type that is returned by an endpoint of the API.
+pub trait Endpoint: DeserializeOwned + Send {
+    /// Url of the endpoint, including the version, e.g. `/v2/pvp/amulets`.
+    const URL: &'static str;
+    /// Whether the endpoint requires an API key to be set on the client.
+    const AUTHENTICATED: bool = false;
+}
+
+/// An endpoint that returns a list of ids when requested directly and supports requesting objects
+/// through the `id`, `ids` and `ids=all` query parameters.
+pub trait BulkEndpoint: Endpoint {
+    /// The type of the id used by the endpoint, usually `u32` or a UUID `String`.
+    type Id: Display + DeserializeOwned + Clone + Eq + Hash + Send + Sync;
+
+    /// Returns the id of the object.
+    fn id(&self) -> &Self::Id;
+}
+
+/// Result of requesting multiple objects by their ids.
+#[derive(Debug, PartialEq)]
+pub struct BulkResult<T: BulkEndpoint> {
+    /// The objects that were found, in the same order as the requested ids.
+    pub found: Vec<T>,
+    /// The requested ids that the API did not return an object for.
+    pub missing: Vec<T::Id>,
+}
+
+/// Url to retrieve a single object by its id.
+pub(crate) fn id_url<T: BulkEndpoint>(id: &T::Id) -> String {
+    format!("{}?id={}", T::URL, percent_encode(&id.to_string()))
+}
+
+/// Url to retrieve multiple objects by their ids.
+pub(crate) fn ids_url<T: BulkEndpoint>(ids: &[T::Id]) -> String {
+    let ids = ids.iter().map(|id| percent_encode(&id.to_string()));
+    format!("{}?ids={}", T::URL, ids_to_string(ids))
+}
+
+/// Removes duplicate ids, keeping the first occurrence, and splits the rest up into chunks that
+/// the API will accept.
+pub(crate) fn chunk_ids<T: BulkEndpoint>(ids: &[T::Id]) -> Vec<Vec<T::Id>> {
+    let mut seen = HashSet::new();
+    let unique: Vec<T::Id> = ids.iter()
+        .filter(|id| seen.insert(*id))
+        .cloned()
+        .collect();
+    unique.chunks(MAX_IDS_PER_REQUEST).map(|chunk| chunk.to_vec()).collect()
+}
+
+/// The API responds with a 404 when none of the requested ids exist, which for a single chunk of
+/// a bulk request just means that every id in it is missing.
+pub(crate) fn none_found<T>(result: Result<Vec<T>, ApiError>) -> Result<Vec<T>, ApiError> {
+    match result {
+        Err(e) if matches!(e.kind(), ApiErrorKind::NotFound(r) if r.text == ALL_IDS_INVALID) => {
+            Ok(Vec::new())
+        }
+        result => result,
+    }
+}
+
+/// Merges the responses of every chunk back into the order of the requested `ids`, and collects
+/// the ids the API did not return anything for.
+pub(crate) fn merge_chunks<T: BulkEndpoint>(chunks: Vec<Vec<T::Id>>, responses: Vec<Vec<T>>)
+    -> BulkResult<T> {
+    let mut objects: HashMap<T::Id, T> = responses.into_iter()
+        .flatten()
+        .map(|object| (object.id().clone(), object))
+        .collect();
+
+    let mut result = BulkResult { found: Vec::new(), missing: Vec::new() };
+    for id in chunks.into_iter().flatten() {
+        match objects.remove(&id) {
+            Some(object) => result.found.push(object),
+            None => result.missing.push(id),
+        }
+    }
+    result
+}
+
+/// Url to retrieve every object of the endpoint.
+pub(crate) fn all_url<T: BulkEndpoint>() -> String {
+    format!("{}?ids=all", T::URL)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::endpoint::*;
+    use crate::v2::pvp::ranks::{Levels, Rank};
+
+    fn rank(id: u32) -> Rank {
+        Rank {
+            id,
+            finisher_id: id,
+            name: id.to_string(),
+            icon_url: String::new(),
+            min_rank: 0,
+            max_rank: 0,
+            levels: vec![Levels { min_rank: 0, max_rank: 0, points: 0 }],
+        }
+    }
+
+    #[test]
+    fn chunk_ids_splits_and_dedups() {
+        let ids: Vec<u32> = (0..450).chain(0..10).collect();
+        let chunks = chunk_ids::<Rank>(&ids);
+        assert_eq!(vec![200, 200, 50], chunks.iter().map(Vec::len).collect::<Vec<_>>());
+        assert_eq!((0..450).collect::<Vec<u32>>(), chunks.concat());
+    }
+
+    #[test]
+    fn chunk_no_ids() {
+        assert!(chunk_ids::<Rank>(&[]).is_empty());
+    }
+
+    #[test]
+    fn merge_keeps_input_order() {
+        let chunks = vec![vec![3, 1], vec![4, 2]];
+        let responses = vec![vec![rank(1), rank(3)], vec![rank(2)]];
+        let result = merge_chunks(chunks, responses);
+        assert_eq!(vec![3, 1, 2], result.found.iter().map(|r| r.id).collect::<Vec<_>>());
+        assert_eq!(vec![4], result.missing);
+    }
+}
@@ -1,3 +1,5 @@
+use serde::Deserialize;
+
 use std::fmt::{self, Display};
 use std::error::Error;
 
@@ -13,17 +15,9 @@ pub struct ApiError {
     err: Box<ApiErrorKind>,
 }
 
-// impl de::Error for ApiError {
-//     fn custom<T: std::string::ToString>(msg: T) -> Self {
-//         ApiError {
-//             text: msg.to_string(),
-//         }
-//     }
-// }
-
 impl Display for ApiError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_str(&self.to_string())
+        self.err.fmt(f)
     }
 }
 
@@ -41,6 +35,23 @@ impl From<minreq::Error> for ApiError {
     }
 }
 
+#[cfg(feature = "async")]
+impl From<reqwest::Error> for ApiError {
+    fn from(err: reqwest::Error) -> Self {
+        Self {
+            err: Box::new(ApiErrorKind::AsyncClientError(err))
+        }
+    }
+}
+
+impl From<serde_json::Error> for ApiError {
+    fn from(err: serde_json::Error) -> Self {
+        Self {
+            err: Box::new(ApiErrorKind::DeserializeError(err))
+        }
+    }
+}
+
 impl ApiError {
     /// Create a new ApiError from any type T that implements the Display trait.
     pub fn new(err: ApiErrorKind) -> ApiError {
@@ -48,29 +59,125 @@ impl ApiError {
             err: Box::new(err),
         }
     }
+
+    /// Create an ApiError from the status code and body of an unsuccessful response.
+    pub fn from_response(status: u16, body: &[u8]) -> ApiError {
+        let response = ErrorResponse::new(status, body);
+        let kind = match status {
+            400 => ApiErrorKind::BadRequest(response),
+            401 => ApiErrorKind::Unauthorized(response),
+            403 => ApiErrorKind::Forbidden(response),
+            404 => ApiErrorKind::NotFound(response),
+            408 => ApiErrorKind::ApiTimeout(response),
+            429 => ApiErrorKind::TooManyRequests(response),
+            500..=599 => ApiErrorKind::ServerError(response),
+            _ => ApiErrorKind::UnexpectedStatus(response),
+        };
+        ApiError::new(kind)
+    }
+
+    /// Returns the kind of error that occurred.
+    pub fn kind(&self) -> &ApiErrorKind {
+        &self.err
+    }
+
+    /// Returns the status code and message of the response, if the error was returned by the API.
+    pub fn response(&self) -> Option<&ErrorResponse> {
+        match self.kind() {
+            ApiErrorKind::BadRequest(r)
+            | ApiErrorKind::Unauthorized(r)
+            | ApiErrorKind::Forbidden(r)
+            | ApiErrorKind::NotFound(r)
+            | ApiErrorKind::ApiTimeout(r)
+            | ApiErrorKind::TooManyRequests(r)
+            | ApiErrorKind::ServerError(r)
+            | ApiErrorKind::UnexpectedStatus(r) => Some(r),
+            _ => None,
+        }
+    }
+
+    /// Returns the HTTP status code, if the error was returned by the API.
+    pub fn status(&self) -> Option<u16> {
+        self.response().map(|r| r.status)
+    }
+
+    /// Returns the message the API sent along with the error, e.g. `"no such id"`.
+    pub fn text(&self) -> Option<&str> {
+        self.response().map(|r| r.text.as_str())
+    }
+}
+
+/// The status code and message of a response the API rejected the request with. The API sends
+/// bodies like `{"text": "Invalid access token"}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorResponse {
+    /// HTTP status code of the response.
+    pub status: u16,
+    /// The `text` field of the response, or the raw body if it was not in the expected format.
+    pub text: String,
+}
+
+impl ErrorResponse {
+    fn new(status: u16, body: &[u8]) -> ErrorResponse {
+        #[derive(Deserialize)]
+        struct Body {
+            text: String,
+        }
+
+        let text = match serde_json::from_slice::<Body>(body) {
+            Ok(body) => body.text,
+            Err(_) => String::from_utf8_lossy(body).trim().to_string(),
+        };
+        ErrorResponse { status, text }
+    }
+}
+
+impl Display for ErrorResponse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.text.is_empty() {
+            write!(f, "(status {})", self.status)
+        } else {
+            write!(f, "{} (status {})", self.text, self.status)
+        }
+    }
 }
 
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum ApiErrorKind {
     ClientError(minreq::Error),
-    ApiTimeout,
-    Forbidden,
-    NotFound,
+    #[cfg(feature = "async")]
+    AsyncClientError(reqwest::Error),
+    /// The response could not be deserialized into the requested type.
+    DeserializeError(serde_json::Error),
+    /// 400, usually caused by malformed query parameters.
+    BadRequest(ErrorResponse),
+    /// 401, the API key is invalid.
+    Unauthorized(ErrorResponse),
+    /// 403, the API key lacks the permissions for the resource.
+    Forbidden(ErrorResponse),
+    /// 404, the endpoint or the requested id(s) do not exist.
+    NotFound(ErrorResponse),
+    /// 408, the API timed out.
+    ApiTimeout(ErrorResponse),
+    /// 429, the rate limit of the API has been exceeded.
+    TooManyRequests(ErrorResponse),
+    /// 5xx, the API is having problems or is down for maintenance.
+    ServerError(ErrorResponse),
+    /// Any other status code the API is not documented to respond with.
+    UnexpectedStatus(ErrorResponse),
     ApiKeyNotSet,
     Custom(String),
-    //DeserializeError(&dyn de::Error),
 }
 
 impl Error for ApiErrorKind {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             Self::ClientError(e) => Some(e),
-            Self::ApiTimeout => None,
-            Self::Forbidden => None,
-            Self::NotFound => None,
-            Self::ApiKeyNotSet => None,
-            Self::Custom(..) => None,
+            #[cfg(feature = "async")]
+            Self::AsyncClientError(e) => Some(e),
+            Self::DeserializeError(e) => Some(e),
+            _ => None,
         }
     }
 }
@@ -79,12 +186,46 @@ impl Display for ApiErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::ClientError(e) => f.write_str(&e.to_string()),
-            Self::ApiTimeout => f.write_str("Client timed out. Check your internet connection or the status of the official API."),
-            Self::Forbidden => f.write_str("Unable to access resource. You probably lack the appropriate premissions on your GW2 API key to access this resource."),
-            Self::NotFound => f.write_str("Unable to find the endpoint."),
+            #[cfg(feature = "async")]
+            Self::AsyncClientError(e) => f.write_str(&e.to_string()),
+            Self::DeserializeError(e) => write!(f, "Unable to deserialize the response: {}", e),
+            Self::BadRequest(r) => write!(f, "The API rejected the request: {}", r),
+            Self::Unauthorized(r) => write!(f, "The GW2 API key is invalid: {}", r),
+            Self::Forbidden(r) => write!(f, "Unable to access resource. You probably lack the appropriate premissions on your GW2 API key to access this resource: {}", r),
+            Self::NotFound(r) => write!(f, "Unable to find the endpoint or the requested ids: {}", r),
+            Self::ApiTimeout(r) => write!(f, "The API timed out. Check the status of the official API: {}", r),
+            Self::TooManyRequests(r) => write!(f, "Too many requests, slow down: {}", r),
+            Self::ServerError(r) => write!(f, "The API is having issues or is down for maintenance: {}", r),
+            Self::UnexpectedStatus(r) => write!(f, "Unexpected response from the API: {}", r),
             Self::ApiKeyNotSet => f.write_str("GW2 API key not set while trying to access resource that needs one."),
             Self::Custom(s) => f.write_str(s),
-            //Self::DeserializeError(e) => f.write(&e.to_string()),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::error::*;
+
+    #[test]
+    fn parse_error_text() {
+        let err = ApiError::from_response(401, br#"{"text": "Invalid access token"}"#);
+        assert!(matches!(err.kind(), ApiErrorKind::Unauthorized(_)));
+        assert_eq!(Some(401), err.status());
+        assert_eq!(Some("Invalid access token"), err.text());
+    }
+
+    #[test]
+    fn error_kinds_by_status() {
+        assert!(matches!(ApiError::from_response(400, b"").kind(), ApiErrorKind::BadRequest(_)));
+        assert!(matches!(ApiError::from_response(429, b"").kind(), ApiErrorKind::TooManyRequests(_)));
+        assert!(matches!(ApiError::from_response(503, b"").kind(), ApiErrorKind::ServerError(_)));
+        assert!(matches!(ApiError::from_response(418, b"").kind(), ApiErrorKind::UnexpectedStatus(_)));
+    }
+
+    #[test]
+    fn unstructured_error_body() {
+        let err = ApiError::from_response(502, b"<html>Bad Gateway</html>\n");
+        assert_eq!(Some("<html>Bad Gateway</html>"), err.text());
+    }
+}
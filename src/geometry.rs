@@ -0,0 +1,314 @@
+//! Conversions between the coordinate systems of the game. Positions reported by MumbleLink are
+//! in meters, map coordinates (`map_rect`) are in inches with y pointing north, and continent
+//! coordinates (`continent_rect` and everything in `v1::map_floor`) are in pixels of the world
+//! map at its highest zoom level with y pointing south. Map tiles split the continent into
+//! squares of `TILE_SIZE` pixels, halving the resolution with every zoom level below the highest.
+//!
+//! ```
+//! use gw2api::geometry::{MapTransform, Point2, Rect};
+//!
+//! // Queensdale
+//! let transform = MapTransform::new(
+//!     Rect::new(Point2::new(-43008.0, -27648.0), Point2::new(43008.0, 30720.0)),
+//!     Rect::new(Point2::new(9856.0, 11648.0), Point2::new(13440.0, 14080.0)),
+//! );
+//! let point = transform.map_to_continent(Point2::new(0.0, 3072.0));
+//! assert_eq!(Point2::new(11648.0, 12800.0), point);
+//! ```
+//!
+//! The types deserialize from the arrays the API uses, e.g. `[x, y]` for a `Point2` and
+//! `[[x0, y0], [x1, y1]]` for a `Rect`.
+use serde::{Deserialize, Serialize};
+
+/// Map coordinates are in inches, MumbleLink positions in meters.
+pub const INCHES_PER_METER: f64 = 39.3701;
+/// Width and height of a map tile in pixels.
+pub const TILE_SIZE: u32 = 256;
+
+/// A point in one of the coordinate systems.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize, Serialize)]
+#[serde(from = "[f64; 2]", into = "[f64; 2]")]
+pub struct Point2 {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Point2 {
+    pub fn new(x: f64, y: f64) -> Point2 {
+        Point2 { x, y }
+    }
+
+    /// Distance between two points.
+    pub fn distance(&self, other: Point2) -> f64 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+}
+
+impl From<[f64; 2]> for Point2 {
+    fn from([x, y]: [f64; 2]) -> Point2 {
+        Point2::new(x, y)
+    }
+}
+
+impl From<Point2> for [f64; 2] {
+    fn from(point: Point2) -> [f64; 2] {
+        [point.x, point.y]
+    }
+}
+
+/// A point in the three dimensional space of a map, such as the center of an event. Its `z` axis
+/// points down.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize, Serialize)]
+#[serde(from = "[f64; 3]", into = "[f64; 3]")]
+pub struct Point3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Point3 {
+    pub fn new(x: f64, y: f64, z: f64) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    /// The point without its height.
+    pub fn xy(&self) -> Point2 {
+        Point2::new(self.x, self.y)
+    }
+}
+
+impl From<[f64; 3]> for Point3 {
+    fn from([x, y, z]: [f64; 3]) -> Point3 {
+        Point3::new(x, y, z)
+    }
+}
+
+impl From<Point3> for [f64; 3] {
+    fn from(point: Point3) -> [f64; 3] {
+        [point.x, point.y, point.z]
+    }
+}
+
+/// An axis-aligned rectangle.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize, Serialize)]
+#[serde(from = "[Point2; 2]", into = "[Point2; 2]")]
+pub struct Rect {
+    /// The corner with the smallest coordinates.
+    pub min: Point2,
+    /// The corner with the largest coordinates.
+    pub max: Point2,
+}
+
+impl Rect {
+    /// The rectangle spanned by two opposite corners, in any order.
+    pub fn new(a: Point2, b: Point2) -> Rect {
+        Rect {
+            min: Point2::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point2::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    pub fn width(&self) -> f64 {
+        self.max.x - self.min.x
+    }
+
+    pub fn height(&self) -> f64 {
+        self.max.y - self.min.y
+    }
+
+    /// Whether the point lies within the rectangle, including its edges.
+    pub fn contains(&self, point: Point2) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+}
+
+/// The API gives rects as two opposite corners, which are not always the minimum and maximum.
+impl From<[Point2; 2]> for Rect {
+    fn from([a, b]: [Point2; 2]) -> Rect {
+        Rect::new(a, b)
+    }
+}
+
+impl From<Rect> for [Point2; 2] {
+    fn from(rect: Rect) -> [Point2; 2] {
+        [rect.min, rect.max]
+    }
+}
+
+/// Whether the point lies within the polygon given by its corners, such as `Sector::bounds`.
+pub fn polygon_contains(polygon: &[Point2], point: Point2) -> bool {
+    // Count how many edges a ray from the point to the right crosses.
+    let mut inside = false;
+    let mut previous = match polygon.last() {
+        Some(corner) => *corner,
+        None => return false,
+    };
+    for &corner in polygon {
+        if (corner.y > point.y) != (previous.y > point.y) {
+            let t = (point.y - corner.y) / (previous.y - corner.y);
+            if point.x < corner.x + t * (previous.x - corner.x) {
+                inside = !inside;
+            }
+        }
+        previous = corner;
+    }
+    inside
+}
+
+/// Converts between the map and continent coordinates of a single map, given its `map_rect` and
+/// `continent_rect`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MapTransform {
+    pub map_rect: Rect,
+    pub continent_rect: Rect,
+}
+
+impl MapTransform {
+    pub fn new(map_rect: Rect, continent_rect: Rect) -> MapTransform {
+        MapTransform { map_rect, continent_rect }
+    }
+
+    /// Converts map coordinates into continent coordinates.
+    pub fn map_to_continent(&self, point: Point2) -> Point2 {
+        let (map, continent) = (&self.map_rect, &self.continent_rect);
+        Point2::new(
+            continent.min.x + (point.x - map.min.x) / map.width() * continent.width(),
+            // The y axis points north in map coordinates, but south in continent coordinates.
+            continent.min.y + (map.max.y - point.y) / map.height() * continent.height(),
+        )
+    }
+
+    /// Converts continent coordinates into map coordinates.
+    pub fn continent_to_map(&self, point: Point2) -> Point2 {
+        let (map, continent) = (&self.map_rect, &self.continent_rect);
+        Point2::new(
+            map.min.x + (point.x - continent.min.x) / continent.width() * map.width(),
+            map.max.y - (point.y - continent.min.y) / continent.height() * map.height(),
+        )
+    }
+
+    /// Converts the `x` and `z` components of a MumbleLink avatar or camera position into
+    /// continent coordinates.
+    pub fn mumble_to_continent(&self, x: f64, z: f64) -> Point2 {
+        self.map_to_continent(mumble_to_map(x, z))
+    }
+}
+
+/// Converts the `x` and `z` components of a MumbleLink avatar or camera position, which are in
+/// meters, into map coordinates.
+pub fn mumble_to_map(x: f64, z: f64) -> Point2 {
+    Point2::new(x * INCHES_PER_METER, z * INCHES_PER_METER)
+}
+
+/// A map tile, as served at `tiles.guildwars2.com/{continent}/{floor}/{zoom}/{x}/{y}.jpg`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tile {
+    pub zoom: u8,
+    pub x: u32,
+    pub y: u32,
+}
+
+impl Tile {
+    /// The tile containing the continent coordinates at the given zoom level, where `max_zoom` is
+    /// the `Continent::max_zoom` of the continent.
+    pub fn at(point: Point2, zoom: u8, max_zoom: u8) -> Tile {
+        let size = tile_span(zoom, max_zoom);
+        Tile {
+            zoom,
+            x: (point.x.max(0.0) / size).floor() as u32,
+            y: (point.y.max(0.0) / size).floor() as u32,
+        }
+    }
+
+    /// The tiles covering the continent coordinates of the rect at the given zoom level, row by
+    /// row, where `max_zoom` is the `Continent::max_zoom` of the continent.
+    pub fn covering(rect: Rect, zoom: u8, max_zoom: u8) -> Vec<Tile> {
+        let size = tile_span(zoom, max_zoom);
+        let first = Tile::at(rect.min, zoom, max_zoom);
+        // Tiles that the rect only touches with its far edges are not needed.
+        let last_x = ((rect.max.x / size).ceil() as u32).saturating_sub(1).max(first.x);
+        let last_y = ((rect.max.y / size).ceil() as u32).saturating_sub(1).max(first.y);
+        (first.y..=last_y)
+            .flat_map(|y| (first.x..=last_x).map(move |x| Tile { zoom, x, y }))
+            .collect()
+    }
+
+    /// The continent coordinates covered by the tile, where `max_zoom` is the
+    /// `Continent::max_zoom` of the continent.
+    pub fn continent_rect(&self, max_zoom: u8) -> Rect {
+        let size = tile_span(self.zoom, max_zoom);
+        let min = Point2::new(f64::from(self.x) * size, f64::from(self.y) * size);
+        Rect::new(min, Point2::new(min.x + size, min.y + size))
+    }
+}
+
+/// How many continent pixels a tile spans at the zoom level.
+fn tile_span(zoom: u8, max_zoom: u8) -> f64 {
+    f64::from(TILE_SIZE) * 2f64.powi(i32::from(max_zoom) - i32::from(zoom))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::*;
+
+    fn queensdale() -> MapTransform {
+        MapTransform::new(
+            Rect::new(Point2::new(-43008.0, -27648.0), Point2::new(43008.0, 30720.0)),
+            Rect::new(Point2::new(9856.0, 11648.0), Point2::new(13440.0, 14080.0)),
+        )
+    }
+
+    #[test]
+    fn map_to_continent() {
+        let transform = queensdale();
+        assert_eq!(Point2::new(9856.0, 11648.0),
+            transform.map_to_continent(Point2::new(-43008.0, 30720.0)));
+        assert_eq!(Point2::new(13440.0, 14080.0),
+            transform.map_to_continent(Point2::new(43008.0, -27648.0)));
+
+        let point = Point2::new(1234.0, -5678.0);
+        let back = transform.continent_to_map(transform.map_to_continent(point));
+        assert!((back.x - point.x).abs() < 1e-6 && (back.y - point.y).abs() < 1e-6);
+    }
+
+    #[test]
+    fn mumble_to_continent() {
+        let point = queensdale().mumble_to_continent(0.0, 3072.0 / INCHES_PER_METER);
+        assert!((point.x - 11648.0).abs() < 1e-6 && (point.y - 12800.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn tiles() {
+        let point = Point2::new(11648.0, 12800.0);
+        assert_eq!(Tile { zoom: 7, x: 45, y: 50 }, Tile::at(point, 7, 7));
+        assert_eq!(Tile { zoom: 3, x: 2, y: 3 }, Tile::at(point, 3, 7));
+        assert_eq!(Tile { zoom: 0, x: 0, y: 0 }, Tile::at(Point2::new(-5.0, -5.0), 0, 7));
+        let rect = Tile { zoom: 3, x: 2, y: 3 }.continent_rect(7);
+        assert_eq!(Rect::new(Point2::new(8192.0, 12288.0), Point2::new(12288.0, 16384.0)), rect);
+        assert!(rect.contains(point));
+
+        let rect = Rect::new(Point2::new(300.0, 10.0), Point2::new(768.0, 256.0));
+        let expected = vec![Tile { zoom: 7, x: 1, y: 0 }, Tile { zoom: 7, x: 2, y: 0 }];
+        assert_eq!(expected, Tile::covering(rect, 7, 7));
+        assert_eq!(vec![Tile { zoom: 0, x: 0, y: 0 }], Tile::covering(rect, 0, 7));
+        assert_eq!(4, Tile::covering(Rect::new(point, Point2::new(12000.0, 13100.0)), 7, 7).len());
+    }
+
+    #[test]
+    fn deserialize() {
+        assert_eq!(Point2::new(1.5, -2.0), serde_json::from_str("[1.5, -2]").unwrap());
+        assert_eq!(Point3::new(1.0, 2.0, 3.0), serde_json::from_str("[1, 2, 3]").unwrap());
+        let rect: Rect = serde_json::from_str("[[10, 20], [0, 40]]").unwrap();
+        assert_eq!(Rect::new(Point2::new(0.0, 20.0), Point2::new(10.0, 40.0)), rect);
+        assert_eq!("[[0.0,20.0],[10.0,40.0]]", serde_json::to_string(&rect).unwrap());
+        assert!(serde_json::from_str::<Point2>("[1, 2, 3]").is_err());
+    }
+
+    #[test]
+    fn polygons() {
+        let triangle = [Point2::new(0.0, 0.0), Point2::new(10.0, 0.0), Point2::new(0.0, 10.0)];
+        assert!(polygon_contains(&triangle, Point2::new(2.0, 2.0)));
+        assert!(!polygon_contains(&triangle, Point2::new(6.0, 6.0)));
+        assert!(!polygon_contains(&[], Point2::new(0.0, 0.0)));
+    }
+}
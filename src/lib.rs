@@ -1,27 +1,57 @@
 #![warn(clippy::all)]
 //! gw2api is a fairly simple wrapper over the Guild Wars 2 API.
 //!
-//! ```
+//! ```no_run
 //! use gw2api::client::Client;
 //! use gw2api::v1::build::Build;
 //!
 //! let client = Client::new();
 //! let build = Build::get_build(&client).unwrap();
-//! println!("Current build id: {}", build.id());
+//! println!("Current build id: {}", build.id);
+//! ```
+//!
+//! With the `async` feature enabled, `client::AsyncClient` can be used instead and every endpoint
+//! function gains an `_async` variant:
+//!
+//! ```ignore
+//! use gw2api::client::AsyncClient;
+//! use gw2api::v1::build::Build;
+//!
+//! let client = AsyncClient::new();
+//! let build = Build::get_build_async(&client).await.unwrap();
 //! ```
 //!
 
 pub use serde::{Deserialize, Serialize};
 
+#[macro_use]
+mod macros;
+
 // Client
+pub mod cache;
 pub mod client;
+pub mod endpoint;
+pub mod pagination;
+pub mod rate_limit;
+pub mod retry;
 pub mod error;
 pub mod utils;
 
 // Object and enum definitions
 pub mod attributes;
+pub mod coins;
+pub mod color;
 
 // Endpoints
 pub mod v1;
 pub mod v2;
 
+// Tools built on top of the endpoints
+pub mod chatlink;
+pub mod crafting;
+#[cfg(feature = "emblem")]
+pub mod emblem;
+pub mod geometry;
+pub mod render;
+pub mod tiles;
+
@@ -0,0 +1,47 @@
+/// Defines the endpoint functions of a type once and expands them into a blocking function taking
+/// a `&Client` and, with the `async` feature enabled, an `async` twin taking an `&AsyncClient`.
+/// Keeping a single definition means the two variants can never drift apart.
+///
+/// Every function is written as
+///
+/// ```text
+/// /// Docs.
+/// pub fn blocking_name / async async_name(client, arg: Type, ...) -> Output {
+///     request(url_expression)
+/// }
+/// ```
+///
+/// where `request` is either `request` or `authenticated_request` on the client, and
+/// `url_expression` evaluates to anything that dereferences to a `&str`.
+macro_rules! endpoints {
+    (
+        impl $ty:ty {
+            $(
+                $(#[$attr:meta])*
+                $vis:vis fn $name:ident / async $async_name:ident
+                    ($client:ident $(, $arg:ident : $arg_ty:ty)* $(,)?) -> $ret:ty {
+                    $method:ident($url:expr)
+                }
+            )*
+        }
+    ) => {
+        impl $ty {
+            $(
+                $(#[$attr])*
+                $vis fn $name($client: &$crate::client::Client $(, $arg: $arg_ty)*)
+                    -> Result<$ret, $crate::error::ApiError> {
+                    $client.$method(&$url)
+                }
+
+                $(#[$attr])*
+                ///
+                #[doc = concat!("Asynchronous variant of [`", stringify!($name), "`](Self::", stringify!($name), ").")]
+                #[cfg(feature = "async")]
+                $vis async fn $async_name($client: &$crate::client::AsyncClient<'_> $(, $arg: $arg_ty)*)
+                    -> Result<$ret, $crate::error::ApiError> {
+                    $client.$method(&$url).await
+                }
+            )*
+        }
+    };
+}
@@ -0,0 +1,189 @@
+//! Support for the `page` and `page_size` query parameters of the v2 API, which splits large
+//! result sets up into pages. Information about the other pages is returned through the
+//! `X-Page-Total`, `X-Page-Size`, `X-Result-Total`, `X-Result-Count` and `Link` headers.
+use serde::de::DeserializeOwned;
+
+use std::marker::PhantomData;
+
+use crate::client::Client;
+use crate::endpoint::Endpoint;
+use crate::error::ApiError;
+
+/// The largest page size the official API accepts.
+pub const MAX_PAGE_SIZE: usize = 200;
+
+/// A single page of results, along with the pagination information the API sent with it.
+#[derive(Debug, PartialEq)]
+pub struct Page<T> {
+    /// The objects on this page.
+    pub items: Vec<T>,
+    /// Index of this page, starting at 0.
+    pub page: usize,
+    /// Amount of objects per page.
+    pub page_size: usize,
+    /// Total amount of pages with the current page size.
+    pub page_total: usize,
+    /// Amount of objects on this page.
+    pub result_count: usize,
+    /// Total amount of objects over all pages.
+    pub result_total: usize,
+    /// Urls to the surrounding pages, taken from the `Link` header.
+    pub links: Links,
+}
+
+/// Urls to the pages related to the current one, as advertised by the `Link` header. The urls
+/// include the version but not the base url, so they can be passed to `Client::request` directly.
+#[derive(Debug, Default, PartialEq)]
+pub struct Links {
+    pub current: Option<String>,
+    pub first: Option<String>,
+    pub previous: Option<String>,
+    pub next: Option<String>,
+    pub last: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Builds a page from its items and a lookup function for the (lowercase) response headers.
+    pub(crate) fn new(items: Vec<T>, page: usize, header: impl Fn(&str) -> Option<String>)
+        -> Page<T> {
+        let number = |name: &str| header(name).and_then(|value| value.trim().parse().ok());
+        let result_count = number("x-result-count").unwrap_or(items.len());
+        Page {
+            page,
+            page_size: number("x-page-size").unwrap_or(result_count),
+            page_total: number("x-page-total").unwrap_or(page + 1),
+            result_count,
+            result_total: number("x-result-total").unwrap_or(result_count),
+            links: header("link").map(|link| Links::parse(&link)).unwrap_or_default(),
+            items,
+        }
+    }
+
+    /// Whether there are more pages after this one.
+    pub fn has_next(&self) -> bool {
+        self.page + 1 < self.page_total
+    }
+}
+
+impl Links {
+    /// Parses a `Link` header of the form `</v2/...?page=1>; rel=next, </v2/...?page=0>; rel=first`.
+    fn parse(header: &str) -> Links {
+        let mut links = Links::default();
+        for link in header.split(',') {
+            let mut parts = link.split(';');
+            let url = match parts.next() {
+                Some(url) => url.trim().trim_start_matches('<').trim_end_matches('>').to_string(),
+                None => continue,
+            };
+            let rel = parts
+                .filter_map(|param| param.trim().strip_prefix("rel="))
+                .map(|rel| rel.trim_matches('"'))
+                .next();
+            match rel {
+                Some("self") => links.current = Some(url),
+                Some("first") => links.first = Some(url),
+                Some("previous") | Some("prev") => links.previous = Some(url),
+                Some("next") => links.next = Some(url),
+                Some("last") => links.last = Some(url),
+                _ => (),
+            }
+        }
+        links
+    }
+}
+
+/// Appends the `page` and `page_size` query parameters to the url.
+pub(crate) fn page_url(url: &str, page: usize, page_size: usize) -> String {
+    let separator = if url.contains('?') { '&' } else { '?' };
+    format!("{}{}page={}&page_size={}", url, separator, page, page_size)
+}
+
+/// Iterator over every page of an endpoint, created by `Client::pages`. Iteration stops after the
+/// last page or after the first error.
+pub struct Pages<'c, 'a, T> {
+    client: &'c Client<'a>,
+    url: &'static str,
+    authenticated: bool,
+    page_size: usize,
+    next_page: Option<usize>,
+    item: PhantomData<T>,
+}
+
+impl<'c, 'a, T: Endpoint> Pages<'c, 'a, T> {
+    pub(crate) fn new(client: &'c Client<'a>, page_size: usize) -> Pages<'c, 'a, T> {
+        Pages::from_url(client, T::URL, T::AUTHENTICATED, page_size)
+    }
+}
+
+impl<'c, 'a, T> Pages<'c, 'a, T> {
+    /// Walks over the pages of a url that is not tied to an `Endpoint` implementation.
+    pub(crate) fn from_url(client: &'c Client<'a>, url: &'static str, authenticated: bool,
+        page_size: usize) -> Pages<'c, 'a, T> {
+        Pages { client, url, authenticated, page_size, next_page: Some(0), item: PhantomData }
+    }
+}
+
+impl<T: DeserializeOwned> Iterator for Pages<'_, '_, T> {
+    type Item = Result<Page<T>, ApiError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let page = self.next_page.take()?;
+        let result = if self.authenticated {
+            self.client.authenticated_request_page(self.url, page, self.page_size)
+        } else {
+            self.client.request_page(self.url, page, self.page_size)
+        };
+        if let Ok(page) = &result {
+            if page.has_next() {
+                self.next_page = Some(page.page + 1);
+            }
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pagination::*;
+
+    const LINK: &str = "</v2/commerce/transactions/history/sells?page=1&page_size=50>; rel=next, \
+        </v2/commerce/transactions/history/sells?page=0&page_size=50>; rel=previous, \
+        </v2/commerce/transactions/history/sells?page=1&page_size=50>; rel=self, \
+        </v2/commerce/transactions/history/sells?page=0&page_size=50>; rel=first, \
+        </v2/commerce/transactions/history/sells?page=5&page_size=50>; rel=last";
+
+    #[test]
+    fn parse_links() {
+        let links = Links::parse(LINK);
+        let url = |page| format!("/v2/commerce/transactions/history/sells?page={}&page_size=50", page);
+        assert_eq!(Some(url(1)), links.next);
+        assert_eq!(Some(url(0)), links.previous);
+        assert_eq!(Some(url(1)), links.current);
+        assert_eq!(Some(url(0)), links.first);
+        assert_eq!(Some(url(5)), links.last);
+    }
+
+    #[test]
+    fn create_page_from_headers() {
+        let header = |name: &str| match name {
+            "x-page-total" => Some("6".to_string()),
+            "x-page-size" => Some("50".to_string()),
+            "x-result-total" => Some("280".to_string()),
+            "x-result-count" => Some("2".to_string()),
+            "link" => Some(LINK.to_string()),
+            _ => None,
+        };
+        let page = Page::new(vec![1, 2], 5, header);
+        assert_eq!(6, page.page_total);
+        assert_eq!(50, page.page_size);
+        assert_eq!(280, page.result_total);
+        assert_eq!(2, page.result_count);
+        assert!(!page.has_next());
+    }
+
+    #[test]
+    fn page_url_query() {
+        assert_eq!("/v2/items?page=2&page_size=10", page_url("/v2/items", 2, 10));
+        assert_eq!("/v2/items?ids=all&page=0&page_size=5", page_url("/v2/items?ids=all", 0, 5));
+    }
+}
@@ -0,0 +1,145 @@
+//! Client side rate limiting. The official API allows a burst of 300 requests, after which the
+//! bucket refills at 5 requests per second, and responds with a 429 once it is empty.
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Amount of requests that can be made in a burst before the API starts throttling.
+pub const BURST: u32 = 300;
+/// Amount of requests per second the bucket of the API refills with.
+pub const REFILL_PER_SECOND: f64 = 5.0;
+/// How many times a request that was answered with a 429 is retried before giving up.
+pub const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+/// The longest `Retry-After` that is waited for, the time it takes an empty bucket to refill.
+pub const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// A token bucket that delays requests so that they stay within the rate limit of the API.
+///
+/// Cloning a `RateLimiter` (or a `Client` that has one) shares the bucket, so every clone and
+/// every thread using one draws from the same limit.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    /// Maximum amount of tokens the bucket can hold.
+    capacity: f64,
+    /// Tokens added to the bucket every second.
+    refill_per_second: f64,
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// Available tokens. Goes below zero when requests are waiting for a token.
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a limiter that allows `burst` requests at once, refilling at `refill_per_second`.
+    pub fn new(burst: u32, refill_per_second: f64) -> RateLimiter {
+        assert!(refill_per_second > 0.0, "refill_per_second has to be positive");
+        RateLimiter {
+            capacity: f64::from(burst),
+            refill_per_second,
+            bucket: Arc::new(Mutex::new(Bucket {
+                tokens: f64::from(burst),
+                last_refill: Instant::now(),
+            })),
+        }
+    }
+
+    /// Takes a token from the bucket and returns how long the caller has to wait before it may
+    /// send its request. Tokens are handed out in order, so waiting callers do not starve.
+    pub fn reserve(&self) -> Duration {
+        let mut bucket = self.refill();
+        bucket.tokens -= 1.0;
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / self.refill_per_second)
+        }
+    }
+
+    /// Blocks the current thread until a request may be sent.
+    pub fn acquire(&self) {
+        let wait = self.reserve();
+        if !wait.is_zero() {
+            std::thread::sleep(wait);
+        }
+    }
+
+    /// Empties the bucket after the API responded with a 429, since the limit on its side has
+    /// evidently been reached.
+    pub fn drain(&self) {
+        let mut bucket = self.refill();
+        bucket.tokens = bucket.tokens.min(0.0);
+    }
+
+    /// Adds the tokens that accumulated since the last refill and returns the locked bucket.
+    fn refill(&self) -> std::sync::MutexGuard<'_, Bucket> {
+        let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.refill_per_second).min(self.capacity);
+        bucket.last_refill = now;
+        bucket
+    }
+}
+
+/// The limits of the official API, a burst of 300 requests refilling at 5 per second.
+impl Default for RateLimiter {
+    fn default() -> Self {
+        RateLimiter::new(BURST, REFILL_PER_SECOND)
+    }
+}
+
+/// Parses the `Retry-After` header of a 429 response, which the API sends in seconds. Waits
+/// longer than `MAX_RETRY_AFTER` are cut short.
+pub(crate) fn retry_after(value: Option<&str>) -> Option<Duration> {
+    let secs = value?.trim().parse::<f64>().ok().filter(|secs| !secs.is_nan())?;
+    Duration::try_from_secs_f64(secs.min(MAX_RETRY_AFTER.as_secs_f64())).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rate_limit::*;
+
+    #[test]
+    fn burst_is_free() {
+        let limiter = RateLimiter::new(3, 1.0);
+        for _ in 0..3 {
+            assert_eq!(Duration::ZERO, limiter.reserve());
+        }
+        let wait = limiter.reserve();
+        assert!(wait > Duration::from_millis(900) && wait <= Duration::from_secs(1));
+    }
+
+    #[test]
+    fn clones_share_the_bucket() {
+        let limiter = RateLimiter::new(1, 1.0);
+        let clone = limiter.clone();
+        assert_eq!(Duration::ZERO, limiter.reserve());
+        assert!(clone.reserve() > Duration::ZERO);
+    }
+
+    #[test]
+    fn drain_empties_the_bucket() {
+        let limiter = RateLimiter::new(10, 5.0);
+        limiter.drain();
+        assert!(limiter.reserve() > Duration::from_millis(150));
+    }
+
+    #[test]
+    fn parse_retry_after() {
+        assert_eq!(Some(Duration::from_secs(2)), retry_after(Some(" 2")));
+        assert_eq!(None, retry_after(Some("soon")));
+        assert_eq!(None, retry_after(Some("-1")));
+        assert_eq!(None, retry_after(Some("NaN")));
+        assert_eq!(None, retry_after(None));
+    }
+
+    #[test]
+    fn cap_retry_after() {
+        assert_eq!(Some(MAX_RETRY_AFTER), retry_after(Some("3600")));
+        assert_eq!(Some(MAX_RETRY_AFTER), retry_after(Some("1e30")));
+        assert_eq!(Some(MAX_RETRY_AFTER), retry_after(Some("inf")));
+    }
+}
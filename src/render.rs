@@ -0,0 +1,88 @@
+//! Urls and downloads of the icons served by the render service. Files are identified by an id
+//! and a signature, which `v1::item_details::Item`, `v1::skin_details::Skin`,
+//! `v1::map_floor::Icon` and `v1::files::File` all carry.
+//!
+//! ```
+//! use gw2api::render::{RenderFile, RenderFormat};
+//! use gw2api::v1::files::File;
+//!
+//! let file = File { id: 102442, signature: "0F80D8D900E1F356B41F752594BEFCE52693DEB3".into() };
+//! assert_eq!(
+//!     "https://render.guildwars2.com/file/0F80D8D900E1F356B41F752594BEFCE52693DEB3/102442.png",
+//!     file.render_url(RenderFormat::Png)
+//! );
+//! ```
+#[cfg(feature = "async")]
+use std::future::Future;
+
+use crate::client::{Client, RENDER_URL};
+#[cfg(feature = "async")]
+use crate::client::AsyncClient;
+use crate::error::ApiError;
+
+/// The image formats the render service can serve a file in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RenderFormat {
+    #[default]
+    Png,
+    Jpg,
+}
+
+impl RenderFormat {
+    /// File extension of the format, as used in render service urls.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            RenderFormat::Png => "png",
+            RenderFormat::Jpg => "jpg",
+        }
+    }
+}
+
+/// A file of the render service.
+pub trait RenderFile {
+    /// id of the file.
+    fn file_id(&self) -> u32;
+
+    /// Signature of the file.
+    fn file_signature(&self) -> &str;
+
+    /// Path of the file on the render service, e.g. `/file/{signature}/{id}.png`.
+    fn render_path(&self, format: RenderFormat) -> String {
+        format!("/file/{}/{}.{}", self.file_signature(), self.file_id(), format.extension())
+    }
+
+    /// Url of the file on the official render service.
+    fn render_url(&self, format: RenderFormat) -> String {
+        format!("{}{}", RENDER_URL, self.render_path(format))
+    }
+
+    /// Download the file from the render service the client is configured with, see
+    /// `Client::set_render_url`.
+    fn download(&self, client: &Client, format: RenderFormat) -> Result<Vec<u8>, ApiError> {
+        client.download(&format!("{}{}", client.render_url(), self.render_path(format)))
+    }
+
+    /// Asynchronous variant of [`download`](Self::download).
+    #[cfg(feature = "async")]
+    fn download_async<'c>(&self, client: &'c AsyncClient, format: RenderFormat)
+        -> impl Future<Output = Result<Vec<u8>, ApiError>> + 'c {
+        let url = format!("{}{}", client.render_url(), self.render_path(format));
+        async move { client.download(&url).await }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::render::*;
+    use crate::v1::map_floor::Icon;
+
+    #[test]
+    fn render_url() {
+        let signature = "5B1E0D1F8E5C8C6E3D1A4B8B2E3C2E3C1F0D1E2A".to_string();
+        let icon = Icon { id: 102478, signature };
+        assert_eq!("/file/5B1E0D1F8E5C8C6E3D1A4B8B2E3C2E3C1F0D1E2A/102478.jpg",
+            icon.render_path(RenderFormat::Jpg));
+        assert!(icon.render_url(RenderFormat::default()).starts_with(RENDER_URL));
+        assert!(icon.render_url(RenderFormat::default()).ends_with("/102478.png"));
+    }
+}
@@ -0,0 +1,225 @@
+//! Retrying of requests that failed for reasons that are likely to go away by themselves, such as
+//! timeouts and the API being briefly unavailable during patch days.
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use crate::rate_limit::{RateLimiter, MAX_RATE_LIMIT_RETRIES};
+
+/// Status codes that are retried by `RetryPolicy::new`.
+pub const RETRYABLE_STATUSES: [u16; 5] = [408, 500, 502, 503, 504];
+
+/// Describes how often and how long after a failed request it is sent again. Requests are retried
+/// when the connection fails or times out, or when the API responds with one of the retryable
+/// status codes.
+///
+/// ```
+/// # use gw2api::client::Client;
+/// # use gw2api::retry::RetryPolicy;
+/// # use std::time::Duration;
+/// let policy = RetryPolicy::new()
+///     .set_max_attempts(5)
+///     .set_backoff(Duration::from_secs(1), Duration::from_secs(30));
+/// let client = Client::new().set_retry_policy(policy);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// How many times a request is sent at most, including the first attempt.
+    max_attempts: u32,
+    /// How long to wait before the first retry, doubled for every retry after it.
+    initial_backoff: Duration,
+    /// Upper bound for the wait between two attempts.
+    max_backoff: Duration,
+    /// Whether the wait is randomised, so that clients that failed together do not retry together.
+    jitter: bool,
+    /// Status codes of responses that are retried.
+    retryable_statuses: Vec<u16>,
+}
+
+impl RetryPolicy {
+    /// A policy that makes up to 3 attempts, waiting 500ms before the first retry and at most 10
+    /// seconds between attempts, and retries the statuses in `RETRYABLE_STATUSES`.
+    pub fn new() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+            jitter: true,
+            retryable_statuses: RETRYABLE_STATUSES.to_vec(),
+        }
+    }
+
+    /// A policy that never retries, which is what a `Client` uses unless told otherwise.
+    pub fn never() -> RetryPolicy {
+        RetryPolicy::new().set_max_attempts(1)
+    }
+
+    /// Sets how many times a request is sent at most, including the first attempt.
+    pub fn set_max_attempts(mut self, max_attempts: u32) -> RetryPolicy {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the wait before the first retry, which doubles with every retry up to `max`.
+    pub fn set_backoff(mut self, initial: Duration, max: Duration) -> RetryPolicy {
+        self.initial_backoff = initial;
+        self.max_backoff = max.max(initial);
+        self
+    }
+
+    /// Sets whether the wait between attempts is randomised, defaults to true.
+    pub fn set_jitter(mut self, jitter: bool) -> RetryPolicy {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets which status codes are retried.
+    pub fn set_retryable_statuses(mut self, statuses: Vec<u16>) -> RetryPolicy {
+        self.retryable_statuses = statuses;
+        self
+    }
+
+    /// Returns how many times a request is sent at most.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns whether a response with the given status code is retried.
+    pub fn is_retryable(&self, status: u16) -> bool {
+        self.retryable_statuses.contains(&status)
+    }
+
+    /// How long to wait after the given (1-based) failed attempt. With jitter the wait is picked
+    /// at random between half and all of the exponential backoff.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let backoff = self.initial_backoff
+            .checked_mul(1 << exponent)
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff));
+        if self.jitter {
+            backoff / 2 + backoff.mul_f64(random_fraction() / 2.0)
+        } else {
+            backoff
+        }
+    }
+}
+
+/// The same policy as `RetryPolicy::new`.
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::new()
+    }
+}
+
+/// Keeps track of the attempts of a single request and decides whether, and after how long, it
+/// is sent again. Shared between the blocking and the async client.
+pub(crate) struct Retries<'c> {
+    policy: &'c RetryPolicy,
+    rate_limiter: Option<&'c RateLimiter>,
+    attempt: u32,
+    rate_limited: u32,
+}
+
+impl<'c> Retries<'c> {
+    pub(crate) fn new(policy: &'c RetryPolicy, rate_limiter: Option<&'c RateLimiter>)
+        -> Retries<'c> {
+        Retries { policy, rate_limiter, attempt: 1, rate_limited: 0 }
+    }
+
+    /// Returns how long to wait before sending the request again, or `None` if the outcome of the
+    /// last attempt is final. `status` is `None` when no response was received at all.
+    pub(crate) fn next_delay(&mut self, status: Option<u16>, retry_after: Option<Duration>)
+        -> Option<Duration> {
+        if let (Some(429), Some(limiter)) = (status, self.rate_limiter) {
+            if self.rate_limited < MAX_RATE_LIMIT_RETRIES {
+                // The next token is only handed out once the bucket has refilled
+                limiter.drain();
+                self.rate_limited += 1;
+                return Some(retry_after.unwrap_or_default());
+            }
+        }
+
+        let retryable = match status {
+            Some(status) => self.policy.is_retryable(status),
+            None => true,
+        };
+        if retryable && self.attempt < self.policy.max_attempts {
+            let backoff = self.policy.backoff(self.attempt);
+            self.attempt += 1;
+            // A longer Retry-After is honoured, but only up to the longest wait of the policy.
+            Some(backoff.max(retry_after.unwrap_or_default()).min(self.policy.max_backoff))
+        } else {
+            None
+        }
+    }
+}
+
+/// A random number in `[0, 1)`, which is plenty for spreading out retries without pulling in a
+/// random number generator.
+fn random_fraction() -> f64 {
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::retry::*;
+
+    #[test]
+    fn exponential_backoff() {
+        let policy = RetryPolicy::new()
+            .set_jitter(false)
+            .set_backoff(Duration::from_millis(100), Duration::from_millis(350));
+        assert_eq!(Duration::from_millis(100), policy.backoff(1));
+        assert_eq!(Duration::from_millis(200), policy.backoff(2));
+        assert_eq!(Duration::from_millis(350), policy.backoff(3));
+        assert_eq!(Duration::from_millis(350), policy.backoff(100));
+    }
+
+    #[test]
+    fn backoff_with_jitter() {
+        let policy = RetryPolicy::new().set_backoff(Duration::from_secs(1), Duration::from_secs(1));
+        for _ in 0..20 {
+            let backoff = policy.backoff(1);
+            assert!(backoff >= Duration::from_millis(500) && backoff <= Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn retries_until_max_attempts() {
+        let policy = RetryPolicy::new().set_max_attempts(3).set_jitter(false);
+        let mut retries = Retries::new(&policy, None);
+        assert!(retries.next_delay(Some(503), None).is_some());
+        assert!(retries.next_delay(None, None).is_some());
+        assert_eq!(None, retries.next_delay(Some(503), None));
+    }
+
+    #[test]
+    fn only_retryable_statuses() {
+        let policy = RetryPolicy::new();
+        let mut retries = Retries::new(&policy, None);
+        assert_eq!(None, retries.next_delay(Some(404), None));
+        assert_eq!(None, retries.next_delay(Some(429), None));
+
+        let never = RetryPolicy::never();
+        assert_eq!(None, Retries::new(&never, None).next_delay(Some(503), None));
+    }
+
+    #[test]
+    fn default_is_new() {
+        assert_eq!(RetryPolicy::new(), RetryPolicy::default());
+        assert_eq!(3, RetryPolicy::default().max_attempts());
+    }
+
+    #[test]
+    fn retry_after_within_max_backoff() {
+        let policy = RetryPolicy::new()
+            .set_jitter(false)
+            .set_backoff(Duration::from_millis(100), Duration::from_secs(2));
+        let mut retries = Retries::new(&policy, None);
+        let delay = retries.next_delay(Some(503), Some(Duration::from_secs(1)));
+        assert_eq!(Some(Duration::from_secs(1)), delay);
+        let delay = retries.next_delay(Some(503), Some(Duration::from_secs(30)));
+        assert_eq!(Some(Duration::from_secs(2)), delay);
+    }
+}
@@ -0,0 +1,166 @@
+//! Downloads of the world map tiles, which the tile service serves as
+//! `{continent_id}/{floor}/{zoom}/{x}/{y}.jpg`. Use `geometry::Tile::covering` to find the tiles
+//! of an area, and with the `stitch` feature enabled, `stitch` or `stitch_rect` to join them
+//! into a single image.
+//!
+//! ```no_run
+//! use gw2api::client::Client;
+//! use gw2api::geometry::{Point2, Rect, Tile};
+//! use gw2api::tiles::TileLayer;
+//!
+//! let client = Client::new();
+//! // Queensdale on the ground floor of Tyria
+//! let rect = Rect::new(Point2::new(9856.0, 11648.0), Point2::new(13440.0, 14080.0));
+//! let tiles = TileLayer::new(1, 1).download_all(&client, &Tile::covering(rect, 5, 7)).unwrap();
+//! ```
+use crate::client::Client;
+#[cfg(feature = "async")]
+use crate::client::AsyncClient;
+use crate::error::ApiError;
+use crate::geometry::Tile;
+#[cfg(feature = "stitch")]
+use crate::geometry::{Rect, TILE_SIZE};
+
+#[cfg(feature = "stitch")]
+use image::{ImageError, RgbaImage};
+
+/// A floor of a continent, whose tiles make up the world map of that floor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TileLayer {
+    pub continent_id: u32,
+    pub floor: i32,
+}
+
+impl TileLayer {
+    pub fn new(continent_id: u32, floor: i32) -> TileLayer {
+        TileLayer { continent_id, floor }
+    }
+
+    /// Path of the tile on the tile service, e.g. `/1/1/7/45/50.jpg`.
+    pub fn tile_path(&self, tile: Tile) -> String {
+        format!("/{}/{}/{}/{}/{}.jpg", self.continent_id, self.floor, tile.zoom, tile.x, tile.y)
+    }
+
+    /// Download the jpg of a tile from the tile service the client is configured with, see
+    /// `Client::set_tiles_url`.
+    pub fn download(&self, client: &Client, tile: Tile) -> Result<Vec<u8>, ApiError> {
+        client.download(&format!("{}{}", client.tiles_url(), self.tile_path(tile)))
+    }
+
+    /// Asynchronous variant of [`download`](Self::download).
+    #[cfg(feature = "async")]
+    pub async fn download_async(&self, client: &AsyncClient<'_>, tile: Tile)
+        -> Result<Vec<u8>, ApiError> {
+        client.download(&format!("{}{}", client.tiles_url(), self.tile_path(tile))).await
+    }
+
+    /// Download the jpgs of all of the tiles, one after another.
+    pub fn download_all(&self, client: &Client, tiles: &[Tile])
+        -> Result<Vec<(Tile, Vec<u8>)>, ApiError> {
+        tiles.iter()
+            .map(|tile| Ok((*tile, self.download(client, *tile)?)))
+            .collect()
+    }
+
+    /// Asynchronous variant of [`download_all`](Self::download_all).
+    #[cfg(feature = "async")]
+    pub async fn download_all_async(&self, client: &AsyncClient<'_>, tiles: &[Tile])
+        -> Result<Vec<(Tile, Vec<u8>)>, ApiError> {
+        let mut downloaded = Vec::with_capacity(tiles.len());
+        for tile in tiles {
+            downloaded.push((*tile, self.download_async(client, *tile).await?));
+        }
+        Ok(downloaded)
+    }
+}
+
+/// Joins downloaded tiles of the same zoom level into one image, with the tile that has the
+/// smallest x and y in the top left corner. Areas without a tile stay transparent.
+#[cfg(feature = "stitch")]
+pub fn stitch(tiles: &[(Tile, Vec<u8>)]) -> Result<RgbaImage, ImageError> {
+    let Some(origin) = top_left(tiles) else {
+        return Ok(RgbaImage::new(0, 0));
+    };
+    let columns = tiles.iter().map(|(tile, _)| tile.x - origin.x + 1).max().unwrap_or(1);
+    let rows = tiles.iter().map(|(tile, _)| tile.y - origin.y + 1).max().unwrap_or(1);
+
+    let mut image = RgbaImage::new(columns * TILE_SIZE, rows * TILE_SIZE);
+    for (tile, bytes) in tiles {
+        let tile_image = image::load_from_memory(bytes)?.to_rgba8();
+        let x = i64::from((tile.x - origin.x) * TILE_SIZE);
+        let y = i64::from((tile.y - origin.y) * TILE_SIZE);
+        image::imageops::replace(&mut image, &tile_image, x, y);
+    }
+    Ok(image)
+}
+
+/// Joins downloaded tiles, such as those of `Tile::covering(rect, zoom, max_zoom)`, and crops the
+/// result to the continent coordinates of the rect.
+#[cfg(feature = "stitch")]
+pub fn stitch_rect(tiles: &[(Tile, Vec<u8>)], rect: Rect, max_zoom: u8)
+    -> Result<RgbaImage, ImageError> {
+    let image = stitch(tiles)?;
+    let Some(origin) = top_left(tiles) else {
+        return Ok(image);
+    };
+    let origin = origin.continent_rect(max_zoom);
+
+    // A tile spans more continent coordinates the further it is zoomed out.
+    let scale = f64::from(TILE_SIZE) / origin.width();
+    let x = ((rect.min.x - origin.min.x) * scale).max(0.0) as u32;
+    let y = ((rect.min.y - origin.min.y) * scale).max(0.0) as u32;
+    let width = (rect.width() * scale).round() as u32;
+    let height = (rect.height() * scale).round() as u32;
+    Ok(image::imageops::crop_imm(&image, x, y, width, height).to_image())
+}
+
+/// The position of the top left corner of the stitched tiles.
+#[cfg(feature = "stitch")]
+fn top_left(tiles: &[(Tile, Vec<u8>)]) -> Option<Tile> {
+    let (first, _) = tiles.first()?;
+    let x = tiles.iter().map(|(tile, _)| tile.x).min()?;
+    let y = tiles.iter().map(|(tile, _)| tile.y).min()?;
+    Some(Tile { zoom: first.zoom, x, y })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tiles::*;
+
+    #[test]
+    fn tile_path() {
+        let layer = TileLayer::new(1, 1);
+        assert_eq!("/1/1/7/45/50.jpg", layer.tile_path(Tile { zoom: 7, x: 45, y: 50 }));
+    }
+
+    #[cfg(feature = "stitch")]
+    #[test]
+    fn stitch_tiles() {
+        use crate::geometry::Point2;
+        use image::{ImageFormat, Rgba};
+        use std::io::Cursor;
+
+        let encode = |shade: u8| {
+            let pixel = Rgba([shade, shade, shade, 255]);
+            let tile = RgbaImage::from_pixel(TILE_SIZE, TILE_SIZE, pixel);
+            let mut bytes = Vec::new();
+            tile.write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png).unwrap();
+            bytes
+        };
+        let tiles = vec![
+            (Tile { zoom: 7, x: 45, y: 50 }, encode(0)),
+            (Tile { zoom: 7, x: 46, y: 51 }, encode(200)),
+        ];
+
+        let image = stitch(&tiles).unwrap();
+        assert_eq!((512, 512), image.dimensions());
+        assert_eq!(&Rgba([0, 0, 0, 255]), image.get_pixel(10, 10));
+        assert_eq!(&Rgba([0, 0, 0, 0]), image.get_pixel(300, 10));
+        assert_eq!(&Rgba([200, 200, 200, 255]), image.get_pixel(300, 300));
+
+        let rect = Rect::new(Point2::new(11620.0, 12800.0), Point2::new(11920.0, 13100.0));
+        let image = stitch_rect(&tiles, rect, 7).unwrap();
+        assert_eq!((300, 300), image.dimensions());
+        assert_eq!(&Rgba([200, 200, 200, 255]), image.get_pixel(299, 299));
+    }
+}
@@ -1,7 +1,7 @@
 use serde::Deserialize;
 
 /// Possible teams used in WvW or SPvP.
-#[derive(Debug, Deserialize, PartialEq, Hash, Eq)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Hash, Eq)]
 pub enum Team {
     #[serde(alias = "red")]
     Red,
@@ -14,7 +14,7 @@ pub enum Team {
 }
 
 /// All the professions currently in the game.
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
 pub enum Profession {
     Revenant,
     Warrior,
@@ -27,6 +27,13 @@ pub enum Profession {
     Elementalist,
 }
 
+/// Genders a character can have.
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum Gender {
+    Male,
+    Female,
+}
+
 /// All possible races currently in the game.
 #[derive(Debug, Deserialize, PartialEq)]
 pub enum Race {
@@ -75,10 +82,25 @@ pub enum WeightClass {
 }
 
 
-/// Convert a `Vec<T>` to a comma-separated `String`
-pub fn ids_to_string(ids: Vec<impl ToString>) -> String {
-    let mut ids: String = ids.iter().map(|id| format!("{},", id.to_string())).collect();
+/// Convert a list of ids to a comma-separated `String`
+pub fn ids_to_string(ids: impl IntoIterator<Item = impl ToString>) -> String {
+    let mut ids: String = ids.into_iter().map(|id| format!("{},", id.to_string())).collect();
     ids.pop(); // Remove the last comma
     ids
 }
 
+/// Percent-encodes everything but the unreserved characters of a url, so that ids such as
+/// character names can be used in paths and query parameters.
+pub fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
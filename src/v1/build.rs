@@ -1,8 +1,5 @@
 use serde::Deserialize;
 
-use crate::client::Client;
-use crate::error::ApiError;
-
 const ENDPOINT_URL: &str = "/v1/build";
 
 /// Contains a Guild Wars 2 build version id
@@ -13,10 +10,12 @@ pub struct Build {
     pub id: u32,
 }
 
-impl Build {
-    /// Returns a Build struct containing the current build version within the `id` field.
-    pub fn get_build(client: &Client) -> Result<Build, ApiError> {
-        client.request(ENDPOINT_URL)
+endpoints! {
+    impl Build {
+        /// Returns a Build struct containing the current build version within the `id` field.
+        pub fn get_build / async get_build_async(client) -> Build {
+            request(ENDPOINT_URL)
+        }
     }
 }
 
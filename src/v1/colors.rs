@@ -1,9 +1,9 @@
 use serde::Deserialize;
 
 use std::collections::HashMap;
+use std::f64::consts::PI;
 
-use crate::client::Client;
-use crate::error::ApiError;
+use crate::color::Rgb;
 
 const ENDPOINT_URL: &str = "/v1/colors";
 
@@ -57,21 +57,156 @@ pub struct Material {
     pub rgb: Vec<u8>,
 }
 
+endpoints! {
+    impl Colors {
+        /// Retrieve all dyes that are in the game.
+        pub fn get_all_dyes / async get_all_dyes_async(client) -> Colors {
+            request(ENDPOINT_URL)
+        }
+    }
+}
+
+/// The kinds of armor a dye can be applied to, which each have their own colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MaterialKind {
+    Cloth,
+    Leather,
+    Metal,
+    Fur,
+}
+
 impl Colors {
-    /// Retrieve all dyes that are in the game.
-    pub fn get_all_dyes(client: &Client) -> Result<Colors, ApiError> {
-        client.request(ENDPOINT_URL)
+    /// The dyes ordered by how close their color on the material is to the target color, as the
+    /// CIEDE2000 color difference, along with their ids and that difference.
+    pub fn by_distance(&self, target: Rgb, material: MaterialKind) -> Vec<(u32, &Dye, f64)> {
+        let mut dyes: Vec<(u32, &Dye, f64)> = self.colors.iter()
+            .filter_map(|(id, dye)| {
+                let rgb = Rgb::from_slice(&dye.material(material).rgb)?;
+                Some((*id, dye, rgb.delta_e(target)))
+            })
+            .collect();
+        dyes.sort_by(|a, b| a.2.total_cmp(&b.2).then(a.0.cmp(&b.0)));
+        dyes
+    }
+
+    /// The dye whose color on the material is closest to the target color, e.g. the one closest
+    /// to `"#7a2f45".parse().unwrap()`.
+    pub fn nearest(&self, target: Rgb, material: MaterialKind) -> Option<(u32, &Dye)> {
+        self.by_distance(target, material).into_iter().next().map(|(id, dye, _)| (id, dye))
+    }
+}
+
+impl Dye {
+    /// The base color, which the materials shift into their own colors.
+    pub fn base_rgb(&self) -> Option<Rgb> {
+        Rgb::from_slice(&self.rgb)
+    }
+
+    /// The appearance of the dye on the given kind of armor.
+    pub fn material(&self, material: MaterialKind) -> &Material {
+        match material {
+            MaterialKind::Cloth => &self.cloth,
+            MaterialKind::Leather => &self.leather,
+            MaterialKind::Metal => &self.metal,
+            MaterialKind::Fur => &self.fur,
+        }
+    }
+}
+
+impl Material {
+    /// Shifts the base color of a dye by the brightness, contrast, hue, saturation and lightness
+    /// of the material, which gives its `rgb` (give or take 1 due to the rounding of the
+    /// parameters by the API).
+    pub fn shift(&self, base: Rgb) -> Rgb {
+        let brightness = f64::from(self.brightness) / 128.0;
+        let contrast = f64::from(self.contrast);
+        let hue = f64::from(self.hue) * PI / 180.0;
+        let saturation = f64::from(self.saturation);
+        let lightness = f64::from(self.lightness);
+
+        let mut matrix = IDENTITY;
+        if brightness != 0.0 || contrast != 1.0 {
+            let t = 128.0 * (2.0 * brightness + 1.0 - contrast);
+            matrix = multiply(&[
+                [contrast, 0.0, 0.0, t],
+                [0.0, contrast, 0.0, t],
+                [0.0, 0.0, contrast, t],
+                [0.0, 0.0, 0.0, 1.0],
+            ], &matrix);
+        }
+        if hue != 0.0 || saturation != 1.0 || lightness != 1.0 {
+            // Rotate the hue and scale saturation and lightness in a space whose third axis is
+            // the gray diagonal of the color cube.
+            let (sin, cos) = hue.sin_cos();
+            for step in [
+                TO_HSL,
+                [
+                    [cos, sin, 0.0, 0.0],
+                    [-sin, cos, 0.0, 0.0],
+                    [0.0, 0.0, 1.0, 0.0],
+                    [0.0, 0.0, 0.0, 1.0],
+                ],
+                [
+                    [saturation, 0.0, 0.0, 0.0],
+                    [0.0, saturation, 0.0, 0.0],
+                    [0.0, 0.0, lightness, 0.0],
+                    [0.0, 0.0, 0.0, 1.0],
+                ],
+                FROM_HSL,
+            ] {
+                matrix = multiply(&step, &matrix);
+            }
+        }
+
+        // The matrices work on BGR rather than RGB.
+        let bgr = [f64::from(base.b), f64::from(base.g), f64::from(base.r), 1.0];
+        let channel = |row: usize| {
+            let value: f64 = matrix[row].iter().zip(bgr).map(|(m, v)| m * v).sum();
+            value.floor().clamp(0.0, 255.0) as u8
+        };
+        Rgb::new(channel(2), channel(1), channel(0))
     }
 }
 
-impl Dye {}
+type Matrix = [[f64; 4]; 4];
+
+const IDENTITY: Matrix = [
+    [1.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, 0.0],
+    [0.0, 0.0, 0.0, 1.0],
+];
+
+// The rounded values ArenaNet published along with the algorithm, rather than exact ones.
+#[allow(clippy::approx_constant)]
+const TO_HSL: Matrix = [
+    [0.707107, 0.0, -0.707107, 0.0],
+    [-0.408248, 0.816497, -0.408248, 0.0],
+    [0.577350, 0.577350, 0.577350, 0.0],
+    [0.0, 0.0, 0.0, 1.0],
+];
+
+#[allow(clippy::approx_constant)]
+const FROM_HSL: Matrix = [
+    [0.707107, -0.408248, 0.577350, 0.0],
+    [0.0, 0.816497, 0.577350, 0.0],
+    [-0.707107, -0.408248, 0.577350, 0.0],
+    [0.0, 0.0, 0.0, 1.0],
+];
 
-impl Material {}
+fn multiply(a: &Matrix, b: &Matrix) -> Matrix {
+    let mut product = [[0.0; 4]; 4];
+    for (i, row) in product.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = (0..4).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    product
+}
 
 #[cfg(test)]
 mod tests {
     use crate::v1::colors::*;
-    use crate::client::Client;
 
     const JSON_DYE: &str = r#"
     {
@@ -141,4 +276,46 @@ mod tests {
     fn create_dye() {
         serde_json::from_str::<Dye>(JSON_DYE).unwrap();
     }
+
+    #[test]
+    fn shift_material() {
+        let dye = serde_json::from_str::<Dye>(JSON_DYE).unwrap();
+        let base = dye.base_rgb().unwrap();
+        assert_eq!(Rgb::new(37, 35, 38), dye.cloth.shift(base));
+        assert_eq!(Rgb::from_slice(&dye.metal.rgb), Some(dye.metal.shift(base)));
+    }
+
+    #[test]
+    fn shift_all_materials() {
+        let json = std::fs::read_to_string("./tests/json/v1/colors.json").unwrap();
+        let colors = serde_json::from_str::<Colors>(&json).unwrap();
+        for dye in colors.colors.values() {
+            let base = dye.base_rgb().unwrap();
+            for kind in [MaterialKind::Cloth, MaterialKind::Leather, MaterialKind::Metal] {
+                let material = dye.material(kind);
+                let expected = Rgb::from_slice(&material.rgb).unwrap();
+                let shifted = material.shift(base);
+                let off_by = [
+                    shifted.r.abs_diff(expected.r),
+                    shifted.g.abs_diff(expected.g),
+                    shifted.b.abs_diff(expected.b),
+                ];
+                assert!(off_by.iter().all(|d| *d <= 1), "{}: {:?}", dye.name, kind);
+            }
+        }
+    }
+
+    #[test]
+    fn nearest_dye() {
+        let json = std::fs::read_to_string("./tests/json/v1/colors.json").unwrap();
+        let colors = serde_json::from_str::<Colors>(&json).unwrap();
+        let (id, dye) = colors.colors.iter().find(|(_, dye)| dye.name == "Black").unwrap();
+        let black = Rgb::from_slice(&dye.cloth.rgb).unwrap();
+
+        let (nearest, _) = colors.nearest(black, MaterialKind::Cloth).unwrap();
+        assert_eq!(*id, nearest);
+        let ranked = colors.by_distance("#7a2f45".parse().unwrap(), MaterialKind::Leather);
+        assert_eq!(colors.colors.len(), ranked.len());
+        assert!(ranked.windows(2).all(|pair| pair[0].2 <= pair[1].2));
+    }
 }
@@ -2,8 +2,7 @@ use serde::Deserialize;
 
 use std::collections::HashMap;
 
-use crate::client::Client;
-use crate::error::ApiError;
+use crate::geometry::{Point2, Tile};
 
 const ENDPOINT_URL: &str = "/v1/continents";
 
@@ -31,14 +30,22 @@ pub struct Continent {
     pub floors: Vec<i8>
 }
 
-impl Continents {
-    /// Retrieve all continents that are in the game.
-    pub fn get_all_continents(client: &Client) -> Result<Continents, ApiError> {
-        client.request(ENDPOINT_URL)
+endpoints! {
+    impl Continents {
+        /// Retrieve all continents that are in the game.
+        pub fn get_all_continents / async get_all_continents_async(client) -> Continents {
+            request(ENDPOINT_URL)
+        }
     }
 }
 
-impl Continent {}
+impl Continent {
+    /// The map tile containing the continent coordinates, with the zoom level clamped to the
+    /// levels the continent supports.
+    pub fn tile_at(&self, point: Point2, zoom: u8) -> Tile {
+        Tile::at(point, zoom.clamp(self.min_zoom, self.max_zoom), self.max_zoom)
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -59,4 +66,13 @@ mod tests {
     fn create_continent() {
         serde_json::from_str::<Continent>(JSON_CONTINENT).unwrap();
     }
+
+    #[test]
+    fn continent_tile_at() {
+        let continent = serde_json::from_str::<Continent>(JSON_CONTINENT).unwrap();
+        let point = Point2::new(16128.0, 23808.0);
+        assert_eq!(Tile { zoom: 7, x: 63, y: 93 }, continent.tile_at(point, 7));
+        assert_eq!(Tile { zoom: 7, x: 63, y: 93 }, continent.tile_at(point, 9));
+        assert_eq!(Tile { zoom: 5, x: 15, y: 23 }, continent.tile_at(point, 5));
+    }
 }
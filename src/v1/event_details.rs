@@ -2,8 +2,7 @@ use serde::Deserialize;
 
 use std::collections::HashMap;
 
-use crate::client::Client;
-use crate::error::ApiError;
+use crate::geometry::{Point2, Point3};
 
 const ENDPOINT_URL: &str = "/v1/event_details";
 
@@ -58,7 +57,7 @@ pub struct Location {
     #[serde(rename = "type")]
     pub shape: Shape,
     /// Coordinates for the center of the event.
-    pub center: Vec<f32>,
+    pub center: Point3,
     /// Height of a non-sphere.
     #[serde(default)]
     pub height: f32,
@@ -73,19 +72,20 @@ pub struct Location {
     pub z_range: Vec<f32>,
     /// Points of the polygon.
     #[serde(default)]
-    pub points: Vec<(f32, f32)>,
+    pub points: Vec<Point2>,
 }
 
-impl Events {
-    /// Retrieve an event by its id.
-    pub fn get_id(client: &Client, id: String) -> Result<Events, ApiError> {
-        let url = format!("{}?event_id={}", ENDPOINT_URL, id);
-        client.request(&url)
-    }
-
-    /// Retrieve all continents that are in the game.
-    pub fn get_all_events(client: &Client) -> Result<Events, ApiError> {
-        client.request(ENDPOINT_URL)
+endpoints! {
+    impl Events {
+        /// Retrieve an event by its id.
+        pub fn get_id / async get_id_async(client, id: String) -> Events {
+            request(format!("{}?event_id={}", ENDPOINT_URL, id))
+        }
+
+        /// Retrieve all continents that are in the game.
+        pub fn get_all_events / async get_all_events_async(client) -> Events {
+            request(ENDPOINT_URL)
+        }
     }
 }
 
@@ -113,7 +113,8 @@ mod tests {
 
     #[test]
     fn create_event() {
-        serde_json::from_str::<Event>(JSON_EVENT).unwrap();
+        let event = serde_json::from_str::<Event>(JSON_EVENT).unwrap();
+        assert_eq!(Point3::new(-9463.6, -40310.2, -785.799), event.location.center);
     }
 
 }
@@ -1,8 +1,5 @@
 use serde::Deserialize;
 
-use crate::client::Client;
-use crate::error::ApiError;
-
 const ENDPOINT_URL: &str = "/v1/event_names";
 
 /// NOTE: This endpoint is disabled, and will only return errors.
@@ -15,10 +12,12 @@ pub struct Event {
     pub name: String,
 }
 
-impl Event {
-    /// Retrieve all event names that are in the game.
-    pub fn get_all(client: &Client) -> Result<Vec<Event>, ApiError> {
-        client.request(ENDPOINT_URL)
+endpoints! {
+    impl Event {
+        /// Retrieve all event names that are in the game.
+        pub fn get_all / async get_all_async(client) -> Vec<Event> {
+            request(ENDPOINT_URL)
+        }
     }
 }
 
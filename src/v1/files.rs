@@ -1,7 +1,6 @@
 use serde::Deserialize;
 
-use crate::client::Client;
-use crate::error::ApiError;
+use crate::render::RenderFile;
 
 const ENDPOINT_URL: &str = "/v1/files";
 
@@ -136,14 +135,25 @@ pub struct File {
     pub signature: String,
 }
 
-impl Files {
-    /// Retrieve information about commonly requested in-game assets (icons) that are in the game.
-    pub fn get_all_files(client: &Client) -> Result<Files, ApiError> {
-        client.request(ENDPOINT_URL)
+endpoints! {
+    impl Files {
+        /// Retrieve information about commonly requested in-game assets (icons) that are in the
+        /// game.
+        pub fn get_all_files / async get_all_files_async(client) -> Files {
+            request(ENDPOINT_URL)
+        }
     }
 }
 
-impl File {}
+impl RenderFile for File {
+    fn file_id(&self) -> u32 {
+        self.id
+    }
+
+    fn file_signature(&self) -> &str {
+        &self.signature
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -1,8 +1,5 @@
 use serde::Deserialize;
 
-use crate::client::Client;
-use crate::error::ApiError;
-
 const ENDPOINT_URL: &str = "/v1/guild_details";
 
 /// Contains information about a guild.
@@ -47,17 +44,17 @@ pub struct Emblem {
     pub foreground_secondary_color_id: u32,
 }
 
-impl Guild {
-    /// Retrieve a guild by its id.
-    pub fn get_by_id(client: &Client, id: String) -> Result<Guild, ApiError> {
-        let url = format!("{}?guild_id={}", ENDPOINT_URL, id);
-        client.request(&url)
-    }
+endpoints! {
+    impl Guild {
+        /// Retrieve a guild by its id.
+        pub fn get_by_id / async get_by_id_async(client, id: String) -> Guild {
+            request(format!("{}?guild_id={}", ENDPOINT_URL, id))
+        }
 
-    /// Retrieve a guild by its name.
-    pub fn get_by_name(client: &Client, name: String) -> Result<Guild, ApiError> {
-        let url = format!("{}?guild_name={}", ENDPOINT_URL, name);
-        client.request(&url)
+        /// Retrieve a guild by its name.
+        pub fn get_by_name / async get_by_name_async(client, name: String) -> Guild {
+            request(format!("{}?guild_name={}", ENDPOINT_URL, name))
+        }
     }
 }
 
@@ -1,22 +1,25 @@
 use serde::Deserialize;
 
-use crate::client::Client;
-use crate::error::ApiError;
+use std::time::Duration;
+
 use crate::attributes::Attribute;
+use crate::coins::Coins;
+use crate::render::RenderFile;
 use crate::utils::Rarity;
+use crate::v1::quoted;
 
 const ENDPOINT_URL: &str = "/v1/item_details";
 
-/// This endpoint is quite silly, since all numerical values are encoded as strings, meaning when
-/// a number is the expected result it will be a string instead, with the exception of
-/// infix_upgrade for some reason which is only used by backpieces afaik ¯\_(ツ)_/¯.
+// This endpoint is quite silly, since all numerical values are encoded as strings, with the
+// exception of infix_upgrade for some reason which is only used by backpieces afaik ¯\_(ツ)_/¯.
+// The `quoted` adapters turn them back into numbers.
 
 /// Struct containing detailed localized information about a requested item.
 #[derive(Debug, Deserialize, PartialEq)]
 pub struct Item {
     /// id of the item.
-    #[serde(rename = "item_id")]
-    pub id: String,
+    #[serde(rename = "item_id", deserialize_with = "quoted::number")]
+    pub id: u32,
     /// Name of the item.
     pub name: String,
     /// Description of the item.
@@ -25,21 +28,25 @@ pub struct Item {
     #[serde(rename = "type")]
     pub item_type: ItemType,
     /// Required level for the item.
-    pub level: String,
+    #[serde(deserialize_with = "quoted::number")]
+    pub level: u32,
     /// Rarity of the item.
     pub rarity: Rarity,
-    /// Value in coins when selling to a vendor.
-    pub vendor_value: String,
+    /// Value when selling to a vendor.
+    pub vendor_value: Coins,
     /// File id to be used with the render service.
-    pub icon_file_id: String,
+    #[serde(deserialize_with = "quoted::number")]
+    pub icon_file_id: u32,
     /// File signature to be used with the render service.
     pub icon_file_signature: String,
     /// Skin id to be used with the skin_details endpoint to get more information. Only present
     /// for item types: Armor, Back and Weapon.
-    pub default_skin: Option<String>,
+    #[serde(default, deserialize_with = "quoted::option_number")]
+    pub default_skin: Option<u32>,
     // TODO: Find an example.
     /// List of upgrade recipes, whatever they are.
-    pub upgrade_recipes: Vec<String>,
+    #[serde(deserialize_with = "quoted::number_vec")]
+    pub upgrade_recipes: Vec<u32>,
     /// Game types where the item is usable.
     pub game_types: Vec<GameType>,
     /// Additional item flags.
@@ -177,15 +184,18 @@ pub struct Armor {
     /// The armor's weight class.
     pub weight_class: WeightClass,
     /// Defense value.
-    pub defense: String,
+    #[serde(deserialize_with = "quoted::number")]
+    pub defense: u32,
     /// Number and type of infusion slots.
     pub infusion_slots: Vec<InfusionSlot>,
     /// Potential bonus given by the object. Optional property.
     pub infix_upgrade: Option<InfixUpgrade>,
     /// Item id of an already applied upgrade component. Can be empty.
-    pub suffix_item_id: Option<String>,
+    #[serde(default, deserialize_with = "quoted::option_number")]
+    pub suffix_item_id: Option<u32>,
     /// Potential secondary upgrade component. Always empty.
-    pub secondary_suffix_item_id: Option<String>,
+    #[serde(default, deserialize_with = "quoted::option_number")]
+    pub secondary_suffix_item_id: Option<u32>,
     /// List of ids of stat choices that can be made with the item.
     #[serde(default)]
     pub stat_choices: Vec<u32>,
@@ -205,8 +215,8 @@ pub enum InfusionType {
 pub struct InfusionSlot {
     /// id of the infusion slot, only used by Back, the only known value is 49428 (+5
     /// Agony_Infusion).
-    #[serde(rename = "item_id")]
-    pub id: Option<String>,
+    #[serde(rename = "item_id", default, deserialize_with = "quoted::option_number")]
+    pub id: Option<u32>,
     /// Types of infusions allowed in this slot.
     pub flags: Vec<InfusionType>,
 }
@@ -235,8 +245,9 @@ pub struct Stat {
 /// Boon Duration.
 #[derive(Debug, Deserialize, PartialEq)]
 pub struct Buff {
-    /// id of the skill applied by the item. Only known values are: "16517" and "10521".
-    pub skill_id: String,
+    /// id of the skill applied by the item. Only known values are: 16517 and 10521.
+    #[serde(deserialize_with = "quoted::number")]
+    pub skill_id: u32,
     /// Description of the effect of the skill. Only known description is "+1% Boon Duration".
     pub description: String,
 }
@@ -250,9 +261,11 @@ pub struct Back {
     /// Potential bonus given by the object. Optional property.
     pub infix_upgrade: Option<InfixUpgrade>,
     /// Item id of an already applied upgrade component. Can be empty.
-    pub suffix_item_id: Option<String>,
+    #[serde(default, deserialize_with = "quoted::option_number")]
+    pub suffix_item_id: Option<u32>,
     /// Potential secondary upgrade component. Always empty.
-    pub secondary_suffix_item_id: Option<String>,
+    #[serde(default, deserialize_with = "quoted::option_number")]
+    pub secondary_suffix_item_id: Option<u32>,
     /// List of ids of stat choices that can be made with the item.
     #[serde(default)]
     pub stat_choices: Vec<u32>,
@@ -262,9 +275,11 @@ pub struct Back {
 #[derive(Debug, Deserialize, PartialEq)]
 pub struct Bag {
     /// Whether or not the bag is a special one.
-    pub no_sell_or_sort: Option<String>,
+    #[serde(default, deserialize_with = "quoted::flag")]
+    pub no_sell_or_sort: bool,
     /// Size of the bag.
-    pub size: Option<String>,
+    #[serde(deserialize_with = "quoted::number")]
+    pub size: u32,
 }
 
 /// All unlock types for consumables.
@@ -304,16 +319,19 @@ pub struct Consumable {
     pub consumable_type: ConsumableType,
     /// Effect duration in milliseconds (may exist if consumable type is "Generic", "Food" or
     /// "Utility").
-    pub duration_ms: Option<String>,
+    #[serde(default, deserialize_with = "quoted::option_millis")]
+    pub duration_ms: Option<Duration>,
     /// Description of the effect (may exist if consumable type is "Generic", "Food" or
     /// "Utility").
     pub description: Option<String>,
     /// The type of unlock (if consumable type is "Unlock").
     pub unlock_type: Option<UnlockType>,
     /// id of the recipe unlocked by the consumable (if unlock type is "CraftingRecipe").
-    pub recipe_id: Option<String>,
+    #[serde(default, deserialize_with = "quoted::option_number")]
+    pub recipe_id: Option<u32>,
     /// id of the dye unlocked by the consumable (if unlock type is "Dye").
-    pub color_id: Option<String>,
+    #[serde(default, deserialize_with = "quoted::option_number")]
+    pub color_id: Option<u32>,
 }
 
 /// Possible container types.
@@ -408,9 +426,11 @@ pub struct Trinket {
     /// Potential bonus given by the object. Optional property.
     pub infix_upgrade: Option<InfixUpgrade>,
     /// Item id of an already applied upgrade component. Can be empty.
-    pub suffix_item_id: Option<String>,
+    #[serde(default, deserialize_with = "quoted::option_number")]
+    pub suffix_item_id: Option<u32>,
     /// Potential secondary upgrade component. Always empty.
-    pub secondary_suffix_item_id: Option<String>,
+    #[serde(default, deserialize_with = "quoted::option_number")]
+    pub secondary_suffix_item_id: Option<u32>,
     /// List of ids of stat choices that can be made with the item.
     #[serde(default)]
     pub stat_choices: Vec<u32>,
@@ -524,27 +544,42 @@ pub struct Weapon {
     /// Damage type.
     pub damage_type: DamageType,
     /// Weapon's minimal power rating.
-    pub min_power: String,
+    #[serde(deserialize_with = "quoted::number")]
+    pub min_power: u32,
     /// Weapon's maximum power rating.
-    pub max_power: String,
+    #[serde(deserialize_with = "quoted::number")]
+    pub max_power: u32,
     /// The weapon's defense rating.
-    pub defense: String,
+    #[serde(deserialize_with = "quoted::number")]
+    pub defense: u32,
     /// List of infusion slots the upgrade component can be applied to.
     pub infusion_slots: Vec<InfusionSlot>,
     /// Potential bonus given by the object. Optional property.
     pub infix_upgrade: Option<InfixUpgrade>,
     /// Item id of an already applied upgrade component. Can be empty.
-    pub suffix_item_id: Option<String>,
+    #[serde(default, deserialize_with = "quoted::option_number")]
+    pub suffix_item_id: Option<u32>,
     /// Potential secondary upgrade component. Always empty.
-    pub secondary_suffix_item_id: Option<String>,
+    #[serde(default, deserialize_with = "quoted::option_number")]
+    pub secondary_suffix_item_id: Option<u32>,
+}
+
+endpoints! {
+    impl Item {
+        /// Retrieve an item by its id.
+        pub fn get_id / async get_id_async(client, id: u32) -> Item {
+            request(format!("{}?item_id={}", ENDPOINT_URL, id))
+        }
+    }
 }
 
+impl RenderFile for Item {
+    fn file_id(&self) -> u32 {
+        self.icon_file_id
+    }
 
-impl Item {
-    /// Retrieve an item by its id.
-    pub fn get_id(client: &Client, id: u32) -> Result<Item, ApiError> {
-        let url = format!("{}?item_id={}", ENDPOINT_URL, id);
-        client.request(&url)
+    fn file_signature(&self) -> &str {
+        &self.icon_file_signature
     }
 }
 
@@ -1,8 +1,5 @@
 use serde::Deserialize;
 
-use crate::client::Client;
-use crate::error::ApiError;
-
 const ENDPOINT_URL: &str = "/v1/items";
 
 /// Contains ids of all player discovered items. Details about a particular item can be
@@ -13,10 +10,12 @@ pub struct Items {
     pub items: Vec<u32>,
 }
 
-impl Items {
-    /// Retrieve all discovered items' ids.
-    pub fn get_all(client: &Client) -> Result<Items, ApiError> {
-        client.request(ENDPOINT_URL)
+endpoints! {
+    impl Items {
+        /// Retrieve all discovered items' ids.
+        pub fn get_all / async get_all_async(client) -> Items {
+            request(ENDPOINT_URL)
+        }
     }
 }
 
@@ -1,34 +1,47 @@
 use serde::Deserialize;
 
-use crate::client::Client;
-use crate::error::ApiError;
-
 use std::collections::BTreeMap;
 
+use crate::chatlink::ChatLink;
+use crate::geometry::{self, MapTransform, Point2, Rect, Tile};
+use crate::render::RenderFile;
+
 const ENDPOINT_URL: &str = "/v1/map_floor";
 
-/// Struct containing details about a specified map floor. All coordinates are map coordinates.
+/// Struct containing details about a specified map floor. All coordinates are continent
+/// coordinates, see the `geometry` module.
 #[derive(Debug, Deserialize, PartialEq)]
 pub struct Floor {
-    /// Tuple describing the dimensions of the texture for the map floor.
-    pub texture_dims: Vec<f32>,
+    /// Width and height of the texture for the map floor.
+    pub texture_dims: Point2,
     /// If present, it represents a rectangle of downloadable textures. Every tile coordinate outside this rectangle is not available on the tile server.
-    pub clamped_view: Vec<Vec<f32>>,
+    pub clamped_view: Rect,
     /// A map of the regions of a map, where the region id is the key and the region object is the
     /// value.
     pub regions: BTreeMap<u32, Region>,
 }
 
+/// Where a point lies on a floor, see `Floor::locate`.
+#[derive(Debug, PartialEq)]
+pub struct FloorLocation<'a> {
+    pub region_id: u32,
+    pub region: &'a Region,
+    /// The map containing the point, if any.
+    pub map: Option<(u32, &'a Map)>,
+    /// The sector of the map containing the point, if any.
+    pub sector: Option<&'a Sector>,
+}
+
 /// Struct containing information about a region of a map.
 #[derive(Debug, Deserialize, PartialEq)]
 pub struct Region {
     /// Region name.
     pub name: String,
     /// The coordinates of the region label.
-    pub label_coord: Vec<f32>,
+    pub label_coord: Point2,
     /// Dimensions of the region, given as the coordinates of the lower-left (SW) and upper-right (NE)
     /// corners.
-    pub continent_rect: Vec<Vec<i32>>,
+    pub continent_rect: Rect,
     /// Mapping from the map id to an object.
     pub maps: BTreeMap<u32, Map>
 }
@@ -50,15 +63,15 @@ pub struct Map {
     pub floors: Vec<i32>,
     /// The coordinates of the map label.
     #[serde(default)]
-    pub label_coord: Vec<f32>,
+    pub label_coord: Option<Point2>,
     /// Dimensions of the map, given as the coordinates of the lower-left (SW) and upper-right (NE)
     /// corners.
     #[serde(default)]
-    pub map_rect: Vec<Vec<i32>>,
+    pub map_rect: Option<Rect>,
     /// Dimensions of the map within the continent coordinate system,
     /// given as the coordinates of the lower-left (SW) and upper-right (NE) corners.
     #[serde(default)]
-    pub continent_rect: Vec<Vec<i32>>,
+    pub continent_rect: Option<Rect>,
     /// List of points of interests in the map (landmarks, waypoints and vistas)
     #[serde(default)]
     pub points_of_interest: Vec<Poi>,
@@ -113,7 +126,7 @@ pub struct Poi {
     /// The floor number of this object.
     pub floor: i32,
     /// The coordinates of this object.
-    pub coord: Vec<f32>,
+    pub coord: Point2,
 }
 
 /// Struct containing information about an icon (used for the shrines of the gods).
@@ -136,7 +149,7 @@ pub struct GodShrine {
     /// Contested name of the god shrine.
     pub name_contested: String,
     /// Coordinates where the god shrine is.
-    pub coord: Vec<f32>,
+    pub coord: Point2,
     /// id of the point of interest for the shrine.
     pub poi_id: u32,
     /// Icon for the uncontested shrine.
@@ -156,9 +169,9 @@ pub struct Task {
     /// The level of the the heart.
     pub level: u16,
     /// Coordinates where the task takes place.
-    pub coord: Vec<f32>,
+    pub coord: Point2,
     /// Boundaries of the task.
-    pub bounds: Vec<Vec<f32>>,
+    pub bounds: Vec<Point2>,
 }
 
 /// Struct containing information about a skill challenge.
@@ -171,7 +184,7 @@ pub struct SkillChallenge {
     #[serde(rename = "idx")]
     pub id: u32,
     /// Coordinates where the skill challenge is.
-    pub coord: Vec<f32>,
+    pub coord: Point2,
 }
 
 /// Possible types of a training point, Tyrian ones give less hero points than Maguuman or ones
@@ -195,7 +208,7 @@ pub struct TrainingPoint {
     /// Description of the training point.
     pub description: String,
     /// Coordinates where the training point is.
-    pub coord: Vec<f32>,
+    pub coord: Point2,
     /// Type of training point (either Maguuma or Tyria)
     #[serde(rename = "type")]
     pub point_type: PointType,
@@ -209,7 +222,7 @@ pub struct Adventure {
     #[serde(rename = "guid")]
     pub id: String,
     /// Coordinates where the adventure is.
-    pub coord: Vec<f32>,
+    pub coord: Point2,
     /// Name of the adventure.
     pub name: String,
     /// Further information about the leaderboard and description of the adventure.
@@ -239,30 +252,84 @@ pub struct Sector {
     /// Level of the area.
     pub level: u32,
     /// Coordinates of this area.
-    pub coord: Vec<f32>,
+    pub coord: Point2,
     /// Bounds of the area.
-    pub bounds: Vec<Vec<f32>>,
+    pub bounds: Vec<Point2>,
+}
+
+endpoints! {
+    impl Floor {
+        /// Retrieve a map floor by its continent id and floor number.
+        pub fn get_map_floor / async get_map_floor_async(client, continent_id: u32, floor: i32)
+            -> Floor {
+            request(format!("{}?continent_id={}&floor={}", ENDPOINT_URL, continent_id, floor))
+        }
+    }
 }
 
 impl Floor {
-    /// Retrieve a map floor by its continent id and floor number.
-    pub fn get_map_floor(client: &Client, continent_id: u32, floor: i32) -> Result<Floor, ApiError> {
-        let url = format!("{}?continent_id={}&floor={}", ENDPOINT_URL, continent_id, floor);
-        client.request(&url)
+    /// The map tiles of the floor at the given zoom level, leaving out those outside of the
+    /// `clamped_view`, where `max_zoom` is the `Continent::max_zoom` of the continent.
+    pub fn tiles(&self, zoom: u8, max_zoom: u8) -> Vec<Tile> {
+        Tile::covering(self.clamped_view, zoom, max_zoom)
+    }
+
+    /// Find the region, map and sector containing the continent coordinates.
+    pub fn locate(&self, point: Point2) -> Option<FloorLocation<'_>> {
+        let (region_id, region) = self.regions.iter()
+            .find(|(_, region)| region.contains(point))?;
+        let map = region.maps.iter()
+            .find(|(_, map)| map.contains(point))
+            .map(|(id, map)| (*id, map));
+        let sector = map.and_then(|(_, map)| map.sectors.iter().find(|s| s.contains(point)));
+        Some(FloorLocation { region_id: *region_id, region, map, sector })
     }
 }
 
-impl Region {}
+impl Region {
+    /// Whether the continent coordinates lie within the region.
+    pub fn contains(&self, point: Point2) -> bool {
+        self.continent_rect.contains(point)
+    }
+}
 
-impl Map {}
+impl Map {
+    /// Converts between the map and continent coordinates of the map.
+    pub fn transform(&self) -> Option<MapTransform> {
+        Some(MapTransform::new(self.map_rect?, self.continent_rect?))
+    }
 
-impl Poi {}
+    /// Whether the continent coordinates lie within the map.
+    pub fn contains(&self, point: Point2) -> bool {
+        self.continent_rect.is_some_and(|rect| rect.contains(point))
+    }
+}
 
-impl Icon {}
+impl Poi {
+    /// The chat link pointing to this point of interest.
+    pub fn chat_link(&self) -> ChatLink {
+        ChatLink::Map(self.id)
+    }
+}
+
+impl RenderFile for Icon {
+    fn file_id(&self) -> u32 {
+        self.id
+    }
+
+    fn file_signature(&self) -> &str {
+        &self.signature
+    }
+}
 
 impl GodShrine {}
 
-impl Task {}
+impl Task {
+    /// Whether the continent coordinates lie within the area of the renown heart.
+    pub fn contains(&self, point: Point2) -> bool {
+        geometry::polygon_contains(&self.bounds, point)
+    }
+}
 
 impl SkillChallenge {}
 
@@ -272,7 +339,12 @@ impl Adventure {}
 
 impl Leaderboard {}
 
-impl Sector {}
+impl Sector {
+    /// Whether the continent coordinates lie within the bounds of the sector.
+    pub fn contains(&self, point: Point2) -> bool {
+        geometry::polygon_contains(&self.bounds, point)
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -281,9 +353,70 @@ mod tests {
 
     const JSON_FLOOR_PATH: &str = "./tests/json/v1/map_floor.json";
 
+    const JSON_TASK: &str = r#"
+    {
+      "task_id": 1,
+      "objective": "Help Farmer Eda protect her fields.",
+      "level": 3,
+      "coord": [ 11187.4, 13046.6 ],
+      "bounds": [
+        [ 11071.1, 12937.3 ], [ 11344.4, 12942.4 ], [ 11324.7, 13160.6 ], [ 11089.9, 13164.4 ]
+      ]
+    }"#;
+
    #[test]
     fn create_floor() {
         let json = read_to_string(JSON_FLOOR_PATH).unwrap();
         serde_json::from_str::<Floor>(&json).unwrap();
     }
+
+    #[test]
+    fn task_contains() {
+        let task = serde_json::from_str::<Task>(JSON_TASK).unwrap();
+        assert!(task.contains(task.coord));
+        assert!(!task.contains(Point2::new(11340.0, 13150.0)));
+    }
+
+    #[test]
+    fn poi_chat_link() {
+        let json = read_to_string(JSON_FLOOR_PATH).unwrap();
+        let floor = serde_json::from_str::<Floor>(&json).unwrap();
+        let poi = floor.regions.values()
+            .flat_map(|region| region.maps.values())
+            .flat_map(|map| &map.points_of_interest)
+            .find(|poi| poi.name == "Plaza of Lost Wisdom")
+            .unwrap();
+        assert_eq!("[&BOACAAA=]", poi.chat_link().to_string());
+    }
+
+    #[test]
+    fn locate_point() {
+        let json = read_to_string(JSON_FLOOR_PATH).unwrap();
+        let floor = serde_json::from_str::<Floor>(&json).unwrap();
+
+        let location = floor.locate(Point2::new(16620.6, 24486.7)).unwrap();
+        assert_eq!(3, location.region_id);
+        assert_eq!(Some(51), location.map.map(|(id, _)| id));
+        assert_eq!(Some(692), location.sector.map(|sector| sector.id));
+
+        let location = floor.locate(Point2::new(12000.0, 28000.0)).unwrap();
+        assert_eq!("Ruins of Orr", location.region.name);
+        assert!(location.map.is_none());
+        assert!(floor.locate(Point2::new(0.0, 0.0)).is_none());
+
+        let (_, map) = location.region.maps.iter().next().unwrap();
+        let transform = map.transform().unwrap();
+        let center = transform.map_to_continent(Point2::new(0.0, 0.0));
+        assert_eq!(Point2::new(16128.0, 23808.0), center);
+    }
+
+    #[test]
+    fn floor_tiles() {
+        let json = read_to_string(JSON_FLOOR_PATH).unwrap();
+        let floor = serde_json::from_str::<Floor>(&json).unwrap();
+        assert_eq!(vec![Tile { zoom: 0, x: 0, y: 0 }], floor.tiles(0, 8));
+        let tiles = floor.tiles(2, 8);
+        assert_eq!(9, tiles.len());
+        assert_eq!(Some(&Tile { zoom: 2, x: 2, y: 2 }), tiles.last());
+    }
 }
@@ -1,8 +1,5 @@
 use serde::Deserialize;
 
-use crate::client::Client;
-use crate::error::ApiError;
-
 const ENDPOINT_URL: &str = "/v1/map_names";
 
 /// Struct containing an unorded list of (localized) map names.
@@ -14,10 +11,12 @@ pub struct MapName {
     pub name: String,
 }
 
-impl MapName {
-    /// Retrieve all map names that are in the game.
-    pub fn get_all(client: &Client) -> Result<Vec<MapName>, ApiError> {
-        client.request(ENDPOINT_URL)
+endpoints! {
+    impl MapName {
+        /// Retrieve all map names that are in the game.
+        pub fn get_all / async get_all_async(client) -> Vec<MapName> {
+            request(ENDPOINT_URL)
+        }
     }
 }
 
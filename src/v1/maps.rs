@@ -2,8 +2,7 @@ use serde::Deserialize;
 
 use std::collections::HashMap;
 
-use crate::client::Client;
-use crate::error::ApiError;
+use crate::geometry::{MapTransform, Point2, Rect};
 
 const ENDPOINT_URL: &str = "/v1/maps";
 
@@ -65,20 +64,33 @@ pub struct Map {
     pub continent_rect: Vec<(i32, i32)>,
 }
 
-impl Maps {
-    /// Retrieve a map by its id.
-    pub fn get_id(client: &Client, id: String) -> Result<Maps, ApiError> {
-        let url = format!("{}?map_id={}", ENDPOINT_URL, id);
-        client.request(&url)
-    }
+endpoints! {
+    impl Maps {
+        /// Retrieve a map by its id.
+        pub fn get_id / async get_id_async(client, id: String) -> Maps {
+            request(format!("{}?map_id={}", ENDPOINT_URL, id))
+        }
 
-    /// Retrieve a map by its id.
-    pub fn get_all(client: &Client) -> Result<Maps, ApiError> {
-        client.request(ENDPOINT_URL)
+        /// Retrieve a map by its id.
+        pub fn get_all / async get_all_async(client) -> Maps {
+            request(ENDPOINT_URL)
+        }
     }
 }
 
-impl Map {}
+impl Map {
+    /// Converts between the map and continent coordinates of the map.
+    pub fn transform(&self) -> Option<MapTransform> {
+        let rect = |corners: &[(i32, i32)]| match corners {
+            [(x0, y0), (x1, y1)] => Some(Rect::new(
+                Point2::new((*x0).into(), (*y0).into()),
+                Point2::new((*x1).into(), (*y1).into()),
+            )),
+            _ => None,
+        };
+        Some(MapTransform::new(rect(&self.map_rect)?, rect(&self.continent_rect)?))
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,163 @@
+//! Serde adapters for the numbers the v1 API sends as strings, e.g. `"level": "80"`. Every
+//! adapter also accepts the unquoted form, so the models keep working if the API ever fixes it.
+//!
+//! Use them with `#[serde(deserialize_with = "quoted::...")]`, adding `#[serde(default)]` for the
+//! `option_*` adapters when the field may be missing altogether.
+use serde::de::{self, Deserializer};
+use serde::Deserialize;
+
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Either the value itself or a string holding it.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Quoted<T> {
+    Unquoted(T),
+    Quoted(String),
+}
+
+fn parse<T, E>(text: &str) -> Result<T, E>
+where
+    T: FromStr,
+    T::Err: Display,
+    E: de::Error,
+{
+    text.trim().parse().map_err(|e| E::custom(format!("invalid number {:?}: {}", text, e)))
+}
+
+/// A number, quoted or not.
+pub(crate) fn number<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr,
+    T::Err: Display,
+{
+    match Quoted::deserialize(deserializer)? {
+        Quoted::Unquoted(value) => Ok(value),
+        Quoted::Quoted(text) => parse(&text),
+    }
+}
+
+/// A number, where `null` and an empty string mean there is none.
+pub(crate) fn option_number<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr,
+    T::Err: Display,
+{
+    match Option::<Quoted<T>>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(Quoted::Unquoted(value)) => Ok(Some(value)),
+        Some(Quoted::Quoted(text)) if text.trim().is_empty() => Ok(None),
+        Some(Quoted::Quoted(text)) => parse(&text).map(Some),
+    }
+}
+
+/// A list of numbers, each quoted or not.
+pub(crate) fn number_vec<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr,
+    T::Err: Display,
+{
+    Vec::<Quoted<T>>::deserialize(deserializer)?
+        .into_iter()
+        .map(|value| match value {
+            Quoted::Unquoted(value) => Ok(value),
+            Quoted::Quoted(text) => parse(&text),
+        })
+        .collect()
+}
+
+/// A flag sent as `"1"` or `"0"`, also accepting `true`, `false` and their quoted forms.
+pub(crate) fn flag<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    match Quoted::<bool>::deserialize(deserializer)? {
+        Quoted::Unquoted(value) => Ok(value),
+        Quoted::Quoted(text) => match text.trim() {
+            "1" | "true" => Ok(true),
+            "0" | "false" | "" => Ok(false),
+            other => Err(de::Error::invalid_value(de::Unexpected::Str(other), &"a flag")),
+        },
+    }
+}
+
+/// A duration given in milliseconds.
+pub(crate) fn millis<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+    number(deserializer).map(Duration::from_millis)
+}
+
+/// A duration given in milliseconds, where `null` and an empty string mean there is none.
+pub(crate) fn option_millis<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    option_number(deserializer).map(|millis| millis.map(Duration::from_millis))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v1::quoted;
+
+    use serde::Deserialize;
+    use std::time::Duration;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Quoted {
+        #[serde(deserialize_with = "quoted::number")]
+        level: u32,
+        #[serde(default, deserialize_with = "quoted::option_number")]
+        suffix_item_id: Option<u32>,
+        #[serde(default, deserialize_with = "quoted::number_vec")]
+        recipes: Vec<u32>,
+        #[serde(default, deserialize_with = "quoted::flag")]
+        no_sell_or_sort: bool,
+        #[serde(default, deserialize_with = "quoted::option_millis")]
+        duration_ms: Option<Duration>,
+    }
+
+    #[test]
+    fn accept_quoted_numbers() {
+        let json = r#"{ "level": "80", "suffix_item_id": "24732", "recipes": ["1", "2"],
+            "no_sell_or_sort": "1", "duration_ms": "1800000" }"#;
+        let quoted = serde_json::from_str::<Quoted>(json).unwrap();
+        assert_eq!(Quoted {
+            level: 80,
+            suffix_item_id: Some(24732),
+            recipes: vec![1, 2],
+            no_sell_or_sort: true,
+            duration_ms: Some(Duration::from_secs(1800)),
+        }, quoted);
+    }
+
+    #[test]
+    fn accept_unquoted_numbers() {
+        let json = r#"{ "level": 80, "suffix_item_id": 24732, "recipes": [1, "2"],
+            "no_sell_or_sort": false, "duration_ms": 1000 }"#;
+        let quoted = serde_json::from_str::<Quoted>(json).unwrap();
+        assert_eq!(80, quoted.level);
+        assert_eq!(Some(24732), quoted.suffix_item_id);
+        assert_eq!(vec![1, 2], quoted.recipes);
+        assert!(!quoted.no_sell_or_sort);
+        assert_eq!(Some(Duration::from_secs(1)), quoted.duration_ms);
+    }
+
+    #[test]
+    fn empty_and_missing_values() {
+        let quoted = serde_json::from_str::<Quoted>(r#"{ "level": "0", "suffix_item_id": "" }"#)
+            .unwrap();
+        assert_eq!(None, quoted.suffix_item_id);
+        assert!(quoted.recipes.is_empty());
+        assert!(!quoted.no_sell_or_sort);
+        assert_eq!(None, quoted.duration_ms);
+    }
+
+    #[test]
+    fn reject_invalid_numbers() {
+        assert!(serde_json::from_str::<Quoted>(r#"{ "level": "eighty" }"#).is_err());
+        assert!(serde_json::from_str::<Quoted>(r#"{ "level": "-1" }"#).is_err());
+        assert!(serde_json::from_str::<Quoted>(r#"{ "level": 1, "no_sell_or_sort": "2" }"#)
+            .is_err());
+    }
+}
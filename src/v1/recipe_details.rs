@@ -1,8 +1,10 @@
 use serde::Deserialize;
 
-use crate::client::Client;
-use crate::error::ApiError;
+use std::time::Duration;
+
+use crate::coins::Coins;
 use crate::utils::Discipline;
+use crate::v1::quoted;
 
 const ENDPOINT_URL: &str = "/v1/recipe_details";
 
@@ -10,22 +12,26 @@ const ENDPOINT_URL: &str = "/v1/recipe_details";
 #[derive(Debug, Deserialize, PartialEq)]
 pub struct Recipe {
     /// id of the recipe.
-    #[serde(rename = "recipe_id")]
-    pub id: String,
+    #[serde(rename = "recipe_id", deserialize_with = "quoted::number")]
+    pub id: u32,
     /// Type of the recipe.
     #[serde(rename = "type")]
     pub recipe_type: RecipeType,
     /// The id of the produced item.
-    pub output_item_id: String,
+    #[serde(deserialize_with = "quoted::number")]
+    pub output_item_id: u32,
     /// The number of the produced.
-    pub output_item_count: String,
+    #[serde(deserialize_with = "quoted::number")]
+    pub output_item_count: u32,
     /// Minimum rating of the recipe.
-    pub min_rating: String,
-    /// Time it takes to craft the item in ms.
-    pub time_to_craft_ms: String,
-    /// Potential value in coins when selling to a vendor.
+    #[serde(deserialize_with = "quoted::number")]
+    pub min_rating: u32,
+    /// Time it takes to craft the item.
+    #[serde(deserialize_with = "quoted::millis")]
+    pub time_to_craft_ms: Duration,
+    /// Potential value when selling to a vendor.
     #[serde(default)]
-    pub vendor_value: String,
+    pub vendor_value: Coins,
     /// Crafting disciplines that can use the recipe.
     #[serde(default)]
     pub disciplines: Vec<Discipline>,
@@ -103,17 +109,19 @@ pub enum RecipeFlags {
 #[derive(Debug, Deserialize, PartialEq)]
 pub struct Ingredient {
     /// id of the ingredient.
-    #[serde(rename = "item_id")]
-    pub id: String,
+    #[serde(rename = "item_id", deserialize_with = "quoted::number")]
+    pub id: u32,
     /// Amount of ingredients required.
-    pub count: String,
+    #[serde(deserialize_with = "quoted::number")]
+    pub count: u32,
 }
 
-impl Recipe {
-    /// Retrieve a recipe by its id.
-    pub fn get_id(client: &Client, id: String) -> Result<Recipe, ApiError> {
-        let url = format!("{}?recipe_id={}", ENDPOINT_URL, id);
-        client.request(&url)
+endpoints! {
+    impl Recipe {
+        /// Retrieve a recipe by its id.
+        pub fn get_id / async get_id_async(client, id: u32) -> Recipe {
+            request(format!("{}?recipe_id={}", ENDPOINT_URL, id))
+        }
     }
 }
 
@@ -142,6 +150,9 @@ mod tests {
 
     #[test]
     fn create_recipe() {
-        serde_json::from_str::<Recipe>(JSON_RECIPE).unwrap();
+        let recipe = serde_json::from_str::<Recipe>(JSON_RECIPE).unwrap();
+        assert_eq!(11541, recipe.output_item_id);
+        assert_eq!(Duration::from_secs(1), recipe.time_to_craft_ms);
+        assert_eq!(19797, recipe.ingredients[0].id);
     }
 }
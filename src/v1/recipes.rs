@@ -1,8 +1,5 @@
 use serde::Deserialize;
 
-use crate::client::Client;
-use crate::error::ApiError;
-
 const ENDPOINT_URL: &str = "/v1/recipes";
 
 /// Contains ids of all player discovered recipes. Details about a particular recipe can be
@@ -13,10 +10,12 @@ pub struct Recipes {
     pub recipes: Vec<u32>,
 }
 
-impl Recipes {
-    /// Retrieve all discovered recipes' ids.
-    pub fn get_all(client: &Client) -> Result<Recipes, ApiError> {
-        client.request(ENDPOINT_URL)
+endpoints! {
+    impl Recipes {
+        /// Retrieve all discovered recipes' ids.
+        pub fn get_all / async get_all_async(client) -> Recipes {
+            request(ENDPOINT_URL)
+        }
     }
 }
 
@@ -1,8 +1,8 @@
 use serde::Deserialize;
 
-use crate::client::Client;
-use crate::error::ApiError;
+use crate::render::RenderFile;
 use crate::utils::Race;
+use crate::v1::quoted;
 
 const ENDPOINT_URL: &str = "/v1/skin_details";
 
@@ -10,8 +10,8 @@ const ENDPOINT_URL: &str = "/v1/skin_details";
 #[derive(Debug, Deserialize, PartialEq)]
 pub struct Skin {
     /// id of the skin.
-    #[serde(rename = "skin_id")]
-    pub id: String,
+    #[serde(rename = "skin_id", deserialize_with = "quoted::number")]
+    pub id: u32,
     /// Name of the skin.
     pub name: String,
     /// Type of the skin.
@@ -24,7 +24,8 @@ pub struct Skin {
     #[serde(default)]
     pub restrictions: Vec<Race>,
     /// File icon id to be used with the render service.
-    pub icon_file_id: String,
+    #[serde(deserialize_with = "quoted::number")]
+    pub icon_file_id: u32,
     /// File signature to be used with the render service.
     pub icon_file_signature: String,
     /// Optional skin description.
@@ -48,11 +49,22 @@ pub enum SkinFlags {
     HideIfLocked,
 }
 
-impl Skin {
-    /// Retrieve a skin by its id.
-    pub fn get_id(client: &Client, id: String) -> Result<Skin, ApiError> {
-        let url = format!("{}?skin_id={}", ENDPOINT_URL, id);
-        client.request(&url)
+endpoints! {
+    impl Skin {
+        /// Retrieve a skin by its id.
+        pub fn get_id / async get_id_async(client, id: u32) -> Skin {
+            request(format!("{}?skin_id={}", ENDPOINT_URL, id))
+        }
+    }
+}
+
+impl RenderFile for Skin {
+    fn file_id(&self) -> u32 {
+        self.icon_file_id
+    }
+
+    fn file_signature(&self) -> &str {
+        &self.icon_file_signature
     }
 }
 
@@ -66,6 +78,8 @@ mod tests {
     #[test]
     fn create_skin() {
         let json = read_to_string(JSON_SKIN_PATH).unwrap();
-        serde_json::from_str::<Skin>(&json).unwrap();
+        let skin = serde_json::from_str::<Skin>(&json).unwrap();
+        assert_eq!(1350, skin.id);
+        assert_eq!(740312, skin.icon_file_id);
     }
 }
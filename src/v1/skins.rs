@@ -1,8 +1,5 @@
 use serde::Deserialize;
 
-use crate::client::Client;
-use crate::error::ApiError;
-
 const ENDPOINT_URL: &str = "/v1/skins";
 
 /// Contains ids of all skins. Details about a particular skin can be obtained from the
@@ -13,9 +10,11 @@ pub struct Skins {
     pub skins: Vec<u32>,
 }
 
-impl Skins {
-    /// Retrieve all skins' ids.
-    pub fn get_all(client: &Client) -> Result<Skins, ApiError> {
-        client.request(ENDPOINT_URL)
+endpoints! {
+    impl Skins {
+        /// Retrieve all skins' ids.
+        pub fn get_all / async get_all_async(client) -> Skins {
+            request(ENDPOINT_URL)
+        }
     }
 }
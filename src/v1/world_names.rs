@@ -1,8 +1,5 @@
 use serde::Deserialize;
 
-use crate::client::Client;
-use crate::error::ApiError;
-
 const ENDPOINT_URL: &str = "/v1/world_names";
 
 /// Struct containing an unorded list of (localized) world names.
@@ -14,10 +11,12 @@ pub struct World {
     pub name: String,
 }
 
-impl World {
-    /// Retrieve all world names that are in the game.
-    pub fn get_all(client: &Client) -> Result<Vec<World>, ApiError> {
-        client.request(ENDPOINT_URL)
+endpoints! {
+    impl World {
+        /// Retrieve all world names that are in the game.
+        pub fn get_all / async get_all_async(client) -> Vec<World> {
+            request(ENDPOINT_URL)
+        }
     }
 }
 
@@ -1,7 +1,5 @@
 use serde::Deserialize;
 
-use crate::client::Client;
-use crate::error::ApiError;
 use crate::utils::Team;
 
 const ENDPOINT_URL: &str = "/v1/wvw/match_details";
@@ -69,11 +67,12 @@ pub struct Map {
     pub bonuses: Vec<Bonus>,
 }
 
-impl Match {
-    /// Retrieve a match by its id.
-    pub fn get_by_id(client: &Client, id: String) -> Result<Match, ApiError> {
-        let url = format!("{}?match_id={}", ENDPOINT_URL, id);
-        client.request(&url)
+endpoints! {
+    impl Match {
+        /// Retrieve a match by its id.
+        pub fn get_by_id / async get_by_id_async(client, id: String) -> Match {
+            request(format!("{}?match_id={}", ENDPOINT_URL, id))
+        }
     }
 }
 
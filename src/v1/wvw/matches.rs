@@ -1,8 +1,5 @@
 use serde::Deserialize;
 
-use crate::client::Client;
-use crate::error::ApiError;
-
 const ENDPOINT_URL: &str = "/v1/wvw/matches";
 
 /// Struct containing information about currently running WvW matches. Further information can be
@@ -32,10 +29,12 @@ pub struct Match {
     pub end_time: String,
 }
 
-impl Matches {
-    /// Retrieve all currently running WvW matches.
-    pub fn get_all(client: &Client) -> Result<Matches, ApiError> {
-        client.request(ENDPOINT_URL)
+endpoints! {
+    impl Matches {
+        /// Retrieve all currently running WvW matches.
+        pub fn get_all / async get_all_async(client) -> Matches {
+            request(ENDPOINT_URL)
+        }
     }
 }
 
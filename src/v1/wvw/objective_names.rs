@@ -1,8 +1,5 @@
 use serde::Deserialize;
 
-use crate::client::Client;
-use crate::error::ApiError;
-
 const ENDPOINT_URL: &str = "/v1/wvw/objective_names";
 
 /// Struct containing an unordered list of (localized) WvW objective names.
@@ -14,17 +11,18 @@ pub struct Objective {
     pub name: String,
 }
 
-impl Objective {
-    /// Retrieve all objective names that are in the game.
-    pub fn get_all(client: &Client) -> Result<Vec<Objective>, ApiError> {
-        client.request(ENDPOINT_URL)
+endpoints! {
+    impl Objective {
+        /// Retrieve all objective names that are in the game.
+        pub fn get_all / async get_all_async(client) -> Vec<Objective> {
+            request(ENDPOINT_URL)
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::v1::wvw::objective_names::*;
-    use crate::client::Client;
 
     const JSON_OBJECTIVE: &str = r#"
     {
@@ -0,0 +1,78 @@
+use serde::Deserialize;
+
+use crate::endpoint::Endpoint;
+
+const ENDPOINT_URL: &str = "/v2/account/achievements";
+
+/// Progress of the account on every achievement it has made progress on.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct AccountAchievements {
+    pub achievements: Vec<AccountAchievement>,
+}
+
+/// Progress of the account on a single achievement.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct AccountAchievement {
+    /// Id of the achievement, resolvable against `/v2/achievements`.
+    pub id: u32,
+    /// Indices of the bits of the achievement that have been completed.
+    #[serde(default)]
+    pub bits: Vec<u32>,
+    /// Current progress towards the achievement.
+    pub current: Option<u32>,
+    /// Amount of progress needed to complete the achievement.
+    pub max: Option<u32>,
+    /// Whether the achievement has been completed.
+    pub done: bool,
+    /// How many times a repeatable achievement has been completed.
+    pub repeated: Option<u32>,
+    /// Whether the achievement is unlocked, only present for achievements that need unlocking.
+    pub unlocked: Option<bool>,
+}
+
+impl Endpoint for AccountAchievements {
+    const URL: &'static str = ENDPOINT_URL;
+    const AUTHENTICATED: bool = true;
+}
+
+endpoints! {
+    impl AccountAchievements {
+        /// Retrieve the achievement progress of the account.
+        pub fn get_account_achievements / async get_account_achievements_async(client)
+            -> AccountAchievements {
+            authenticated_request(ENDPOINT_URL)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::account::achievements::*;
+
+    const JSON_ACHIEVEMENTS: &str = r#"
+    [
+      {
+        "id": 1,
+        "current": 1,
+        "max": 1000,
+        "done": false
+      },
+      {
+        "id": 2,
+        "bits": [0, 2, 3],
+        "current": 3,
+        "max": 4,
+        "done": true,
+        "repeated": 5,
+        "unlocked": true
+      }
+    ]"#;
+
+    #[test]
+    fn create_account_achievements() {
+        let achievements = serde_json::from_str::<AccountAchievements>(JSON_ACHIEVEMENTS).unwrap();
+        assert!(achievements.achievements[0].bits.is_empty());
+        assert_eq!(Some(5), achievements.achievements[1].repeated);
+    }
+}
@@ -0,0 +1,127 @@
+use serde::Deserialize;
+
+use std::collections::HashMap;
+
+use crate::attributes::Attribute;
+use crate::endpoint::Endpoint;
+
+const ENDPOINT_URL: &str = "/v2/account/bank";
+
+/// The bank vault of the account, one entry per slot.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct Bank {
+    /// Contents of every bank slot in order, `None` for empty slots.
+    pub slots: Vec<Option<ItemSlot>>,
+}
+
+/// A stack of items in a bank, inventory or equipment slot.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct ItemSlot {
+    /// Item id, resolvable against `/v2/items`.
+    pub id: u32,
+    /// Amount of items in the stack.
+    pub count: u32,
+    /// Amount of charges left on the item, for consumables with charges.
+    pub charges: Option<u32>,
+    /// Id of the skin applied to the item, if transmuted.
+    pub skin: Option<u32>,
+    /// Ids of the dyes applied to the item, `None` for channels without a dye.
+    #[serde(default)]
+    pub dyes: Vec<Option<u32>>,
+    /// Item ids of the runes or sigils in the item.
+    #[serde(default)]
+    pub upgrades: Vec<u32>,
+    /// Which upgrade slots the upgrades are in.
+    #[serde(default)]
+    pub upgrade_slot_indices: Vec<u32>,
+    /// Item ids of the infusions in the item.
+    #[serde(default)]
+    pub infusions: Vec<u32>,
+    /// The stats chosen for items with selectable stats.
+    pub stats: Option<ItemStats>,
+    /// Whether the item is bound to the account or a character.
+    pub binding: Option<Binding>,
+    /// Name of the character the item is bound to, if it is soulbound.
+    pub bound_to: Option<String>,
+}
+
+/// Stats that were selected for an item.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct ItemStats {
+    /// Id of the stat combination, resolvable against `/v2/itemstats`.
+    pub id: u32,
+    /// The attribute bonuses the stats give.
+    #[serde(default)]
+    pub attributes: HashMap<Attribute, i32>,
+}
+
+/// What an item is bound to.
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum Binding {
+    Account,
+    Character,
+}
+
+impl Endpoint for Bank {
+    const URL: &'static str = ENDPOINT_URL;
+    const AUTHENTICATED: bool = true;
+}
+
+endpoints! {
+    impl Bank {
+        /// Retrieve the contents of the account's bank vault.
+        pub fn get_bank / async get_bank_async(client) -> Bank {
+            authenticated_request(ENDPOINT_URL)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::account::bank::*;
+
+    const JSON_BANK: &str = r#"
+    [
+      {
+        "id": 30689,
+        "count": 1,
+        "skin": 5879,
+        "upgrades": [24554],
+        "infusions": [49432],
+        "binding": "Account"
+      },
+      null,
+      {
+        "id": 80248,
+        "count": 1,
+        "dyes": [1, null, 473, 1136],
+        "stats": {
+          "id": 1130,
+          "attributes": {
+            "Power": 141,
+            "Precision": 101,
+            "CritDamage": 101
+          }
+        },
+        "binding": "Character",
+        "bound_to": "Hello World"
+      },
+      {
+        "id": 19721,
+        "count": 250
+      }
+    ]"#;
+
+    #[test]
+    fn create_bank() {
+        let bank = serde_json::from_str::<Bank>(JSON_BANK).unwrap();
+        assert_eq!(4, bank.slots.len());
+        assert_eq!(None, bank.slots[1]);
+
+        let armor = bank.slots[2].as_ref().unwrap();
+        assert_eq!(vec![Some(1), None, Some(473), Some(1136)], armor.dyes);
+        assert_eq!(Some(&141), armor.stats.as_ref().unwrap().attributes.get(&Attribute::Power));
+        assert_eq!(Some(Binding::Character), armor.binding);
+    }
+}
@@ -0,0 +1,105 @@
+use serde::Deserialize;
+
+use crate::endpoint::Endpoint;
+use crate::utils::Profession;
+
+const ENDPOINT_URL: &str = "/v2/account/buildstorage";
+
+/// The build templates stored in the build storage of the account.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct BuildStorage {
+    pub builds: Vec<BuildTemplate>,
+}
+
+/// A build template, as stored in the build storage or on a character.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct BuildTemplate {
+    /// Name the player gave to the build.
+    pub name: String,
+    /// Profession the build is for.
+    pub profession: Profession,
+    /// The three chosen specializations.
+    pub specializations: Vec<BuildSpecialization>,
+    /// Skills used on land.
+    pub skills: BuildSkills,
+    /// Skills used underwater.
+    pub aquatic_skills: BuildSkills,
+    /// Legend ids used on land, only for revenants.
+    #[serde(default)]
+    pub legends: Vec<Option<String>>,
+    /// Legend ids used underwater, only for revenants.
+    #[serde(default)]
+    pub aquatic_legends: Vec<Option<String>>,
+    /// Pet ids, only for rangers.
+    pub pets: Option<BuildPets>,
+}
+
+/// A specialization in a build template.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct BuildSpecialization {
+    /// Id of the specialization, `None` if the slot is empty.
+    pub id: Option<u32>,
+    /// Ids of the chosen major traits, one per tier.
+    pub traits: Vec<Option<u32>>,
+}
+
+/// Slot skills in a build template, `None` for empty slots.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct BuildSkills {
+    pub heal: Option<u32>,
+    pub utilities: Vec<Option<u32>>,
+    pub elite: Option<u32>,
+}
+
+/// Pets in a ranger's build template.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct BuildPets {
+    pub terrestrial: Vec<Option<u32>>,
+    pub aquatic: Vec<Option<u32>>,
+}
+
+impl Endpoint for BuildStorage {
+    const URL: &'static str = ENDPOINT_URL;
+    const AUTHENTICATED: bool = true;
+}
+
+endpoints! {
+    impl BuildStorage {
+        /// Retrieve the build templates in the account's build storage.
+        pub fn get_build_storage / async get_build_storage_async(client) -> BuildStorage {
+            authenticated_request(ENDPOINT_URL)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::account::buildstorage::*;
+
+    const JSON_BUILD_STORAGE: &str = r#"
+    [
+      {
+        "name": "Power Soulbeast",
+        "profession": "Ranger",
+        "specializations": [
+          { "id": 8, "traits": [1099, 1100, 1067] },
+          { "id": 32, "traits": [1066, 1065, 1068] },
+          { "id": 55, "traits": [2071, 2161, 2128] }
+        ],
+        "skills": { "heal": 31914, "utilities": [12633, 12489, 45970], "elite": 12580 },
+        "aquatic_skills": { "heal": 21773, "utilities": [12492, null, 12633], "elite": null },
+        "pets": { "terrestrial": [59, 46], "aquatic": [21, 40] }
+      }
+    ]"#;
+
+    #[test]
+    fn create_build_storage() {
+        let storage = serde_json::from_str::<BuildStorage>(JSON_BUILD_STORAGE).unwrap();
+        let build = &storage.builds[0];
+        assert_eq!(Profession::Ranger, build.profession);
+        assert_eq!(vec![Some(12492), None, Some(12633)], build.aquatic_skills.utilities);
+        assert!(build.legends.is_empty());
+        assert_eq!(vec![Some(59), Some(46)], build.pets.as_ref().unwrap().terrestrial);
+    }
+}
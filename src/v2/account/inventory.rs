@@ -0,0 +1,50 @@
+use serde::Deserialize;
+
+use crate::endpoint::Endpoint;
+use crate::v2::account::bank::ItemSlot;
+
+const ENDPOINT_URL: &str = "/v2/account/inventory";
+
+/// The shared inventory slots of the account, which are available to every character.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct SharedInventory {
+    /// Contents of every shared inventory slot in order, `None` for empty slots.
+    pub slots: Vec<Option<ItemSlot>>,
+}
+
+impl Endpoint for SharedInventory {
+    const URL: &'static str = ENDPOINT_URL;
+    const AUTHENTICATED: bool = true;
+}
+
+endpoints! {
+    impl SharedInventory {
+        /// Retrieve the contents of the account's shared inventory slots.
+        pub fn get_shared_inventory / async get_shared_inventory_async(client) -> SharedInventory {
+            authenticated_request(ENDPOINT_URL)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::account::inventory::*;
+
+    const JSON_INVENTORY: &str = r#"
+    [
+      {
+        "id": 78599,
+        "count": 1,
+        "binding": "Account"
+      },
+      null
+    ]"#;
+
+    #[test]
+    fn create_shared_inventory() {
+        let inventory = serde_json::from_str::<SharedInventory>(JSON_INVENTORY).unwrap();
+        assert_eq!(78599, inventory.slots[0].as_ref().unwrap().id);
+        assert_eq!(None, inventory.slots[1]);
+    }
+}
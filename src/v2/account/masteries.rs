@@ -0,0 +1,105 @@
+use serde::Deserialize;
+
+use crate::endpoint::Endpoint;
+
+const ENDPOINT_URL: &str = "/v2/account/masteries";
+const POINTS_ENDPOINT_URL: &str = "/v2/account/mastery/points";
+
+/// The mastery tracks the account has trained.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct AccountMasteries {
+    pub masteries: Vec<AccountMastery>,
+}
+
+/// Progress of the account on a single mastery track.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct AccountMastery {
+    /// Id of the mastery track, resolvable against `/v2/masteries`.
+    pub id: u32,
+    /// Index of the highest level that has been trained, starting at 0.
+    pub level: Option<u32>,
+}
+
+/// The mastery points the account has earned and spent.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct MasteryPoints {
+    /// Earned and spent points per region.
+    pub totals: Vec<RegionPoints>,
+    /// Ids of the mastery points the account has unlocked.
+    pub unlocked: Vec<u32>,
+}
+
+/// Mastery points of a single region.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct RegionPoints {
+    /// Name of the region, e.g. `Tyria` or `Maguuma`.
+    pub region: String,
+    /// Amount of points spent on mastery tracks.
+    pub spent: u32,
+    /// Amount of points earned.
+    pub earned: u32,
+}
+
+impl Endpoint for AccountMasteries {
+    const URL: &'static str = ENDPOINT_URL;
+    const AUTHENTICATED: bool = true;
+}
+
+impl Endpoint for MasteryPoints {
+    const URL: &'static str = POINTS_ENDPOINT_URL;
+    const AUTHENTICATED: bool = true;
+}
+
+endpoints! {
+    impl AccountMasteries {
+        /// Retrieve the mastery tracks the account has trained.
+        pub fn get_account_masteries / async get_account_masteries_async(client)
+            -> AccountMasteries {
+            authenticated_request(ENDPOINT_URL)
+        }
+    }
+}
+
+endpoints! {
+    impl MasteryPoints {
+        /// Retrieve the mastery points the account has earned and spent.
+        pub fn get_mastery_points / async get_mastery_points_async(client) -> MasteryPoints {
+            authenticated_request(POINTS_ENDPOINT_URL)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::account::masteries::*;
+
+    const JSON_MASTERIES: &str = r#"
+    [
+      { "id": 1, "level": 4 },
+      { "id": 2 }
+    ]"#;
+
+    const JSON_MASTERY_POINTS: &str = r#"
+    {
+      "totals": [
+        { "region": "Tyria", "spent": 49, "earned": 62 },
+        { "region": "Maguuma", "spent": 43, "earned": 55 }
+      ],
+      "unlocked": [1, 2, 3, 4]
+    }"#;
+
+    #[test]
+    fn create_account_masteries() {
+        let masteries = serde_json::from_str::<AccountMasteries>(JSON_MASTERIES).unwrap();
+        assert_eq!(Some(4), masteries.masteries[0].level);
+        assert_eq!(None, masteries.masteries[1].level);
+    }
+
+    #[test]
+    fn create_mastery_points() {
+        let points = serde_json::from_str::<MasteryPoints>(JSON_MASTERY_POINTS).unwrap();
+        assert_eq!("Maguuma", points.totals[1].region);
+        assert_eq!(4, points.unlocked.len());
+    }
+}
@@ -0,0 +1,68 @@
+use serde::Deserialize;
+
+use crate::endpoint::Endpoint;
+use crate::v2::account::bank::Binding;
+
+const ENDPOINT_URL: &str = "/v2/account/materials";
+
+/// The material storage of the account.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct Materials {
+    /// Every material the storage has a slot for, including the ones with a count of 0.
+    pub materials: Vec<Material>,
+}
+
+/// A stack of crafting materials in the material storage.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Material {
+    /// Item id of the material.
+    pub id: u32,
+    /// Id of the material category, resolvable against `/v2/materials`.
+    pub category: u32,
+    /// Whether the material is bound to the account.
+    pub binding: Option<Binding>,
+    /// Amount of the material in storage.
+    pub count: u32,
+}
+
+impl Endpoint for Materials {
+    const URL: &'static str = ENDPOINT_URL;
+    const AUTHENTICATED: bool = true;
+}
+
+endpoints! {
+    impl Materials {
+        /// Retrieve the contents of the account's material storage.
+        pub fn get_materials / async get_materials_async(client) -> Materials {
+            authenticated_request(ENDPOINT_URL)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::account::materials::*;
+
+    const JSON_MATERIALS: &str = r#"
+    [
+      {
+        "id": 12134,
+        "category": 5,
+        "count": 250
+      },
+      {
+        "id": 46742,
+        "category": 37,
+        "binding": "Account",
+        "count": 0
+      }
+    ]"#;
+
+    #[test]
+    fn create_materials() {
+        let materials = serde_json::from_str::<Materials>(JSON_MATERIALS).unwrap();
+        assert_eq!(250, materials.materials[0].count);
+        assert_eq!(Some(Binding::Account), materials.materials[1].binding);
+    }
+}
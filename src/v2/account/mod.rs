@@ -0,0 +1,152 @@
+/// Defines types for the account endpoints that return a plain list of ids, along with their
+/// `Endpoint` implementations and getters.
+macro_rules! id_lists {
+    ($( $(#[$attr:meta])* $name:ident($id:ty) = $url:expr, $get:ident / $get_async:ident; )*) => {
+        $(
+            $(#[$attr])*
+            #[derive(Debug, Deserialize, PartialEq)]
+            #[serde(transparent)]
+            pub struct $name {
+                pub ids: Vec<$id>,
+            }
+
+            impl $name {
+                /// Whether the list contains the given id.
+                pub fn contains(&self, id: &$id) -> bool {
+                    self.ids.contains(id)
+                }
+            }
+
+            impl Endpoint for $name {
+                const URL: &'static str = $url;
+                const AUTHENTICATED: bool = true;
+            }
+
+            endpoints! {
+                impl $name {
+                    #[doc = concat!("Retrieve the ids from `", $url, "` for the `Client`'s key.")]
+                    pub fn $get / async $get_async(client) -> $name {
+                        authenticated_request($url)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+pub mod achievements;
+pub mod bank;
+pub mod buildstorage;
+pub mod inventory;
+pub mod masteries;
+pub mod materials;
+pub mod progress;
+pub mod unlocks;
+pub mod wallet;
+
+use serde::Deserialize;
+
+use crate::endpoint::Endpoint;
+
+const ENDPOINT_URL: &str = "/v2/account";
+
+/// General information about the account of the API key.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Account {
+    /// Unique persistent account GUID.
+    pub id: String,
+    /// Age of the account in seconds.
+    pub age: u64,
+    /// Unique account name, including the numeric suffix, e.g. `Example.1234`.
+    pub name: String,
+    /// Id of the home world of the account.
+    pub world: u32,
+    /// Ids of the guilds the account is a member of.
+    pub guilds: Vec<String>,
+    /// Ids of the guilds the account is the leader of, requires the guilds permission.
+    pub guild_leader: Option<Vec<String>>,
+    /// Timestamp of when the account was created.
+    pub created: String,
+    /// Which versions of the game the account has access to.
+    pub access: Vec<Access>,
+    /// Whether the account has unlocked the commander tag.
+    pub commander: bool,
+    /// Personal fractal level, requires the progression permission.
+    pub fractal_level: Option<u32>,
+    /// Amount of daily achievement points, requires the progression permission.
+    pub daily_ap: Option<u32>,
+    /// Amount of monthly achievement points, requires the progression permission.
+    pub monthly_ap: Option<u32>,
+    /// World vs World rank, requires the progression permission.
+    pub wvw_rank: Option<u32>,
+    /// Timestamp of when the account information last changed.
+    pub last_modified: String,
+    /// Amount of build storage slots the account has unlocked, requires the builds permission.
+    pub build_storage_slots: Option<u32>,
+}
+
+/// Versions of the game an account can have access to.
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum Access {
+    None,
+    PlayForFree,
+    GuildWars2,
+    HeartOfThorns,
+    PathOfFire,
+    EndOfDragons,
+    /// An expansion that was released after this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+impl Endpoint for Account {
+    const URL: &'static str = ENDPOINT_URL;
+    const AUTHENTICATED: bool = true;
+}
+
+endpoints! {
+    impl Account {
+        /// Retrieve the general information of the account the `Client`'s key belongs to.
+        pub fn get_account / async get_account_async(client) -> Account {
+            authenticated_request(ENDPOINT_URL)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::account::*;
+
+    const JSON_ACCOUNT: &str = r#"
+    {
+      "id": "A9F9A2C5-5E58-4D3B-A5AF-0ECC8A5C8D34",
+      "name": "Example.1234",
+      "age": 8163420,
+      "world": 1007,
+      "guilds": ["75FD83CF-0C45-4834-BC4C-097F93A487AF"],
+      "guild_leader": ["75FD83CF-0C45-4834-BC4C-097F93A487AF"],
+      "created": "2012-08-21T00:00:00Z",
+      "access": ["GuildWars2", "HeartOfThorns", "PathOfFire", "EndOfDragons"],
+      "commander": true,
+      "fractal_level": 100,
+      "daily_ap": 7210,
+      "monthly_ap": 721,
+      "wvw_rank": 1337,
+      "last_modified": "2022-03-24T12:00:00Z",
+      "build_storage_slots": 6
+    }"#;
+
+    #[test]
+    fn create_account() {
+        let account = serde_json::from_str::<Account>(JSON_ACCOUNT).unwrap();
+        assert_eq!("Example.1234", account.name);
+        assert_eq!(Some(100), account.fractal_level);
+        assert_eq!(Access::EndOfDragons, account.access[3]);
+    }
+
+    #[test]
+    fn unknown_access() {
+        let access = serde_json::from_str::<Vec<Access>>(r#"["GuildWars2", "JanthirWilds"]"#);
+        assert_eq!(vec![Access::GuildWars2, Access::Unknown], access.unwrap());
+    }
+}
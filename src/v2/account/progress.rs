@@ -0,0 +1,80 @@
+//! Account progress that resets daily or weekly, and the account's magic find.
+use serde::Deserialize;
+
+use crate::endpoint::Endpoint;
+
+const LUCK_ENDPOINT_URL: &str = "/v2/account/luck";
+
+id_lists! {
+    /// Ids of the daily crafting items that have been crafted since the daily reset, e.g.
+    /// `lump_of_mithrilium`.
+    AccountDailyCrafting(String) = "/v2/account/dailycrafting",
+        get_account_daily_crafting / get_account_daily_crafting_async;
+    /// Ids of the dungeon paths that have been completed since the daily reset.
+    AccountDungeons(String) = "/v2/account/dungeons",
+        get_account_dungeons / get_account_dungeons_async;
+    /// Ids of the map meta event chests that have been looted since the daily reset.
+    AccountMapChests(String) = "/v2/account/mapchests",
+        get_account_map_chests / get_account_map_chests_async;
+    /// Ids of the raid encounters that have been completed since the weekly reset.
+    AccountRaids(String) = "/v2/account/raids", get_account_raids / get_account_raids_async;
+    /// Ids of the world bosses that have been defeated since the daily reset.
+    AccountWorldBosses(String) = "/v2/account/worldbosses",
+        get_account_world_bosses / get_account_world_bosses_async;
+}
+
+/// The luck the account has consumed, which is where most of its magic find comes from.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct AccountLuck {
+    /// The API returns a single entry with the id `luck`, or none if no luck was consumed yet.
+    pub entries: Vec<LuckEntry>,
+}
+
+/// An entry of `/v2/account/luck`.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct LuckEntry {
+    pub id: String,
+    /// Amount of luck consumed.
+    pub value: u64,
+}
+
+impl AccountLuck {
+    /// Amount of luck the account has consumed.
+    pub fn value(&self) -> u64 {
+        self.entries.iter().map(|entry| entry.value).sum()
+    }
+}
+
+impl Endpoint for AccountLuck {
+    const URL: &'static str = LUCK_ENDPOINT_URL;
+    const AUTHENTICATED: bool = true;
+}
+
+endpoints! {
+    impl AccountLuck {
+        /// Retrieve the amount of luck the account has consumed.
+        pub fn get_account_luck / async get_account_luck_async(client) -> AccountLuck {
+            authenticated_request(LUCK_ENDPOINT_URL)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::account::progress::*;
+
+    #[test]
+    fn create_account_raids() {
+        let json = r#"["vale_guardian", "spirit_woods", "gorseval"]"#;
+        let raids = serde_json::from_str::<AccountRaids>(json).unwrap();
+        assert!(raids.contains(&"gorseval".to_string()));
+    }
+
+    #[test]
+    fn create_account_luck() {
+        let luck = serde_json::from_str::<AccountLuck>(r#"[{"id": "luck", "value": 120000}]"#);
+        assert_eq!(120000, luck.unwrap().value());
+        assert_eq!(0, serde_json::from_str::<AccountLuck>("[]").unwrap().value());
+    }
+}
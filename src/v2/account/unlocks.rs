@@ -0,0 +1,174 @@
+use serde::Deserialize;
+
+use std::collections::{BTreeMap, HashSet};
+
+use crate::endpoint::Endpoint;
+use crate::v2::colors::{Color, DyeUnlocks, Hue};
+
+const FINISHERS_ENDPOINT_URL: &str = "/v2/account/finishers";
+
+id_lists! {
+    /// Ids of the dyes the account has unlocked, resolvable against `/v2/colors`.
+    AccountDyes(u32) = "/v2/account/dyes", get_account_dyes / get_account_dyes_async;
+    /// Ids of the emotes the account has unlocked, e.g. `rank`.
+    AccountEmotes(String) = "/v2/account/emotes", get_account_emotes / get_account_emotes_async;
+    /// Ids of the gliders the account has unlocked, resolvable against `/v2/gliders`.
+    AccountGliders(u32) = "/v2/account/gliders", get_account_gliders / get_account_gliders_async;
+    /// Ids of the home instance cats the account has unlocked.
+    AccountHomeCats(u32) = "/v2/account/home/cats",
+        get_account_home_cats / get_account_home_cats_async;
+    /// Ids of the home instance nodes the account has unlocked, e.g. `quartz_node`.
+    AccountHomeNodes(String) = "/v2/account/home/nodes",
+        get_account_home_nodes / get_account_home_nodes_async;
+    /// Ids of the mail carriers the account has unlocked, resolvable against `/v2/mailcarriers`.
+    AccountMailCarriers(u32) = "/v2/account/mailcarriers",
+        get_account_mail_carriers / get_account_mail_carriers_async;
+    /// Ids of the miniatures the account has unlocked, resolvable against `/v2/minis`.
+    AccountMinis(u32) = "/v2/account/minis", get_account_minis / get_account_minis_async;
+    /// Ids of the mount skins the account has unlocked, resolvable against `/v2/mounts/skins`.
+    AccountMountSkins(u32) = "/v2/account/mounts/skins",
+        get_account_mount_skins / get_account_mount_skins_async;
+    /// Ids of the mounts the account has unlocked, e.g. `raptor`.
+    AccountMountTypes(String) = "/v2/account/mounts/types",
+        get_account_mount_types / get_account_mount_types_async;
+    /// Ids of the novelties the account has unlocked, resolvable against `/v2/novelties`.
+    AccountNovelties(u32) = "/v2/account/novelties",
+        get_account_novelties / get_account_novelties_async;
+    /// Ids of the outfits the account has unlocked, resolvable against `/v2/outfits`.
+    AccountOutfits(u32) = "/v2/account/outfits", get_account_outfits / get_account_outfits_async;
+    /// Ids of the Stronghold heroes the account has unlocked, see `v2::pvp::heroes`.
+    AccountPvpHeroes(u32) = "/v2/account/pvp/heroes",
+        get_account_pvp_heroes / get_account_pvp_heroes_async;
+    /// Ids of the recipes the account has learned, resolvable against `/v2/recipes`.
+    AccountRecipes(u32) = "/v2/account/recipes", get_account_recipes / get_account_recipes_async;
+    /// Ids of the skins the account has unlocked, resolvable against `/v2/skins`.
+    AccountSkins(u32) = "/v2/account/skins", get_account_skins / get_account_skins_async;
+    /// Ids of the titles the account has unlocked, resolvable against `/v2/titles`.
+    AccountTitles(u32) = "/v2/account/titles", get_account_titles / get_account_titles_async;
+}
+
+impl AccountDyes {
+    /// Splits the colors, e.g. those of `client.get_all::<Color>()`, into the dyes the account
+    /// owns and those it is missing, grouped by hue. Colors without a hue, such as Dye Remover,
+    /// are grouped under `None`.
+    pub fn by_hue<'a>(&self, colors: &'a [Color]) -> BTreeMap<Option<Hue>, DyeUnlocks<'a>> {
+        let unlocked: HashSet<u32> = self.ids.iter().copied().collect();
+        let mut hues: BTreeMap<Option<Hue>, DyeUnlocks<'a>> = BTreeMap::new();
+        for color in colors {
+            let dyes = hues.entry(color.categories.hue).or_default();
+            if unlocked.contains(&color.id) {
+                dyes.owned.push(color);
+            } else {
+                dyes.missing.push(color);
+            }
+        }
+        hues
+    }
+}
+
+/// The finishers the account has unlocked.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct AccountFinishers {
+    pub finishers: Vec<AccountFinisher>,
+}
+
+/// A finisher the account has unlocked, either permanently or a number of times.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct AccountFinisher {
+    /// Id of the finisher, resolvable against `/v2/finishers`.
+    pub id: u32,
+    /// Whether the finisher is unlocked permanently.
+    pub permanent: bool,
+    /// How many uses are left, if the finisher is not unlocked permanently.
+    pub quantity: Option<u32>,
+}
+
+impl Endpoint for AccountFinishers {
+    const URL: &'static str = FINISHERS_ENDPOINT_URL;
+    const AUTHENTICATED: bool = true;
+}
+
+endpoints! {
+    impl AccountFinishers {
+        /// Retrieve the finishers the account has unlocked.
+        pub fn get_account_finishers / async get_account_finishers_async(client)
+            -> AccountFinishers {
+            authenticated_request(FINISHERS_ENDPOINT_URL)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::account::unlocks::*;
+
+    const JSON_FINISHERS: &str = r#"
+    [
+      { "id": 1, "permanent": true },
+      { "id": 2, "permanent": false, "quantity": 5 }
+    ]"#;
+
+    const JSON_COLORS: &str = r#"
+    [
+      {
+        "id": 1, "name": "Dye Remover", "base_rgb": [128, 26, 26],
+        "cloth": { "brightness": 0, "contrast": 1, "hue": 0, "saturation": 0, "lightness": 1, "rgb": [128, 26, 26] },
+        "leather": { "brightness": 0, "contrast": 1, "hue": 0, "saturation": 0, "lightness": 1, "rgb": [128, 26, 26] },
+        "metal": { "brightness": 0, "contrast": 1, "hue": 0, "saturation": 0, "lightness": 1, "rgb": [128, 26, 26] },
+        "categories": []
+      },
+      {
+        "id": 10, "name": "Sky", "base_rgb": [128, 26, 26],
+        "cloth": { "brightness": 0, "contrast": 1, "hue": 0, "saturation": 0, "lightness": 1, "rgb": [128, 26, 26] },
+        "leather": { "brightness": 0, "contrast": 1, "hue": 0, "saturation": 0, "lightness": 1, "rgb": [128, 26, 26] },
+        "metal": { "brightness": 0, "contrast": 1, "hue": 0, "saturation": 0, "lightness": 1, "rgb": [128, 26, 26] },
+        "categories": ["Blue", "Vibrant", "Rare"]
+      },
+      {
+        "id": 11, "name": "Midnight Ice", "base_rgb": [128, 26, 26],
+        "cloth": { "brightness": 0, "contrast": 1, "hue": 0, "saturation": 0, "lightness": 1, "rgb": [128, 26, 26] },
+        "leather": { "brightness": 0, "contrast": 1, "hue": 0, "saturation": 0, "lightness": 1, "rgb": [128, 26, 26] },
+        "metal": { "brightness": 0, "contrast": 1, "hue": 0, "saturation": 0, "lightness": 1, "rgb": [128, 26, 26] },
+        "categories": ["Blue", "Metal", "Uncommon"]
+      },
+      {
+        "id": 12, "name": "Blood", "base_rgb": [128, 26, 26],
+        "cloth": { "brightness": 0, "contrast": 1, "hue": 0, "saturation": 0, "lightness": 1, "rgb": [128, 26, 26] },
+        "leather": { "brightness": 0, "contrast": 1, "hue": 0, "saturation": 0, "lightness": 1, "rgb": [128, 26, 26] },
+        "metal": { "brightness": 0, "contrast": 1, "hue": 0, "saturation": 0, "lightness": 1, "rgb": [128, 26, 26] },
+        "categories": ["Red", "Leather", "Common"]
+      }
+    ]"#;
+
+    #[test]
+    fn create_id_lists() {
+        let dyes = serde_json::from_str::<AccountDyes>("[1, 2, 3, 473]").unwrap();
+        assert!(dyes.contains(&473));
+        let mounts = serde_json::from_str::<AccountMountTypes>(r#"["raptor", "skimmer"]"#).unwrap();
+        assert!(mounts.contains(&"skimmer".to_string()));
+    }
+
+    #[test]
+    fn dyes_by_hue() {
+        let colors: Vec<Color> = serde_json::from_str(JSON_COLORS).unwrap();
+        let dyes = serde_json::from_str::<AccountDyes>("[1, 10, 473]").unwrap();
+        let hues = dyes.by_hue(&colors);
+        assert_eq!(vec![None, Some(Hue::Red), Some(Hue::Blue)],
+            hues.keys().copied().collect::<Vec<_>>());
+
+        let ids = |dyes: &[&Color]| dyes.iter().map(|color| color.id).collect::<Vec<_>>();
+        assert_eq!(vec![1], ids(&hues[&None].owned));
+        assert_eq!(vec![10], ids(&hues[&Some(Hue::Blue)].owned));
+        assert_eq!(vec![11], ids(&hues[&Some(Hue::Blue)].missing));
+        assert!(hues[&Some(Hue::Red)].owned.is_empty());
+        assert_eq!(vec![12], ids(&hues[&Some(Hue::Red)].missing));
+    }
+
+    #[test]
+    fn create_account_finishers() {
+        let finishers = serde_json::from_str::<AccountFinishers>(JSON_FINISHERS).unwrap();
+        assert_eq!(None, finishers.finishers[0].quantity);
+        assert_eq!(Some(5), finishers.finishers[1].quantity);
+    }
+}
@@ -0,0 +1,64 @@
+use serde::Deserialize;
+
+use crate::endpoint::Endpoint;
+
+const ENDPOINT_URL: &str = "/v2/account/wallet";
+
+/// The currencies in the wallet of the account.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct Wallet {
+    /// Every currency the account holds any of.
+    pub currencies: Vec<WalletCurrency>,
+}
+
+/// The amount of a single currency in the wallet.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct WalletCurrency {
+    /// Id of the currency, resolvable against `/v2/currencies`. Coins have the id 1.
+    pub id: u32,
+    /// Amount of the currency, in copper for coins.
+    pub value: u64,
+}
+
+impl Wallet {
+    /// Returns the amount of the currency with the given id, 0 if the account holds none of it.
+    pub fn amount(&self, currency_id: u32) -> u64 {
+        self.currencies.iter()
+            .find(|currency| currency.id == currency_id)
+            .map_or(0, |currency| currency.value)
+    }
+}
+
+impl Endpoint for Wallet {
+    const URL: &'static str = ENDPOINT_URL;
+    const AUTHENTICATED: bool = true;
+}
+
+endpoints! {
+    impl Wallet {
+        /// Retrieve the currencies in the account's wallet.
+        pub fn get_wallet / async get_wallet_async(client) -> Wallet {
+            authenticated_request(ENDPOINT_URL)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::account::wallet::*;
+
+    const JSON_WALLET: &str = r#"
+    [
+      { "id": 1, "value": 12345678 },
+      { "id": 2, "value": 50000 },
+      { "id": 23, "value": 120 }
+    ]"#;
+
+    #[test]
+    fn create_wallet() {
+        let wallet = serde_json::from_str::<Wallet>(JSON_WALLET).unwrap();
+        assert_eq!(12345678, wallet.amount(1));
+        assert_eq!(0, wallet.amount(4));
+    }
+}
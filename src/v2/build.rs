@@ -1,21 +1,26 @@
 use serde::Deserialize;
 
-use crate::client::Client;
-use crate::error::ApiError;
+use crate::endpoint::Endpoint;
 
 const ENDPOINT_URL: &str = "/v2/build";
 
 /// Contains a Guild Wars 2 build version id
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, PartialEq)]
 pub struct Build {
     /// The current build version id
     pub id: u32,
 }
 
-impl Build {
-    /// Returns a Build struct containing the current build version within the `id` field.
-    pub fn get_build(client: &Client) -> Result<Build, ApiError> {
-       client.request(ENDPOINT_URL)
+impl Endpoint for Build {
+    const URL: &'static str = ENDPOINT_URL;
+}
+
+endpoints! {
+    impl Build {
+        /// Returns a Build struct containing the current build version within the `id` field.
+        pub fn get_build / async get_build_async(client) -> Build {
+            request(ENDPOINT_URL)
+        }
     }
 }
 
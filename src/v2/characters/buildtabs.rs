@@ -0,0 +1,64 @@
+use serde::Deserialize;
+
+use crate::v2::account::buildstorage::BuildTemplate;
+use crate::v2::characters::character_url;
+
+/// A build tab of a character, see `/v2/characters/:id/buildtabs`.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct BuildTab {
+    /// Number of the tab, starting at 1.
+    pub tab: u32,
+    /// Whether this is the active tab.
+    pub is_active: bool,
+    /// The build stored in the tab.
+    pub build: BuildTemplate,
+}
+
+endpoints! {
+    impl BuildTab {
+        /// Retrieve every build tab of the character with the given name.
+        pub fn get_build_tabs / async get_build_tabs_async(client, name: &str) -> Vec<BuildTab> {
+            authenticated_request(character_url(name, "buildtabs?tabs=all"))
+        }
+
+        /// Retrieve the active build tab of the character with the given name.
+        pub fn get_active_build_tab / async get_active_build_tab_async(client, name: &str)
+            -> BuildTab {
+            authenticated_request(character_url(name, "buildtabs/active"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::characters::buildtabs::*;
+    use crate::utils::Profession;
+
+    const JSON_BUILD_TAB: &str = r#"
+    {
+      "tab": 2,
+      "is_active": false,
+      "build": {
+        "name": "Herald",
+        "profession": "Revenant",
+        "specializations": [
+          { "id": 15, "traits": [1761, 1774, 1749] },
+          { "id": 3, "traits": [1706, 1714, 1732] },
+          { "id": null, "traits": [null, null, null] }
+        ],
+        "skills": { "heal": null, "utilities": [null, null, null], "elite": null },
+        "aquatic_skills": { "heal": null, "utilities": [null, null, null], "elite": null },
+        "legends": ["Legend3", "Legend2"],
+        "aquatic_legends": [null, null]
+      }
+    }"#;
+
+    #[test]
+    fn create_build_tab() {
+        let tab = serde_json::from_str::<BuildTab>(JSON_BUILD_TAB).unwrap();
+        assert_eq!(Profession::Revenant, tab.build.profession);
+        assert_eq!(None, tab.build.specializations[2].id);
+        assert_eq!(Some("Legend3".to_string()), tab.build.legends[0]);
+        assert_eq!(None, tab.build.pets);
+    }
+}
@@ -0,0 +1,181 @@
+use serde::Deserialize;
+
+use crate::v2::account::bank::{Binding, ItemStats};
+use crate::v2::characters::character_url;
+
+/// Equipment of a character, see `/v2/characters/:id/equipment`.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct CharacterEquipment {
+    pub equipment: Vec<EquipmentItem>,
+}
+
+/// A piece of equipment of a character.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct EquipmentItem {
+    /// Item id, resolvable against `/v2/items`.
+    pub id: u32,
+    /// The slot the item is equipped in, `None` for items that are only in the armory.
+    pub slot: Option<EquipmentSlot>,
+    /// Item ids of the infusions in the item.
+    #[serde(default)]
+    pub infusions: Vec<u32>,
+    /// Item ids of the runes or sigils in the item.
+    #[serde(default)]
+    pub upgrades: Vec<u32>,
+    /// Id of the skin applied to the item, if transmuted.
+    pub skin: Option<u32>,
+    /// The stats chosen for items with selectable stats.
+    pub stats: Option<ItemStats>,
+    /// Whether the item is bound to the account or a character.
+    pub binding: Option<Binding>,
+    /// Name of the character the item is bound to, if it is soulbound.
+    pub bound_to: Option<String>,
+    /// Amount of charges left, for gathering tools and the like.
+    pub charges: Option<u32>,
+    /// Ids of the dyes applied to the item, `None` for channels without a dye.
+    #[serde(default)]
+    pub dyes: Vec<Option<u32>>,
+    /// Where the item is stored.
+    pub location: Option<EquipmentLocation>,
+    /// The equipment tabs the item is used in.
+    #[serde(default)]
+    pub tabs: Vec<u32>,
+}
+
+/// The slots a character can equip items in.
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum EquipmentSlot {
+    HelmAquatic,
+    Backpack,
+    Coat,
+    Boots,
+    Gloves,
+    Helm,
+    Leggings,
+    Shoulders,
+    Accessory1,
+    Accessory2,
+    Ring1,
+    Ring2,
+    Amulet,
+    WeaponAquaticA,
+    WeaponAquaticB,
+    WeaponA1,
+    WeaponA2,
+    WeaponB1,
+    WeaponB2,
+    Sickle,
+    Axe,
+    Pick,
+    PowerCore,
+    SensoryArray,
+    ServiceChip,
+    /// A slot that was added after this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Where an equipped item is stored.
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum EquipmentLocation {
+    /// Equipped in the active equipment tab.
+    Equipped,
+    /// Stored in an inactive equipment tab.
+    Armory,
+    /// Equipped in the active tab from the legendary armory.
+    EquippedFromLegendaryArmory,
+    /// Used from the legendary armory in an inactive tab.
+    LegendaryArmory,
+}
+
+/// An equipment tab of a character, see `/v2/characters/:id/equipmenttabs`.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct EquipmentTab {
+    /// Number of the tab, starting at 1.
+    pub tab: u32,
+    /// Name the player gave to the tab.
+    pub name: String,
+    /// Whether this is the active tab.
+    pub is_active: bool,
+    /// Equipment in the tab.
+    pub equipment: Vec<EquipmentItem>,
+    /// Equipment used in structured PvP.
+    pub equipment_pvp: PvpEquipment,
+}
+
+/// The amulet, rune and sigils a character uses in structured PvP.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct PvpEquipment {
+    /// Id of the amulet, resolvable against `/v2/pvp/amulets`.
+    pub amulet: Option<u32>,
+    /// Item id of the rune.
+    pub rune: Option<u32>,
+    /// Item ids of the sigils.
+    pub sigils: Vec<Option<u32>>,
+}
+
+endpoints! {
+    impl CharacterEquipment {
+        /// Retrieve the equipment of the character with the given name.
+        pub fn get_character_equipment / async get_character_equipment_async(client, name: &str)
+            -> CharacterEquipment {
+            authenticated_request(character_url(name, "equipment"))
+        }
+    }
+}
+
+endpoints! {
+    impl EquipmentTab {
+        /// Retrieve every equipment tab of the character with the given name.
+        pub fn get_equipment_tabs / async get_equipment_tabs_async(client, name: &str)
+            -> Vec<EquipmentTab> {
+            authenticated_request(character_url(name, "equipmenttabs?tabs=all"))
+        }
+
+        /// Retrieve the active equipment tab of the character with the given name.
+        pub fn get_active_equipment_tab / async get_active_equipment_tab_async(client, name: &str)
+            -> EquipmentTab {
+            authenticated_request(character_url(name, "equipmenttabs/active"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::characters::equipment::*;
+
+    const JSON_EQUIPMENT_TAB: &str = r#"
+    {
+      "tab": 1,
+      "name": "Raids",
+      "is_active": true,
+      "equipment": [
+        {
+          "id": 48074,
+          "slot": "Helm",
+          "infusions": [49432],
+          "upgrades": [24836],
+          "skin": 7130,
+          "stats": { "id": 161, "attributes": { "Power": 63, "Precision": 45, "CritDamage": 45 } },
+          "binding": "Account",
+          "location": "Equipped",
+          "tabs": [1, 2],
+          "dyes": [473, null, null, null]
+        },
+        {
+          "id": 80111,
+          "slot": "Relic",
+          "location": "EquippedFromLegendaryArmory"
+        }
+      ],
+      "equipment_pvp": { "amulet": 30, "rune": 24836, "sigils": [24615, null, null, null] }
+    }"#;
+
+    #[test]
+    fn create_equipment_tab() {
+        let tab = serde_json::from_str::<EquipmentTab>(JSON_EQUIPMENT_TAB).unwrap();
+        assert_eq!(Some(EquipmentSlot::Helm), tab.equipment[0].slot);
+        assert_eq!(Some(EquipmentSlot::Unknown), tab.equipment[1].slot);
+        assert_eq!(Some(30), tab.equipment_pvp.amulet);
+    }
+}
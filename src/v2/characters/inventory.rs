@@ -0,0 +1,69 @@
+use serde::Deserialize;
+
+use crate::v2::account::bank::ItemSlot;
+use crate::v2::characters::character_url;
+
+/// Inventory of a character, see `/v2/characters/:id/inventory`.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct CharacterInventory {
+    /// The bags of the character in order, `None` for empty bag slots.
+    pub bags: Vec<Option<Bag>>,
+}
+
+/// An equipped bag and its contents.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Bag {
+    /// Item id of the bag.
+    pub id: u32,
+    /// Amount of slots in the bag.
+    pub size: u32,
+    /// Contents of every slot of the bag, `None` for empty slots.
+    pub inventory: Vec<Option<ItemSlot>>,
+}
+
+impl CharacterInventory {
+    /// Iterates over the items in every bag.
+    pub fn items(&self) -> impl Iterator<Item = &ItemSlot> {
+        self.bags.iter()
+            .flatten()
+            .flat_map(|bag| bag.inventory.iter().flatten())
+    }
+}
+
+endpoints! {
+    impl CharacterInventory {
+        /// Retrieve the inventory bags of the character with the given name.
+        pub fn get_character_inventory / async get_character_inventory_async(client, name: &str)
+            -> CharacterInventory {
+            authenticated_request(character_url(name, "inventory"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::characters::inventory::*;
+
+    const JSON_INVENTORY: &str = r#"
+    {
+      "bags": [
+        {
+          "id": 8932,
+          "size": 20,
+          "inventory": [
+            { "id": 19721, "count": 250, "binding": "Account" },
+            null,
+            { "id": 24836, "count": 3 }
+          ]
+        },
+        null
+      ]
+    }"#;
+
+    #[test]
+    fn create_character_inventory() {
+        let inventory = serde_json::from_str::<CharacterInventory>(JSON_INVENTORY).unwrap();
+        assert_eq!(2, inventory.bags.len());
+        assert_eq!(vec![19721, 24836], inventory.items().map(|item| item.id).collect::<Vec<_>>());
+    }
+}
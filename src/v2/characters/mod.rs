@@ -0,0 +1,274 @@
+pub mod buildtabs;
+pub mod equipment;
+pub mod inventory;
+pub mod sab;
+pub mod training;
+
+use serde::Deserialize;
+
+use crate::endpoint::{BulkEndpoint, Endpoint};
+use crate::utils::{percent_encode, Discipline, Gender, Profession, Race};
+use crate::v2::characters::buildtabs::BuildTab;
+use crate::v2::characters::equipment::{EquipmentItem, EquipmentTab};
+use crate::v2::characters::inventory::Bag;
+use crate::v2::characters::training::Training;
+
+const ENDPOINT_URL: &str = "/v2/characters";
+
+/// Url of a sub-resource of the character with the given name, e.g. `core`.
+pub(crate) fn character_url(name: &str, resource: &str) -> String {
+    format!("{}/{}/{}", ENDPOINT_URL, percent_encode(name), resource)
+}
+
+/// A character of the account, as returned when requesting characters by name. Apart from the
+/// core fields, every field requires an additional permission on the API key and is left empty
+/// without it.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Character {
+    /// Name of the character, which doubles as its id.
+    pub name: String,
+    pub race: Race,
+    pub gender: Gender,
+    pub profession: Profession,
+    pub level: u32,
+    /// Id of the guild the character is representing.
+    pub guild: Option<String>,
+    /// Time the character has been played, in seconds.
+    pub age: u64,
+    /// Timestamp of when the character was last changed.
+    pub last_modified: String,
+    /// Timestamp of when the character was created.
+    pub created: String,
+    pub deaths: u32,
+    /// Id of the selected title, resolvable against `/v2/titles`.
+    pub title: Option<u32>,
+    /// Crafting disciplines of the character, requires the inventories permission.
+    #[serde(default)]
+    pub crafting: Vec<Crafting>,
+    /// Ids of the answers to the biography questions, resolvable against `/v2/backstory/answers`.
+    #[serde(default)]
+    pub backstory: Vec<String>,
+    /// World vs World abilities the character has trained.
+    #[serde(default)]
+    pub wvw_abilities: Vec<WvwAbility>,
+    /// Amount of build tabs the character has unlocked, requires the builds permission.
+    pub build_tabs_unlocked: Option<u32>,
+    /// Number of the active build tab, requires the builds permission.
+    pub active_build_tab: Option<u32>,
+    /// Every build tab of the character, requires the builds permission.
+    #[serde(default)]
+    pub build_tabs: Vec<BuildTab>,
+    /// Amount of equipment tabs the character has unlocked, requires the builds permission.
+    pub equipment_tabs_unlocked: Option<u32>,
+    /// Number of the active equipment tab, requires the builds permission.
+    pub active_equipment_tab: Option<u32>,
+    /// All equipment of the character, requires the inventories permission.
+    #[serde(default)]
+    pub equipment: Vec<EquipmentItem>,
+    /// Every equipment tab of the character, requires the builds permission.
+    #[serde(default)]
+    pub equipment_tabs: Vec<EquipmentTab>,
+    /// Ids of the recipes the character can use, requires the inventories permission.
+    #[serde(default)]
+    pub recipes: Vec<u32>,
+    /// Skill tree progress of the character, requires the builds permission.
+    #[serde(default)]
+    pub training: Vec<Training>,
+    /// Inventory bags of the character, `None` for empty bag slots. Requires the inventories
+    /// permission.
+    #[serde(default)]
+    pub bags: Vec<Option<Bag>>,
+}
+
+/// The core information of a character, see `/v2/characters/:id/core`.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct CharacterCore {
+    pub name: String,
+    pub race: Race,
+    pub gender: Gender,
+    pub profession: Profession,
+    pub level: u32,
+    pub guild: Option<String>,
+    pub age: u64,
+    pub last_modified: String,
+    pub created: String,
+    pub deaths: u32,
+    pub title: Option<u32>,
+}
+
+/// A crafting discipline of a character.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Crafting {
+    pub discipline: Discipline,
+    /// Crafting level of the discipline.
+    pub rating: u32,
+    /// Whether the discipline is one of the two active ones.
+    pub active: bool,
+}
+
+/// A trained World vs World ability.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct WvwAbility {
+    /// Id of the ability, resolvable against `/v2/wvw/abilities`.
+    pub id: u32,
+    pub rank: u32,
+}
+
+/// Crafting disciplines of a character, see `/v2/characters/:id/crafting`.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct CharacterCrafting {
+    pub crafting: Vec<Crafting>,
+}
+
+/// Backstory of a character, see `/v2/characters/:id/backstory`.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct CharacterBackstory {
+    pub backstory: Vec<String>,
+}
+
+/// Recipes a character can use, see `/v2/characters/:id/recipes`.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct CharacterRecipes {
+    pub recipes: Vec<u32>,
+}
+
+/// Ids of the hero challenges a character has completed, see `/v2/characters/:id/heropoints`.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct CharacterHeroPoints {
+    pub ids: Vec<String>,
+}
+
+impl Endpoint for Character {
+    const URL: &'static str = ENDPOINT_URL;
+    const AUTHENTICATED: bool = true;
+}
+
+impl BulkEndpoint for Character {
+    type Id = String;
+
+    fn id(&self) -> &Self::Id {
+        &self.name
+    }
+}
+
+endpoints! {
+    impl CharacterCore {
+        /// Retrieve the core information of the character with the given name.
+        pub fn get_character_core / async get_character_core_async(client, name: &str)
+            -> CharacterCore {
+            authenticated_request(character_url(name, "core"))
+        }
+    }
+}
+
+endpoints! {
+    impl CharacterCrafting {
+        /// Retrieve the crafting disciplines of the character with the given name.
+        pub fn get_character_crafting / async get_character_crafting_async(client, name: &str)
+            -> CharacterCrafting {
+            authenticated_request(character_url(name, "crafting"))
+        }
+    }
+}
+
+endpoints! {
+    impl CharacterBackstory {
+        /// Retrieve the backstory of the character with the given name.
+        pub fn get_character_backstory / async get_character_backstory_async(client, name: &str)
+            -> CharacterBackstory {
+            authenticated_request(character_url(name, "backstory"))
+        }
+    }
+}
+
+endpoints! {
+    impl CharacterRecipes {
+        /// Retrieve the recipes the character with the given name can use.
+        pub fn get_character_recipes / async get_character_recipes_async(client, name: &str)
+            -> CharacterRecipes {
+            authenticated_request(character_url(name, "recipes"))
+        }
+    }
+}
+
+endpoints! {
+    impl CharacterHeroPoints {
+        /// Retrieve the hero challenges the character with the given name has completed.
+        pub fn get_character_hero_points / async get_character_hero_points_async(client,
+            name: &str) -> CharacterHeroPoints {
+            authenticated_request(character_url(name, "heropoints"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::characters::*;
+
+    const JSON_CHARACTER: &str = r#"
+    {
+      "name": "Hello World",
+      "race": "Norn",
+      "gender": "Female",
+      "flags": [],
+      "profession": "Guardian",
+      "level": 80,
+      "guild": "75FD83CF-0C45-4834-BC4C-097F93A487AF",
+      "age": 2384040,
+      "last_modified": "2022-03-24T12:00:00Z",
+      "created": "2015-01-01T00:00:00Z",
+      "deaths": 537,
+      "crafting": [
+        { "discipline": "Armorsmith", "rating": 500, "active": true },
+        { "discipline": "Weaponsmith", "rating": 400, "active": false }
+      ],
+      "title": 299,
+      "backstory": ["26-118", "27-124"],
+      "wvw_abilities": [{ "id": 2, "rank": 5 }],
+      "build_tabs_unlocked": 3,
+      "active_build_tab": 1,
+      "build_tabs": [],
+      "equipment_tabs_unlocked": 2,
+      "active_equipment_tab": 1,
+      "equipment": [],
+      "equipment_tabs": [],
+      "recipes": [7, 8],
+      "training": [{ "id": 10, "spent": 290, "done": true }],
+      "bags": [null]
+    }"#;
+
+    const JSON_CORE: &str = r#"
+    {
+      "name": "Hello World",
+      "race": "Charr",
+      "gender": "Male",
+      "profession": "Engineer",
+      "level": 42,
+      "age": 3600,
+      "created": "2022-03-01T00:00:00Z",
+      "last_modified": "2022-03-02T00:00:00Z",
+      "deaths": 0
+    }"#;
+
+    #[test]
+    fn create_character() {
+        let character = serde_json::from_str::<Character>(JSON_CHARACTER).unwrap();
+        assert_eq!("Hello World", character.id());
+        assert_eq!(Discipline::Armorsmith, character.crafting[0].discipline);
+        assert_eq!(vec![None], character.bags);
+    }
+
+    #[test]
+    fn create_character_core() {
+        let core = serde_json::from_str::<CharacterCore>(JSON_CORE).unwrap();
+        assert_eq!(Race::Charr, core.race);
+        assert_eq!(None, core.guild);
+        assert_eq!(None, core.title);
+    }
+
+    #[test]
+    fn encode_character_name() {
+        assert_eq!("/v2/characters/Hello%20World/core", character_url("Hello World", "core"));
+    }
+}
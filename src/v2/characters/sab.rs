@@ -0,0 +1,81 @@
+use serde::Deserialize;
+
+use crate::v2::characters::character_url;
+
+/// Super Adventure Box progress of a character, see `/v2/characters/:id/sab`.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct SuperAdventureBox {
+    /// Zones the character has completed.
+    pub zones: Vec<SabZone>,
+    /// Upgrades the character has unlocked.
+    pub unlocks: Vec<SabUnlock>,
+    /// Songs the character has unlocked.
+    pub songs: Vec<SabSong>,
+}
+
+/// A completed Super Adventure Box zone.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct SabZone {
+    pub id: u32,
+    /// Difficulty the zone was completed on.
+    pub mode: SabMode,
+    /// World number of the zone.
+    pub world: u32,
+    /// Zone number within the world.
+    pub zone: u32,
+}
+
+/// Difficulties of Super Adventure Box.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum SabMode {
+    Infantile,
+    Normal,
+    Tribulation,
+}
+
+/// An unlocked Super Adventure Box upgrade, e.g. `slingshot`.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct SabUnlock {
+    pub id: u32,
+    pub name: Option<String>,
+}
+
+/// An unlocked Super Adventure Box song, e.g. `secret_song`.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct SabSong {
+    pub id: u32,
+    pub name: Option<String>,
+}
+
+endpoints! {
+    impl SuperAdventureBox {
+        /// Retrieve the Super Adventure Box progress of the character with the given name.
+        pub fn get_character_sab / async get_character_sab_async(client, name: &str)
+            -> SuperAdventureBox {
+            authenticated_request(character_url(name, "sab"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::characters::sab::*;
+
+    const JSON_SAB: &str = r#"
+    {
+      "zones": [
+        { "id": 1, "mode": "normal", "world": 1, "zone": 1 },
+        { "id": 7, "mode": "tribulation", "world": 2, "zone": 3 }
+      ],
+      "unlocks": [{ "id": 1, "name": "slingshot" }],
+      "songs": [{ "id": 1, "name": "secret_song" }]
+    }"#;
+
+    #[test]
+    fn create_sab() {
+        let sab = serde_json::from_str::<SuperAdventureBox>(JSON_SAB).unwrap();
+        assert_eq!(SabMode::Tribulation, sab.zones[1].mode);
+        assert_eq!(Some("slingshot".to_string()), sab.unlocks[0].name);
+    }
+}
@@ -0,0 +1,42 @@
+use serde::Deserialize;
+
+use crate::v2::characters::character_url;
+
+/// Skill tree progress of a character, see `/v2/characters/:id/training`.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct CharacterTraining {
+    pub training: Vec<Training>,
+}
+
+/// Progress in a single skill tree.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Training {
+    /// Id of the skill tree, resolvable against the `training` field of `/v2/professions`.
+    pub id: u32,
+    /// Amount of hero points spent in the tree.
+    pub spent: u32,
+    /// Whether the tree is fully trained.
+    pub done: bool,
+}
+
+endpoints! {
+    impl CharacterTraining {
+        /// Retrieve the skill tree progress of the character with the given name.
+        pub fn get_character_training / async get_character_training_async(client, name: &str)
+            -> CharacterTraining {
+            authenticated_request(character_url(name, "training"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::characters::training::*;
+
+    #[test]
+    fn create_training() {
+        let json = r#"{ "training": [{ "id": 10, "spent": 290, "done": true }] }"#;
+        let training = serde_json::from_str::<CharacterTraining>(json).unwrap();
+        assert!(training.training[0].done);
+    }
+}
@@ -0,0 +1,228 @@
+use serde::de::IgnoredAny;
+use serde::Deserialize;
+
+use crate::color::Rgb;
+use crate::endpoint::{BulkEndpoint, Endpoint};
+use crate::v1::colors::{Material, MaterialKind};
+
+/// A dye, along with its appearance on the different kinds of armor.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Color {
+    /// id of the dye, as used by `/v2/account/dyes`.
+    pub id: u32,
+    /// Name of the dye.
+    pub name: String,
+    /// The base RGB values.
+    pub base_rgb: Vec<u8>,
+    /// Appearance of the dye on *cloth* armor.
+    pub cloth: Material,
+    /// Appearance of the dye on *leather* armor.
+    pub leather: Material,
+    /// Appearance of the dye on *metal* armor.
+    pub metal: Material,
+    /// Appearance of the dye on *fur*, if it differs from the other materials.
+    pub fur: Option<Material>,
+    /// Item id of the dye, resolvable against `/v2/items`. Not every color has an item, e.g.
+    /// Dye Remover.
+    pub item: Option<u32>,
+    /// The hue, material and rarity of the dye.
+    #[serde(default)]
+    pub categories: Categories,
+}
+
+impl Color {
+    /// The base color, which the materials shift into their own colors.
+    pub fn base_rgb(&self) -> Option<Rgb> {
+        Rgb::from_slice(&self.base_rgb)
+    }
+
+    /// The appearance of the dye on the given kind of armor, if there is one.
+    pub fn material(&self, material: MaterialKind) -> Option<&Material> {
+        match material {
+            MaterialKind::Cloth => Some(&self.cloth),
+            MaterialKind::Leather => Some(&self.leather),
+            MaterialKind::Metal => Some(&self.metal),
+            MaterialKind::Fur => self.fur.as_ref(),
+        }
+    }
+}
+
+impl Endpoint for Color {
+    const URL: &'static str = "/v2/colors";
+}
+
+impl BulkEndpoint for Color {
+    type Id = u32;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+/// The categories a dye is sorted into in the dye selection. The API lists them as strings,
+/// which are empty for colors that are not dyes.
+#[derive(Debug, Deserialize, PartialEq, Default)]
+#[serde(from = "Vec<Category>")]
+pub struct Categories {
+    pub hue: Option<Hue>,
+    pub material: Option<MaterialCategory>,
+    pub rarity: Option<RarityCategory>,
+}
+
+/// The color family of a dye.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Hue {
+    Gray,
+    Brown,
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Purple,
+}
+
+/// The finish of a dye.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MaterialCategory {
+    Vibrant,
+    Leather,
+    Metal,
+}
+
+/// How hard a dye is to come by.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RarityCategory {
+    Starter,
+    Common,
+    Uncommon,
+    Rare,
+    Exclusive,
+}
+
+/// A single category as listed by the API.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Category {
+    Hue(Hue),
+    Material(MaterialCategory),
+    Rarity(RarityCategory),
+    // Keeps categories added to the API later from failing the whole dye.
+    Other(IgnoredAny),
+}
+
+impl From<Vec<Category>> for Categories {
+    fn from(list: Vec<Category>) -> Self {
+        let mut categories = Categories::default();
+        for category in list {
+            match category {
+                Category::Hue(hue) => categories.hue = Some(hue),
+                Category::Material(material) => categories.material = Some(material),
+                Category::Rarity(rarity) => categories.rarity = Some(rarity),
+                Category::Other(_) => {}
+            }
+        }
+        categories
+    }
+}
+
+/// The dyes of a hue, split by whether an account has unlocked them, see
+/// `AccountDyes::by_hue`.
+#[derive(Debug, PartialEq, Default)]
+pub struct DyeUnlocks<'a> {
+    pub owned: Vec<&'a Color>,
+    pub missing: Vec<&'a Color>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::colors::*;
+
+    const JSON_COLOR: &str = r#"
+    {
+      "id": 10,
+      "name": "Sky",
+      "base_rgb": [128, 26, 26],
+      "cloth": {
+        "brightness": 22,
+        "contrast": 1.25,
+        "hue": 196,
+        "saturation": 0.742188,
+        "lightness": 1.32813,
+        "rgb": [54, 130, 160]
+      },
+      "leather": {
+        "brightness": 22,
+        "contrast": 1.25,
+        "hue": 196,
+        "saturation": 0.664063,
+        "lightness": 1.32813,
+        "rgb": [61, 129, 156]
+      },
+      "metal": {
+        "brightness": 22,
+        "contrast": 1.25,
+        "hue": 196,
+        "saturation": 0.546875,
+        "lightness": 1.32813,
+        "rgb": [65, 123, 146]
+      },
+      "fur": {
+        "brightness": 22,
+        "contrast": 1.25,
+        "hue": 196,
+        "saturation": 0.742188,
+        "lightness": 1.32813,
+        "rgb": [54, 130, 160]
+      },
+      "item": 20370,
+      "categories": ["Blue", "Vibrant", "Rare"]
+    }"#;
+
+    const JSON_DYE_REMOVER: &str = r#"
+    {
+      "id": 1,
+      "name": "Dye Remover",
+      "base_rgb": [128, 26, 26],
+      "cloth": {
+        "brightness": 15, "contrast": 1.25, "hue": 38, "saturation": 0.28125,
+        "lightness": 1.44531, "rgb": [124, 108, 83]
+      },
+      "leather": {
+        "brightness": -8, "contrast": 1, "hue": 34, "saturation": 0.3125,
+        "lightness": 1.09375, "rgb": [65, 49, 29]
+      },
+      "metal": {
+        "brightness": 5, "contrast": 1.05469, "hue": 38, "saturation": 0.101563,
+        "lightness": 1.36719, "rgb": [96, 91, 83]
+      },
+      "categories": []
+    }"#;
+
+    #[test]
+    fn create_color() {
+        let color = serde_json::from_str::<Color>(JSON_COLOR).unwrap();
+        assert_eq!(Some(Rgb::new(128, 26, 26)), color.base_rgb());
+        assert_eq!(Some(Hue::Blue), color.categories.hue);
+        assert_eq!(Some(MaterialCategory::Vibrant), color.categories.material);
+        assert_eq!(Some(RarityCategory::Rare), color.categories.rarity);
+        assert_eq!(vec![54, 130, 160], color.material(MaterialKind::Fur).unwrap().rgb);
+    }
+
+    #[test]
+    fn create_color_without_categories() {
+        let color = serde_json::from_str::<Color>(JSON_DYE_REMOVER).unwrap();
+        assert_eq!(None, color.item);
+        assert_eq!(None, color.material(MaterialKind::Fur));
+        assert_eq!(Categories::default(), color.categories);
+    }
+
+    #[test]
+    fn unknown_categories() {
+        let categories = serde_json::from_str::<Categories>(r#"["Pink", "Metal", "Starter"]"#)
+            .unwrap();
+        assert_eq!(None, categories.hue);
+        assert_eq!(Some(MaterialCategory::Metal), categories.material);
+        assert_eq!(Some(RarityCategory::Starter), categories.rarity);
+    }
+}
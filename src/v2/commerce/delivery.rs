@@ -0,0 +1,50 @@
+use serde::Deserialize;
+
+use crate::coins::Coins;
+use crate::endpoint::Endpoint;
+
+const ENDPOINT_URL: &str = "/v2/commerce/delivery";
+
+/// Coins and items waiting to be picked up from the trading post.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Delivery {
+    /// Coins from items that were sold.
+    pub coins: Coins,
+    /// Items that were bought.
+    pub items: Vec<DeliveryItem>,
+}
+
+/// A stack of bought items waiting to be picked up.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct DeliveryItem {
+    /// Item id, resolvable against `/v2/items`.
+    pub id: u32,
+    pub count: u32,
+}
+
+impl Endpoint for Delivery {
+    const URL: &'static str = ENDPOINT_URL;
+    const AUTHENTICATED: bool = true;
+}
+
+endpoints! {
+    impl Delivery {
+        /// Retrieve the coins and items waiting in the delivery box of the account.
+        pub fn get_delivery / async get_delivery_async(client) -> Delivery {
+            authenticated_request(ENDPOINT_URL)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::commerce::delivery::*;
+
+    #[test]
+    fn create_delivery() {
+        let json = r#"{ "coins": 12345, "items": [{ "id": 19684, "count": 250 }] }"#;
+        let delivery = serde_json::from_str::<Delivery>(json).unwrap();
+        assert_eq!("1g 23s 45c", delivery.coins.to_string());
+        assert_eq!(250, delivery.items[0].count);
+    }
+}
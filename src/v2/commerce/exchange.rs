@@ -0,0 +1,59 @@
+use serde::Deserialize;
+
+use crate::coins::Coins;
+
+const COINS_ENDPOINT_URL: &str = "/v2/commerce/exchange/coins";
+const GEMS_ENDPOINT_URL: &str = "/v2/commerce/exchange/gems";
+
+/// A quote for exchanging coins into gems.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct CoinsToGems {
+    /// Price of a single gem at this rate.
+    pub coins_per_gem: Coins,
+    /// Gems the exchanged coins buy.
+    pub quantity: u64,
+}
+
+/// A quote for exchanging gems into coins.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct GemsToCoins {
+    /// Price of a single gem at this rate.
+    pub coins_per_gem: Coins,
+    /// Coins the exchanged gems buy.
+    pub quantity: Coins,
+}
+
+endpoints! {
+    impl CoinsToGems {
+        /// Retrieve how many gems the given amount of coins would buy.
+        pub fn get_quote / async get_quote_async(client, coins: Coins) -> CoinsToGems {
+            request(format!("{}?quantity={}", COINS_ENDPOINT_URL, coins.copper()))
+        }
+    }
+}
+
+endpoints! {
+    impl GemsToCoins {
+        /// Retrieve how many coins the given amount of gems would buy.
+        pub fn get_quote / async get_quote_async(client, gems: u64) -> GemsToCoins {
+            request(format!("{}?quantity={}", GEMS_ENDPOINT_URL, gems))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::commerce::exchange::*;
+
+    #[test]
+    fn create_exchange_rates() {
+        let json = r#"{ "coins_per_gem": 2474, "quantity": 40 }"#;
+        let rate = serde_json::from_str::<CoinsToGems>(json).unwrap();
+        assert_eq!(Coins::from_parts(0, 24, 74), Some(rate.coins_per_gem));
+        assert_eq!(40, rate.quantity);
+
+        let json = r#"{ "coins_per_gem": 1858, "quantity": 185800 }"#;
+        let rate = serde_json::from_str::<GemsToCoins>(json).unwrap();
+        assert_eq!(Coins::from_parts(18, 58, 0), Some(rate.quantity));
+    }
+}
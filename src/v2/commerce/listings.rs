@@ -0,0 +1,102 @@
+use serde::Deserialize;
+
+use crate::coins::Coins;
+use crate::endpoint::{BulkEndpoint, Endpoint};
+
+/// The full order book of an item on the trading post.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Listing {
+    /// Item id, resolvable against `/v2/items`.
+    pub id: u32,
+    /// Buy orders, grouped by price from the highest to the lowest.
+    pub buys: Vec<ListingEntry>,
+    /// Sell offers, grouped by price from the lowest to the highest.
+    pub sells: Vec<ListingEntry>,
+}
+
+/// All orders of one side of the order book at a single price.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct ListingEntry {
+    /// Amount of individual orders at this price.
+    pub listings: u32,
+    /// Price per item.
+    pub unit_price: Coins,
+    /// Total amount of items at this price.
+    pub quantity: u32,
+}
+
+impl Listing {
+    /// The highest buy order, if there is any.
+    pub fn best_buy(&self) -> Option<&ListingEntry> {
+        self.buys.iter().max_by_key(|entry| entry.unit_price)
+    }
+
+    /// The lowest sell offer, if there is any.
+    pub fn best_sell(&self) -> Option<&ListingEntry> {
+        self.sells.iter().min_by_key(|entry| entry.unit_price)
+    }
+
+    /// Total price of buying `quantity` items from the cheapest sell offers, or `None` if not
+    /// enough items are offered.
+    pub fn cost_to_buy(&self, quantity: u32) -> Option<Coins> {
+        let mut sells: Vec<&ListingEntry> = self.sells.iter().collect();
+        sells.sort_by_key(|entry| entry.unit_price);
+
+        let mut remaining = quantity;
+        let mut cost = Coins::ZERO;
+        for entry in sells {
+            if remaining == 0 {
+                break;
+            }
+            let bought = remaining.min(entry.quantity);
+            cost = cost.checked_add(entry.unit_price.checked_mul(u64::from(bought))?)?;
+            remaining -= bought;
+        }
+        if remaining == 0 { Some(cost) } else { None }
+    }
+}
+
+impl Endpoint for Listing {
+    const URL: &'static str = "/v2/commerce/listings";
+}
+
+impl BulkEndpoint for Listing {
+    type Id = u32;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::commerce::listings::*;
+
+    const JSON_LISTING: &str = r#"
+    {
+      "id": 19684,
+      "buys": [
+        { "listings": 1, "unit_price": 7018, "quantity": 250 },
+        { "listings": 4, "unit_price": 7017, "quantity": 1000 }
+      ],
+      "sells": [
+        { "listings": 1, "unit_price": 7286, "quantity": 20 },
+        { "listings": 2, "unit_price": 7300, "quantity": 250 }
+      ]
+    }"#;
+
+    #[test]
+    fn create_listing() {
+        let listing = serde_json::from_str::<Listing>(JSON_LISTING).unwrap();
+        assert_eq!(Coins::from_copper(7018), listing.best_buy().unwrap().unit_price);
+        assert_eq!(Coins::from_copper(7286), listing.best_sell().unwrap().unit_price);
+    }
+
+    #[test]
+    fn cost_to_buy() {
+        let listing = serde_json::from_str::<Listing>(JSON_LISTING).unwrap();
+        assert_eq!(Some(Coins::from_copper(20 * 7286 + 10 * 7300)), listing.cost_to_buy(30));
+        assert_eq!(None, listing.cost_to_buy(271));
+        assert_eq!(Some(Coins::ZERO), listing.cost_to_buy(0));
+    }
+}
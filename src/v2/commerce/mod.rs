@@ -0,0 +1,5 @@
+pub mod delivery;
+pub mod exchange;
+pub mod listings;
+pub mod prices;
+pub mod transactions;
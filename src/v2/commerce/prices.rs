@@ -0,0 +1,67 @@
+use serde::Deserialize;
+
+use crate::coins::Coins;
+use crate::endpoint::{BulkEndpoint, Endpoint};
+
+/// The highest buy order and lowest sell offer of an item on the trading post.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Price {
+    /// Item id, resolvable against `/v2/items`.
+    pub id: u32,
+    /// Whether free to play accounts can buy or sell the item.
+    pub whitelisted: bool,
+    /// The highest buy order, along with the amount of items wanted at that price.
+    pub buys: PriceInfo,
+    /// The lowest sell offer, along with the amount of items offered at that price.
+    pub sells: PriceInfo,
+}
+
+/// Price and quantity of one side of the order book.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct PriceInfo {
+    /// Amount of items at the given price, 0 if there are no orders.
+    pub quantity: u32,
+    /// Price per item, 0 if there are no orders.
+    pub unit_price: Coins,
+}
+
+impl Price {
+    /// Difference between the lowest sell offer and the highest buy order in copper, before the
+    /// trading post takes its fees.
+    pub fn spread(&self) -> i64 {
+        self.sells.unit_price.copper() as i64 - self.buys.unit_price.copper() as i64
+    }
+}
+
+impl Endpoint for Price {
+    const URL: &'static str = "/v2/commerce/prices";
+}
+
+impl BulkEndpoint for Price {
+    type Id = u32;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::commerce::prices::*;
+
+    const JSON_PRICE: &str = r#"
+    {
+      "id": 19684,
+      "whitelisted": false,
+      "buys": { "quantity": 145975, "unit_price": 7018 },
+      "sells": { "quantity": 126, "unit_price": 7286 }
+    }"#;
+
+    #[test]
+    fn create_price() {
+        let price = serde_json::from_str::<Price>(JSON_PRICE).unwrap();
+        assert_eq!(Coins::from_copper(7018), price.buys.unit_price);
+        assert_eq!("72s 86c", price.sells.unit_price.to_string());
+        assert_eq!(268, price.spread());
+    }
+}
@@ -0,0 +1,110 @@
+use serde::Deserialize;
+
+use crate::client::Client;
+use crate::coins::Coins;
+#[cfg(feature = "async")]
+use crate::client::AsyncClient;
+use crate::error::ApiError;
+use crate::pagination::{Page, Pages};
+
+/// A buy order or sell offer of the account on the trading post.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Transaction {
+    /// Id of the transaction.
+    pub id: u64,
+    /// Item id, resolvable against `/v2/items`.
+    pub item_id: u32,
+    /// Price per item.
+    pub price: Coins,
+    /// Amount of items that were ordered or offered.
+    pub quantity: u32,
+    /// ISO-8601 timestamp of when the order or offer was placed.
+    pub created: String,
+    /// ISO-8601 timestamp of when the transaction completed, only set for past transactions.
+    pub purchased: Option<String>,
+}
+
+/// The four transaction lists of `/v2/commerce/transactions`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TransactionList {
+    /// Unfulfilled buy orders.
+    CurrentBuys,
+    /// Unfulfilled sell offers.
+    CurrentSells,
+    /// Buy orders fulfilled in the past 90 days.
+    HistoryBuys,
+    /// Sell offers fulfilled in the past 90 days.
+    HistorySells,
+}
+
+impl TransactionList {
+    /// Url of the list, without pagination parameters.
+    pub fn url(self) -> &'static str {
+        match self {
+            TransactionList::CurrentBuys => "/v2/commerce/transactions/current/buys",
+            TransactionList::CurrentSells => "/v2/commerce/transactions/current/sells",
+            TransactionList::HistoryBuys => "/v2/commerce/transactions/history/buys",
+            TransactionList::HistorySells => "/v2/commerce/transactions/history/sells",
+        }
+    }
+}
+
+impl Transaction {
+    /// Retrieve a single page of the given transaction list of the account.
+    pub fn get_transactions(client: &Client, list: TransactionList, page: usize, page_size: usize)
+        -> Result<Page<Transaction>, ApiError> {
+        client.authenticated_request_page(list.url(), page, page_size)
+    }
+
+    /// Returns an iterator over every page of the given transaction list of the account.
+    pub fn transactions<'c, 'a>(client: &'c Client<'a>, list: TransactionList, page_size: usize)
+        -> Pages<'c, 'a, Transaction> {
+        Pages::from_url(client, list.url(), true, page_size)
+    }
+
+    /// Asynchronous variant of [`get_transactions`](Self::get_transactions).
+    #[cfg(feature = "async")]
+    pub async fn get_transactions_async(client: &AsyncClient<'_>, list: TransactionList,
+        page: usize, page_size: usize) -> Result<Page<Transaction>, ApiError> {
+        client.authenticated_request_page(list.url(), page, page_size).await
+    }
+
+    /// Asynchronous variant of [`transactions`](Self::transactions), returning a stream of pages.
+    #[cfg(feature = "async")]
+    pub fn transactions_async<'c>(client: &'c AsyncClient<'_>, list: TransactionList,
+        page_size: usize)
+        -> impl futures_util::Stream<Item = Result<Page<Transaction>, ApiError>> + 'c {
+        client.pages_from_url(list.url(), true, page_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::commerce::transactions::*;
+
+    const JSON_TRANSACTIONS: &str = r#"
+    [
+      {
+        "id": 2256424470,
+        "item_id": 19684,
+        "price": 7018,
+        "quantity": 250,
+        "created": "2023-04-12T17:31:24+00:00",
+        "purchased": "2023-04-12T18:02:51+00:00"
+      },
+      {
+        "id": 2256441550,
+        "item_id": 24277,
+        "price": 5530,
+        "quantity": 1,
+        "created": "2023-04-12T18:15:02+00:00"
+      }
+    ]"#;
+
+    #[test]
+    fn create_transactions() {
+        let transactions = serde_json::from_str::<Vec<Transaction>>(JSON_TRANSACTIONS).unwrap();
+        assert!(transactions[0].purchased.is_some());
+        assert_eq!(None, transactions[1].purchased);
+    }
+}
@@ -0,0 +1,81 @@
+use serde::Deserialize;
+
+use crate::endpoint::{BulkEndpoint, Endpoint};
+
+/// A background of guild emblems, see `v1::guild_details::Emblem::background_id`.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct EmblemBackground {
+    /// id of the background.
+    pub id: u32,
+    /// Render service urls of the layers of the background. Backgrounds have a single layer,
+    /// tinted with the background color of the emblem.
+    pub layers: Vec<String>,
+}
+
+/// A foreground of guild emblems, see `v1::guild_details::Emblem::foreground_id`.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct EmblemForeground {
+    /// id of the foreground.
+    pub id: u32,
+    /// Render service urls of the layers of the foreground: an untinted preview of the whole
+    /// foreground, followed by the layers tinted with the primary and the secondary color.
+    pub layers: Vec<String>,
+}
+
+impl EmblemForeground {
+    /// The layer tinted with the primary color of the emblem.
+    pub fn primary_layer(&self) -> Option<&str> {
+        self.layers.get(1).map(String::as_str)
+    }
+
+    /// The layer tinted with the secondary color of the emblem.
+    pub fn secondary_layer(&self) -> Option<&str> {
+        self.layers.get(2).map(String::as_str)
+    }
+}
+
+impl Endpoint for EmblemBackground {
+    const URL: &'static str = "/v2/emblem/backgrounds";
+}
+
+impl BulkEndpoint for EmblemBackground {
+    type Id = u32;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+impl Endpoint for EmblemForeground {
+    const URL: &'static str = "/v2/emblem/foregrounds";
+}
+
+impl BulkEndpoint for EmblemForeground {
+    type Id = u32;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::emblem::*;
+
+    const JSON_FOREGROUND: &str = r#"
+    {
+      "id": 114,
+      "layers": [
+        "https://render.guildwars2.com/file/4D8D0BEE1A0E8B3F6A0EEF4B1B06C8C7EB1D0AE6/59575.png",
+        "https://render.guildwars2.com/file/0C2E1E0E6D3B1D1A8D3F4B2E6E4C2B6C8A2B7C01/59577.png",
+        "https://render.guildwars2.com/file/7B5F0F21A6C6A1F57D0E5D8E4A9A3E4C6E6D3F15/59579.png"
+      ]
+    }"#;
+
+    #[test]
+    fn create_foreground() {
+        let foreground = serde_json::from_str::<EmblemForeground>(JSON_FOREGROUND).unwrap();
+        assert!(foreground.primary_layer().unwrap().ends_with("/59577.png"));
+        assert!(foreground.secondary_layer().unwrap().ends_with("/59579.png"));
+    }
+}
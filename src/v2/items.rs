@@ -0,0 +1,768 @@
+use serde::de::{self, Deserializer};
+use serde::Deserialize;
+use serde_json::Value;
+
+use std::time::Duration;
+
+use crate::attributes::Attribute;
+use crate::coins::Coins;
+use crate::endpoint::{BulkEndpoint, Endpoint};
+use crate::utils::{Rarity, WeightClass};
+use crate::v1::quoted;
+
+/// An item, along with the details specific to its type.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Item {
+    /// id of the item.
+    pub id: u32,
+    /// Chat link to the item.
+    pub chat_link: String,
+    /// Name of the item.
+    pub name: String,
+    /// Url to the icon of the item.
+    pub icon: Option<String>,
+    /// Description of the item, which may contain markup.
+    pub description: Option<String>,
+    /// Rarity of the item.
+    pub rarity: Rarity,
+    /// Required level for the item.
+    pub level: u32,
+    /// Value when selling to a vendor.
+    pub vendor_value: Coins,
+    /// Skin id of the item, resolvable against `/v2/skins`.
+    pub default_skin: Option<u32>,
+    /// Additional item flags.
+    pub flags: Vec<ItemFlag>,
+    /// Game types where the item is usable.
+    pub game_types: Vec<GameType>,
+    /// Races and professions that can use the item, empty if there are no restrictions.
+    pub restrictions: Vec<String>,
+    /// Items this item can be upgraded into, e.g. through the Mystic Forge.
+    #[serde(default)]
+    pub upgrades_into: Vec<ItemUpgrade>,
+    /// Items this item can be upgraded from.
+    #[serde(default)]
+    pub upgrades_from: Vec<ItemUpgrade>,
+    /// Type of the item, along with the details specific to that type.
+    #[serde(flatten)]
+    pub details: ItemDetails,
+}
+
+/// Flags describing how an item can be used and traded.
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum ItemFlag {
+    AccountBindOnUse,
+    AccountBound,
+    Attuned,
+    BulkConsume,
+    DeleteWarning,
+    HideSuffix,
+    Infused,
+    MonsterOnly,
+    NoMysticForge,
+    NoSalvage,
+    NoSell,
+    NotUpgradeable,
+    NoUnderwater,
+    SoulbindOnAcquire,
+    SoulBindOnUse,
+    Tonic,
+    Unique,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Game modes an item can be used in.
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum GameType {
+    Activity,
+    Dungeon,
+    Pve,
+    Pvp,
+    PvpLobby,
+    Wvw,
+    #[serde(other)]
+    Unknown,
+}
+
+/// A link to an item that this item upgrades into or from.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct ItemUpgrade {
+    /// How the item is upgraded, e.g. `Attunement` or `Infusion`.
+    pub upgrade: String,
+    /// Id of the other item.
+    pub item_id: u32,
+}
+
+/// The details of an item, tagged on the `type` of the item. Types without any details, as well
+/// as types this crate does not know about yet, are unit variants.
+#[derive(Debug, PartialEq)]
+pub enum ItemDetails {
+    Armor(ArmorDetails),
+    Back(BackDetails),
+    Bag(BagDetails),
+    Consumable(ConsumableDetails),
+    Container(ContainerDetails),
+    CraftingMaterial,
+    Gathering(GatheringDetails),
+    Gizmo(GizmoDetails),
+    JadeTechModule,
+    Key,
+    MiniPet(MiniPetDetails),
+    PowerCore,
+    Relic,
+    Tool(ToolDetails),
+    Trait,
+    Trinket(TrinketDetails),
+    Trophy,
+    UpgradeComponent(UpgradeComponentDetails),
+    Weapon(WeaponDetails),
+    Unknown,
+}
+
+/// Reads the `type` and `details` fields of an item. Written out by hand rather than derived as
+/// an adjacently tagged enum, so that unknown types are accepted whether they have details or not.
+impl<'de> Deserialize<'de> for ItemDetails {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<ItemDetails, D::Error> {
+        #[derive(Deserialize)]
+        struct Tagged {
+            #[serde(rename = "type")]
+            item_type: String,
+            #[serde(default)]
+            details: Value,
+        }
+
+        fn parse<'de, T: Deserialize<'de>, E: de::Error>(details: Value) -> Result<T, E> {
+            T::deserialize(details).map_err(E::custom)
+        }
+
+        let tagged = Tagged::deserialize(deserializer)?;
+        let details = tagged.details;
+        Ok(match tagged.item_type.as_str() {
+            "Armor" => ItemDetails::Armor(parse(details)?),
+            "Back" => ItemDetails::Back(parse(details)?),
+            "Bag" => ItemDetails::Bag(parse(details)?),
+            "Consumable" => ItemDetails::Consumable(parse(details)?),
+            "Container" => ItemDetails::Container(parse(details)?),
+            "CraftingMaterial" => ItemDetails::CraftingMaterial,
+            "Gathering" => ItemDetails::Gathering(parse(details)?),
+            "Gizmo" => ItemDetails::Gizmo(parse(details)?),
+            "JadeTechModule" => ItemDetails::JadeTechModule,
+            "Key" => ItemDetails::Key,
+            "MiniPet" => ItemDetails::MiniPet(parse(details)?),
+            "PowerCore" => ItemDetails::PowerCore,
+            "Relic" => ItemDetails::Relic,
+            "Tool" => ItemDetails::Tool(parse(details)?),
+            "Trait" => ItemDetails::Trait,
+            "Trinket" => ItemDetails::Trinket(parse(details)?),
+            "Trophy" => ItemDetails::Trophy,
+            "UpgradeComponent" => ItemDetails::UpgradeComponent(parse(details)?),
+            "Weapon" => ItemDetails::Weapon(parse(details)?),
+            _ => ItemDetails::Unknown,
+        })
+    }
+}
+
+impl ItemDetails {
+    /// The stats of the item, if they are fixed.
+    pub fn infix_upgrade(&self) -> Option<&InfixUpgrade> {
+        match self {
+            ItemDetails::Armor(details) => details.infix_upgrade.as_ref(),
+            ItemDetails::Back(details) => details.infix_upgrade.as_ref(),
+            ItemDetails::Trinket(details) => details.infix_upgrade.as_ref(),
+            ItemDetails::UpgradeComponent(details) => details.infix_upgrade.as_ref(),
+            ItemDetails::Weapon(details) => details.infix_upgrade.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Ids of the stat combinations that can be chosen for the item, resolvable against
+    /// `/v2/itemstats`. Empty if the stats are fixed or the item has none.
+    pub fn stat_choices(&self) -> &[u32] {
+        match self {
+            ItemDetails::Armor(details) => &details.stat_choices,
+            ItemDetails::Back(details) => &details.stat_choices,
+            ItemDetails::Trinket(details) => &details.stat_choices,
+            ItemDetails::Weapon(details) => &details.stat_choices,
+            _ => &[],
+        }
+    }
+
+    /// Scales the multipliers of `/v2/itemstats` to the attribute values on this item, 0 for
+    /// items without stats.
+    pub fn attribute_adjustment(&self) -> f64 {
+        match self {
+            ItemDetails::Armor(details) => details.attribute_adjustment,
+            ItemDetails::Back(details) => details.attribute_adjustment,
+            ItemDetails::Trinket(details) => details.attribute_adjustment,
+            ItemDetails::Weapon(details) => details.attribute_adjustment,
+            _ => 0.0,
+        }
+    }
+}
+
+/// Pieces of armor.
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum ArmorSlot {
+    Boots,
+    Coat,
+    Gloves,
+    Helm,
+    HelmAquatic,
+    Leggings,
+    Shoulders,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Details of a piece of armor.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct ArmorDetails {
+    /// Which piece of armor this is.
+    #[serde(rename = "type")]
+    pub slot: ArmorSlot,
+    /// Weight class of the armor.
+    pub weight_class: WeightClass,
+    /// Defense value of the armor.
+    pub defense: u32,
+    /// Infusion slots of the armor.
+    pub infusion_slots: Vec<InfusionSlot>,
+    /// Scales the multipliers of `/v2/itemstats` to the attribute values of this armor.
+    pub attribute_adjustment: f64,
+    /// Fixed stats of the armor.
+    pub infix_upgrade: Option<InfixUpgrade>,
+    /// Item id of the rune in the armor.
+    pub suffix_item_id: Option<u32>,
+    /// Ids of the stat combinations that can be chosen for the armor.
+    #[serde(default)]
+    pub stat_choices: Vec<u32>,
+}
+
+/// Details of a back item.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct BackDetails {
+    /// Infusion slots of the back item.
+    pub infusion_slots: Vec<InfusionSlot>,
+    /// Scales the multipliers of `/v2/itemstats` to the attribute values of this back item.
+    pub attribute_adjustment: f64,
+    /// Fixed stats of the back item.
+    pub infix_upgrade: Option<InfixUpgrade>,
+    /// Item id of the jewel in the back item.
+    pub suffix_item_id: Option<u32>,
+    /// Ids of the stat combinations that can be chosen for the back item.
+    #[serde(default)]
+    pub stat_choices: Vec<u32>,
+}
+
+/// Details of a bag.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct BagDetails {
+    /// Amount of slots in the bag.
+    pub size: u32,
+    /// Whether items in the bag are hidden from vendors and the sort button.
+    pub no_sell_or_sort: bool,
+}
+
+/// Types of consumables.
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum ConsumableType {
+    AppearanceChange,
+    Booze,
+    ContractNpc,
+    Currency,
+    Food,
+    Generic,
+    Halloween,
+    Immediate,
+    MountRandomUnlock,
+    RandomUnlock,
+    Transmutation,
+    Unlock,
+    UpgradeRemoval,
+    Utility,
+    TeleportToFriend,
+    #[serde(other)]
+    Unknown,
+}
+
+/// What a consumable of type `Unlock` unlocks.
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum UnlockType {
+    BagSlot,
+    BankTab,
+    BuildLibrarySlot,
+    BuildLoadoutTab,
+    Champion,
+    CollectibleCapacity,
+    Content,
+    CraftingRecipe,
+    Dye,
+    GearLoadoutTab,
+    GliderSkin,
+    JadeBotSkin,
+    Minipet,
+    Ms,
+    Outfit,
+    RandomUnlock,
+    SharedSlot,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Details of a consumable.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct ConsumableDetails {
+    /// Type of the consumable.
+    #[serde(rename = "type")]
+    pub consumable_type: ConsumableType,
+    /// Description of the effect of the consumable.
+    pub description: Option<String>,
+    /// Duration of the effect, sent in milliseconds.
+    #[serde(default, deserialize_with = "quoted::option_millis")]
+    pub duration_ms: Option<Duration>,
+    /// What the consumable unlocks, for consumables of type `Unlock`.
+    pub unlock_type: Option<UnlockType>,
+    /// Id of the dye the consumable unlocks, resolvable against `/v2/colors`.
+    pub color_id: Option<u32>,
+    /// Id of the recipe the consumable unlocks, resolvable against `/v2/recipes`.
+    pub recipe_id: Option<u32>,
+    /// Ids of additional recipes the consumable unlocks.
+    #[serde(default)]
+    pub extra_recipe_ids: Vec<u32>,
+    /// Id of the guild upgrade the consumable unlocks.
+    pub guild_upgrade_id: Option<u32>,
+    /// Amount of stacks of the effect applied by the consumable.
+    pub apply_count: Option<u32>,
+    /// Name of the effect of the consumable.
+    pub name: Option<String>,
+    /// Url to the icon of the effect.
+    pub icon: Option<String>,
+    /// Ids of the skins the consumable unlocks, resolvable against `/v2/skins`.
+    #[serde(default)]
+    pub skins: Vec<u32>,
+}
+
+/// Types of containers.
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum ContainerType {
+    Default,
+    GiftBox,
+    Immediate,
+    OpenUI,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Details of a container.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct ContainerDetails {
+    /// Type of the container.
+    #[serde(rename = "type")]
+    pub container_type: ContainerType,
+}
+
+/// Types of gathering tools.
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum GatheringType {
+    Bait,
+    Foraging,
+    Logging,
+    Lure,
+    Mining,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Details of a gathering tool.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct GatheringDetails {
+    /// Type of the gathering tool.
+    #[serde(rename = "type")]
+    pub gathering_type: GatheringType,
+}
+
+/// Types of gizmos.
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum GizmoType {
+    Default,
+    ContainerKey,
+    RentableContractNpc,
+    UnlimitedConsumable,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Details of a gizmo.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct GizmoDetails {
+    /// Type of the gizmo.
+    #[serde(rename = "type")]
+    pub gizmo_type: GizmoType,
+    /// Id of the guild upgrade the gizmo unlocks.
+    pub guild_upgrade_id: Option<u32>,
+    /// Ids of the vendors the gizmo opens.
+    #[serde(default)]
+    pub vendor_ids: Vec<u32>,
+}
+
+/// Details of a miniature.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct MiniPetDetails {
+    /// Id of the miniature, resolvable against `/v2/minis`.
+    pub minipet_id: u32,
+}
+
+/// Types of tools.
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum ToolType {
+    Salvage,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Details of a tool.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct ToolDetails {
+    /// Type of the tool.
+    #[serde(rename = "type")]
+    pub tool_type: ToolType,
+    /// Amount of uses of the tool.
+    pub charges: u32,
+}
+
+/// Types of trinkets.
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum TrinketType {
+    Accessory,
+    Amulet,
+    Ring,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Details of a trinket.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct TrinketDetails {
+    /// Type of the trinket.
+    #[serde(rename = "type")]
+    pub trinket_type: TrinketType,
+    /// Infusion slots of the trinket.
+    pub infusion_slots: Vec<InfusionSlot>,
+    /// Scales the multipliers of `/v2/itemstats` to the attribute values of this trinket.
+    pub attribute_adjustment: f64,
+    /// Fixed stats of the trinket.
+    pub infix_upgrade: Option<InfixUpgrade>,
+    /// Item id of the jewel in the trinket.
+    pub suffix_item_id: Option<u32>,
+    /// Ids of the stat combinations that can be chosen for the trinket.
+    #[serde(default)]
+    pub stat_choices: Vec<u32>,
+}
+
+/// Types of upgrade components.
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum UpgradeType {
+    /// Jewels, infusions, and PvP sigils & runes.
+    Default,
+    /// Universal upgrades and gemstones.
+    Gem,
+    Rune,
+    Sigil,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Details of an upgrade component.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct UpgradeComponentDetails {
+    /// Type of the upgrade component.
+    #[serde(rename = "type")]
+    pub upgrade_type: UpgradeType,
+    /// Item types the upgrade component can be applied to, e.g. `HeavyArmor` or `Sword`.
+    pub flags: Vec<String>,
+    /// Infusion slots the upgrade component can be applied to.
+    pub infusion_upgrade_flags: Vec<InfusionFlag>,
+    /// Suffix added to the name of the item it is applied to.
+    pub suffix: Option<String>,
+    /// Stats the upgrade component gives.
+    pub infix_upgrade: Option<InfixUpgrade>,
+    /// Descriptions of the bonuses of a rune for every amount of runes applied.
+    #[serde(default)]
+    pub bonuses: Vec<String>,
+}
+
+/// Types of weapons.
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum WeaponType {
+    Axe,
+    Dagger,
+    Focus,
+    Greatsword,
+    Hammer,
+    Harpoon,
+    LargeBundle,
+    LongBow,
+    Mace,
+    Pistol,
+    Rifle,
+    Scepter,
+    Shield,
+    ShortBow,
+    SmallBundle,
+    Speargun,
+    Staff,
+    Sword,
+    Torch,
+    Toy,
+    ToyTwoHanded,
+    Trident,
+    Warhorn,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Damage types of weapons.
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum DamageType {
+    Choking,
+    Fire,
+    Ice,
+    Lightning,
+    Physical,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Details of a weapon.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct WeaponDetails {
+    /// Type of the weapon.
+    #[serde(rename = "type")]
+    pub weapon_type: WeaponType,
+    /// Damage type of the weapon.
+    pub damage_type: DamageType,
+    /// Minimum weapon strength.
+    pub min_power: u32,
+    /// Maximum weapon strength.
+    pub max_power: u32,
+    /// Defense value of the weapon, only non-zero for shields.
+    pub defense: u32,
+    /// Infusion slots of the weapon.
+    pub infusion_slots: Vec<InfusionSlot>,
+    /// Scales the multipliers of `/v2/itemstats` to the attribute values of this weapon.
+    pub attribute_adjustment: f64,
+    /// Fixed stats of the weapon.
+    pub infix_upgrade: Option<InfixUpgrade>,
+    /// Item id of the sigil in the weapon.
+    pub suffix_item_id: Option<u32>,
+    /// Ids of the stat combinations that can be chosen for the weapon.
+    #[serde(default)]
+    pub stat_choices: Vec<u32>,
+}
+
+/// Kinds of infusion slots and infusions.
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum InfusionFlag {
+    Enrichment,
+    Infusion,
+    Defense,
+    Offense,
+    Utility,
+    Agony,
+    #[serde(other)]
+    Unknown,
+}
+
+/// An infusion slot of an item.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct InfusionSlot {
+    /// Which infusions fit into the slot.
+    pub flags: Vec<InfusionFlag>,
+    /// Item id of the infusion in the slot.
+    pub item_id: Option<u32>,
+}
+
+/// The fixed stats of an item.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct InfixUpgrade {
+    /// Id of the stat combination, resolvable against `/v2/itemstats`.
+    pub id: u32,
+    /// The attribute bonuses of the item.
+    pub attributes: Vec<InfixAttribute>,
+    /// An additional effect of the item.
+    pub buff: Option<Buff>,
+}
+
+/// A single attribute bonus of an item.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct InfixAttribute {
+    pub attribute: Attribute,
+    pub modifier: i32,
+}
+
+/// An additional effect of an item, e.g. bonus boon duration.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Buff {
+    /// Id of the skill applying the effect, resolvable against `/v2/skills`.
+    pub skill_id: u32,
+    /// Description of the effect.
+    pub description: Option<String>,
+}
+
+impl Endpoint for Item {
+    const URL: &'static str = "/v2/items";
+}
+
+impl BulkEndpoint for Item {
+    type Id = u32;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::items::*;
+    use crate::chatlink::{ChatLink, ItemLink};
+
+    const JSON_WEAPON: &str = r#"
+    {
+      "name": "Zojja's Claymore",
+      "type": "Weapon",
+      "level": 80,
+      "rarity": "Ascended",
+      "vendor_value": 330,
+      "default_skin": 4678,
+      "game_types": ["Activity", "Wvw", "Dungeon", "Pve"],
+      "flags": ["HideSuffix", "AccountBound", "NotUpgradeable", "AccountBindOnUse"],
+      "restrictions": [],
+      "id": 46762,
+      "chat_link": "[&AgGqtgAA]",
+      "icon": "https://render.guildwars2.com/file/B4C4F8C7B7D5D3E9B8F2C2F9A2E0D4E2BA8A6D47/434452.png",
+      "details": {
+        "type": "Greatsword",
+        "damage_type": "Physical",
+        "min_power": 1045,
+        "max_power": 1155,
+        "defense": 0,
+        "infusion_slots": [{ "flags": ["Infusion"] }, { "flags": ["Infusion"] }],
+        "attribute_adjustment": 717.024,
+        "infix_upgrade": {
+          "id": 1130,
+          "attributes": [
+            { "attribute": "Power", "modifier": 251 },
+            { "attribute": "Precision", "modifier": 179 },
+            { "attribute": "CritDamage", "modifier": 179 }
+          ]
+        },
+        "secondary_suffix_item_id": "",
+        "stat_choices": [161, 155, 159, 157, 158, 160, 153, 605]
+      }
+    }"#;
+
+    const JSON_CRAFTING_MATERIAL: &str = r#"
+    {
+      "name": "Glob of Ectoplasm",
+      "description": "Salvaged from rare, exotic, and ascended items.",
+      "type": "CraftingMaterial",
+      "level": 0,
+      "rarity": "Exotic",
+      "vendor_value": 64,
+      "game_types": ["Activity", "Wvw", "Dungeon", "Pve"],
+      "flags": [],
+      "restrictions": [],
+      "id": 19721,
+      "chat_link": "[&AgHJTQAA]",
+      "icon": "https://render.guildwars2.com/file/18CE5D78317265000CF3C23ED76AB3CEE86BA60E/65941.png"
+    }"#;
+
+    const JSON_BAG: &str = r#"
+    {
+      "name": "20 Slot Invisible Bag",
+      "type": "Bag",
+      "level": 0,
+      "rarity": "Exotic",
+      "vendor_value": 330,
+      "game_types": ["Activity", "Wvw", "Dungeon", "Pve"],
+      "flags": ["NoSell"],
+      "restrictions": [],
+      "id": 9588,
+      "chat_link": "[&AgF0JQAA]",
+      "details": { "size": 20, "no_sell_or_sort": true }
+    }"#;
+
+    const JSON_FOOD: &str = r#"
+    {
+      "name": "Bowl of Sweet and Spicy Butternut Squash Soup",
+      "type": "Consumable",
+      "level": 80,
+      "rarity": "Fine",
+      "vendor_value": 33,
+      "game_types": ["Activity", "Wvw", "Dungeon", "Pve"],
+      "flags": [],
+      "restrictions": [],
+      "id": 41569,
+      "chat_link": "[&AgFhogAA]",
+      "details": {
+        "type": "Food",
+        "description": "+66 Power\n+10% Experience from Kills",
+        "duration_ms": 1800000,
+        "apply_count": 1,
+        "name": "Nourishment"
+      }
+    }"#;
+
+    #[test]
+    fn create_weapon() {
+        let item = serde_json::from_str::<Item>(JSON_WEAPON).unwrap();
+        let weapon = match &item.details {
+            ItemDetails::Weapon(weapon) => weapon,
+            details => panic!("expected a weapon, got {:?}", details),
+        };
+        assert_eq!(WeaponType::Greatsword, weapon.weapon_type);
+        assert_eq!(1130, item.details.infix_upgrade().unwrap().id);
+        assert_eq!(8, item.details.stat_choices().len());
+        assert_eq!(717.024, item.details.attribute_adjustment());
+
+        let chat_link = ChatLink::Item(ItemLink::new(item.id));
+        assert_eq!(chat_link.to_string(), item.chat_link);
+    }
+
+    #[test]
+    fn create_consumable() {
+        let item = serde_json::from_str::<Item>(JSON_FOOD).unwrap();
+        match item.details {
+            ItemDetails::Consumable(food) => {
+                assert_eq!(Some(Duration::from_secs(30 * 60)), food.duration_ms);
+            }
+            details => panic!("expected a consumable, got {:?}", details),
+        }
+    }
+
+    #[test]
+    fn create_item_without_details() {
+        let item = serde_json::from_str::<Item>(JSON_CRAFTING_MATERIAL).unwrap();
+        assert_eq!(ItemDetails::CraftingMaterial, item.details);
+        assert!(item.details.stat_choices().is_empty());
+        assert_eq!(None, item.details.infix_upgrade());
+    }
+
+    #[test]
+    fn create_bag() {
+        let item = serde_json::from_str::<Item>(JSON_BAG).unwrap();
+        assert_eq!(ItemDetails::Bag(BagDetails { size: 20, no_sell_or_sort: true }), item.details);
+        assert_eq!(None, item.icon);
+    }
+
+    #[test]
+    fn unknown_item_type() {
+        let json = JSON_CRAFTING_MATERIAL.replace("CraftingMaterial", "Mystery");
+        let item = serde_json::from_str::<Item>(&json).unwrap();
+        assert_eq!(ItemDetails::Unknown, item.details);
+
+        let json = JSON_BAG.replace(r#""type": "Bag""#, r#""type": "Mystery""#);
+        let item = serde_json::from_str::<Item>(&json).unwrap();
+        assert_eq!(ItemDetails::Unknown, item.details);
+    }
+}
@@ -0,0 +1,85 @@
+use serde::Deserialize;
+
+use crate::attributes::Attribute;
+use crate::endpoint::{BulkEndpoint, Endpoint};
+
+/// A stat combination of items, such as Berserker's, which the `infix_upgrade` and
+/// `stat_choices` of `/v2/items` refer to.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct ItemStat {
+    /// id of the stat combination.
+    pub id: u32,
+    /// Name of the stat combination, as used in the prefix of item names. Can be empty.
+    pub name: String,
+    /// The attributes of the stat combination.
+    pub attributes: Vec<ItemStatAttribute>,
+}
+
+/// How a single attribute of a stat combination scales with the item it is applied to.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct ItemStatAttribute {
+    pub attribute: Attribute,
+    /// Multiplied with the `attribute_adjustment` of an item.
+    pub multiplier: f64,
+    /// Flat bonus added on top.
+    pub value: i32,
+}
+
+impl ItemStat {
+    /// The attribute bonuses this stat combination gives on an item with the given
+    /// `attribute_adjustment`, e.g. `ItemDetails::attribute_adjustment`.
+    pub fn attributes_for(&self, attribute_adjustment: f64) -> Vec<(Attribute, i32)> {
+        self.attributes
+            .iter()
+            .map(|stat| {
+                let modifier = (stat.multiplier * attribute_adjustment).round() as i32;
+                (stat.attribute, modifier + stat.value)
+            })
+            .collect()
+    }
+}
+
+impl Endpoint for ItemStat {
+    const URL: &'static str = "/v2/itemstats";
+}
+
+impl BulkEndpoint for ItemStat {
+    type Id = u32;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::itemstats::*;
+
+    const JSON_BERSERKER: &str = r#"
+    {
+      "id": 161,
+      "name": "Berserker's",
+      "attributes": [
+        { "attribute": "Power", "multiplier": 0.35, "value": 0 },
+        { "attribute": "Precision", "multiplier": 0.25, "value": 0 },
+        { "attribute": "CritDamage", "multiplier": 0.25, "value": 0 }
+      ]
+    }"#;
+
+    #[test]
+    fn create_itemstat() {
+        let stat = serde_json::from_str::<ItemStat>(JSON_BERSERKER).unwrap();
+        assert_eq!("Berserker's", stat.name);
+    }
+
+    #[test]
+    fn attributes_for_ascended_greatsword() {
+        let stat = serde_json::from_str::<ItemStat>(JSON_BERSERKER).unwrap();
+        let expected = vec![
+            (Attribute::Power, 251),
+            (Attribute::Precision, 179),
+            (Attribute::CritDamage, 179),
+        ];
+        assert_eq!(expected, stat.attributes_for(717.024));
+    }
+}
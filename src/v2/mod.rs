@@ -1,3 +1,12 @@
+pub mod account;
 pub mod build;
+pub mod characters;
+pub mod colors;
+pub mod commerce;
+pub mod emblem;
+pub mod items;
+pub mod itemstats;
 pub mod pvp;
+pub mod recipes;
 pub mod tokeninfo;
+pub mod wvw;
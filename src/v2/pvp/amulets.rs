@@ -3,11 +3,7 @@ use serde::Deserialize;
 use std::collections::HashMap;
 
 use crate::attributes::Attribute;
-use crate::client::Client;
-use crate::error::ApiError;
-use crate::utils::ids_to_string;
-
-const ENDPOINT_URL: &str = "/v2/pvp/amulets";
+use crate::endpoint::{BulkEndpoint, Endpoint};
 
 /// Returns information about the PvP amulets.
 #[derive(Debug, Deserialize, PartialEq)]
@@ -24,38 +20,21 @@ pub struct Amulet {
     pub attributes: HashMap<Attribute, i32>,
 }
 
-impl Amulet {
-    /// Retrieve an amulet by its id.
-    pub fn get_id(client: &Client, id: u32) -> Result<Amulet, ApiError> {
-        let url = format!("{}?id={}", ENDPOINT_URL, id);
-        client.request(&url)
-    }
-
-    /// Retrieve all ids for the available PvP amulets, returning a `Vec` of ids.
-    pub fn get_all_ids(client: &Client) -> Result<Vec<u32>, ApiError> {
-        client.request(ENDPOINT_URL)
-    }
+impl Endpoint for Amulet {
+    const URL: &'static str = "/v2/pvp/amulets";
+}
 
-    /// Retrieve multiple amulets by their ids, if any of the ids do not exist it will not be in the
-    /// returned `Vec`, if all the ids are invalid the `Vec` will be empty.
-    pub fn get_amulets_by_ids(client: &Client, ids: Vec<u32>) -> Result<Vec<Amulet>, ApiError> {
-        let url = format!("{}?ids={}", ENDPOINT_URL, ids_to_string(ids));
-        client.request(&url)
-    }
+impl BulkEndpoint for Amulet {
+    type Id = u32;
 
-    /// Retrieve all available amulets, returning a `Vec` of `Amulet` objects.
-    pub fn get_all_amulets(client: &Client) -> Result<Vec<Amulet>, ApiError> {
-        let url = format!("{}?ids=all", ENDPOINT_URL);
-        client.request(&url)
+    fn id(&self) -> &Self::Id {
+        &self.id
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
-
     use crate::v2::pvp::amulets::*;
-    use crate::attributes::Attribute;
     use crate::client::Client;
 
     const JSON_AMULET: &str = r#"
@@ -80,23 +59,21 @@ mod tests {
         let client = Client::new();
         // Arbitrary number that the official number of amulets should exceed
         let num_amulets = 12;
-        assert!(Amulet::get_all_ids(&client).unwrap().len() >= num_amulets);
+        assert!(client.get_ids::<Amulet>().unwrap().len() >= num_amulets);
     }
 
     #[test]
     fn get_amulet_by_id() {
         let client = Client::new();
         let amulet = serde_json::from_str::<Amulet>(JSON_AMULET).unwrap();
-        assert_eq!(amulet, Amulet::get_id(&client, amulet.id).unwrap());
+        assert_eq!(amulet, client.get::<Amulet>(&amulet.id).unwrap());
     }
 
     #[test]
     fn get_ids() {
         let client = Client::new();
-        let mut ids: Vec<u32> = Vec::new();
-        ids.push(1);
-        ids.push(90909); // does not exist
-        let amulets = Amulet::get_amulets_by_ids(&client, ids).unwrap();
+        let ids: Vec<u32> = vec![1, 90909]; // 90909 does not exist
+        let amulets = client.get_many::<Amulet>(&ids).unwrap();
         assert!(amulets.len() == 1);
     }
 
@@ -105,7 +82,7 @@ mod tests {
         let client = Client::new();
         // Arbitrary number that the official number of amulets should exceed
         let num_amulets = 12;
-        let amulets = Amulet::get_all_amulets(&client).unwrap();
+        let amulets = client.get_all::<Amulet>().unwrap();
         assert!(amulets.len() >= num_amulets);
     }
 }
@@ -2,11 +2,8 @@ use serde::Deserialize;
 
 use std::collections::HashMap;
 
-use crate::client::Client;
-use crate::error::ApiError;
-use crate::utils::{ids_to_string, Profession, Team};
-
-const ENDPOINT_URL: &str = "/v2/pvp/games";
+use crate::endpoint::{BulkEndpoint, Endpoint};
+use crate::utils::{Profession, Team};
 
 /// A structured PvP game.
 #[derive(Debug, Deserialize, PartialEq)]
@@ -46,38 +43,25 @@ pub enum RatingType {
     Custom,
 }
 
-impl Game {
-    /// Retrieve a game by its id.
-    pub fn get_id(client: &Client, id: String) -> Result<Game, ApiError> {
-        let url = format!("{}?id={}", ENDPOINT_URL, id);
-        client.authenticated_request(&url)
-    }
-
-    /// Retrieve ids of all recently played games.
-    pub fn get_all_ids(client: &Client) -> Result<Vec<String>, ApiError> {
-        client.authenticated_request(ENDPOINT_URL)
-    }
+impl Endpoint for Game {
+    const URL: &'static str = "/v2/pvp/games";
+    const AUTHENTICATED: bool = true;
+}
 
-    /// Retrieve all games that have been played, capped at 10 most recent games.
-    pub fn get_all_games(client: &Client) -> Result<Vec<Game>, ApiError> {
-        let url = format!("{}?ids=all", ENDPOINT_URL);
-        client.authenticated_request(&url)
-    }
+impl BulkEndpoint for Game {
+    type Id = String;
 
-    /// Retrive games by their ids.
-    pub fn get_games_by_ids(client: &Client, ids: Vec<String>) -> Result<Vec<Game>, ApiError> {
-        let url = format!("{}?ids={}", ENDPOINT_URL, ids_to_string(ids));
-        client.authenticated_request(&url)
+    fn id(&self) -> &Self::Id {
+        &self.id
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::v2::pvp::games::{Game, Team, Profession, RatingType};
+    use crate::v2::pvp::games::Game;
     use crate::client::Client;
-    use crate::error::ApiError;
+    use crate::error::ApiErrorKind;
     use std::env;
-    use std::collections::HashMap;
 
     const JSON_GAME: &str = r#"{
             "id": "ABCDE02B-8888-FEBA-1234-DE98765C7DEF",
@@ -101,12 +85,11 @@ mod tests {
         serde_json::from_str::<Game>(JSON_GAME).unwrap();
     }
 
-
     #[test]
     fn get_all_games() {
         let api_key = env::var("GW2_TEST_KEY").expect("GW2_TEST_KEY environment variable is not set.");
         let client = Client::new().set_api_key(api_key);
-        Game::get_all_games(&client).unwrap();
+        client.get_all::<Game>().unwrap();
     }
 
     // Since, the most recent PvP games are both dependant on the key and who played them, testing
@@ -117,17 +100,19 @@ mod tests {
         let api_key = env::var("GW2_TEST_KEY").expect("GW2_TEST_KEY environment variable is not set.");
         let client = Client::new().set_api_key(api_key);
         let id = "1".to_string();
-        // TODO:
-        //assert_eq!(Err(ApiError::new("{\"text\":\"no such id\"}".to_string())), Game::get_id(&client, id.clone()));
+        let err = client.get::<Game>(&id).unwrap_err();
+        assert!(matches!(err.kind(), ApiErrorKind::NotFound(_)));
+        assert_eq!(Some("no such id"), err.text());
     }
 
     #[test]
     fn get_invalid_games_by_ids() {
         let api_key = env::var("GW2_TEST_KEY").expect("GW2_TEST_KEY environment variable is not set.");
-        let ids = vec!("1".to_string(), "2".to_string());
+        let ids = ["1".to_string(), "2".to_string()];
         let client = Client::new().set_api_key(api_key);
-        // TODO:
-        //assert_eq!(Err(ApiError::new("{\"text\":\"all ids provided are invalid\"}".to_string())), Game::get_games_by_ids(&client, ids));
+        // The API responds with "all ids provided are invalid", which is reported as missing ids.
+        let result = client.get_many_with_missing::<Game>(&ids).unwrap();
+        assert!(result.found.is_empty());
+        assert_eq!(ids.to_vec(), result.missing);
     }
-
 }
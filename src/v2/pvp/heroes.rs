@@ -1,10 +1,6 @@
 use serde::Deserialize;
 
-use crate::client::Client;
-use crate::error::ApiError;
-use crate::utils::ids_to_string;
-
-const ENDPOINT_URL: &str = "/v2/pvp/heroes";
+use crate::endpoint::{BulkEndpoint, Endpoint};
 
 /// A hero used in the Stronghold game structured PvP game type.
 #[derive(Debug, Deserialize, PartialEq)]
@@ -55,28 +51,15 @@ pub struct Skin {
     pub unlock_items: Vec<u32>,
 }
 
-impl Hero {
-    /// Retrieve a hero by its id.
-    pub fn get_id(client: &Client, id: String) -> Result<Hero, ApiError> {
-        let url = format!("{}?id={}", ENDPOINT_URL, id);
-        client.request(&url)
-    }
-
-    /// Retrieve the ids for all available heroes.
-    pub fn get_all_ids(client: &Client) -> Result<Vec<String>, ApiError> {
-        client.request(ENDPOINT_URL)
-    }
+impl Endpoint for Hero {
+    const URL: &'static str = "/v2/pvp/heroes";
+}
 
-    /// Retrieve all heroes that are available.
-    pub fn get_all_heroes(client: &Client) -> Result<Vec<Hero>, ApiError> {
-        let url = format!("{}?ids=all", ENDPOINT_URL);
-        client.request(&url)
-    }
+impl BulkEndpoint for Hero {
+    type Id = String;
 
-    /// Retrive heroes by their ids.
-    pub fn get_heroes_by_ids(client: &Client, ids: Vec<String>) -> Result<Vec<Hero>, ApiError> {
-        let url = format!("{}?ids={}", ENDPOINT_URL, ids_to_string(ids));
-        client.request(&url)
+    fn id(&self) -> &Self::Id {
+        &self.id
     }
 }
 
@@ -188,7 +171,7 @@ mod tests {
     fn get_id() {
         let client = Client::new();
         let hero = serde_json::from_str::<Hero>(JSON_HERO).unwrap();
-        assert_eq!(hero, Hero::get_id(&client, hero.id.to_string()).unwrap());
+        assert_eq!(hero, client.get::<Hero>(&hero.id).unwrap());
     }
 
     #[test]
@@ -200,19 +183,19 @@ mod tests {
             "BEA79596-CA8B-4D46-9B9C-EA1B606BCF42",
             "CF977AE5-C605-4586-A802-3E25F0F35772",
         );
-        assert_eq!(ids, Hero::get_all_ids(&client).unwrap());
+        assert_eq!(ids, client.get_ids::<Hero>().unwrap());
     }
 
     #[test]
     fn get_all_heroes() {
         let client = Client::new();
-        let ids = vec!(
+        let ids = [
             "115C140F-C2F5-40EB-8EA2-C3773F2AE468",
             "B7EA9889-5F16-4636-9705-4FCAF8B39ECD",
             "BEA79596-CA8B-4D46-9B9C-EA1B606BCF42",
             "CF977AE5-C605-4586-A802-3E25F0F35772",
-        );
-        assert!(Hero::get_all_heroes(&client).unwrap().len() == ids.len());
+        ];
+        assert!(client.get_all::<Hero>().unwrap().len() == ids.len());
     }
 
     #[test]
@@ -222,7 +205,7 @@ mod tests {
             "115C140F-C2F5-40EB-8EA2-C3773F2AE468".to_string(),
             "B7EA9889-5F16-4636-9705-4FCAF8B39ECD".to_string(),
         );
-        assert!(Hero::get_heroes_by_ids(&client, ids.clone()).unwrap().len() == ids.len());
+        assert!(client.get_many::<Hero>(&ids).unwrap().len() == ids.len());
     }
 }
 
@@ -1,10 +1,6 @@
 use serde::Deserialize;
 
-use crate::client::Client;
-use crate::error::ApiError;
-use crate::utils::ids_to_string;
-
-const ENDPOINT_URL: &str = "/v2/pvp/ranks";
+use crate::endpoint::{BulkEndpoint, Endpoint};
 
 /// Information about a structured PvP rank.
 #[derive(Debug, Deserialize, PartialEq)]
@@ -24,9 +20,7 @@ pub struct Rank {
     pub max_rank: u32,
     /// Span of levels which the rank covers, also contains the PvP experience points needed to go
     /// from the minimum rank to the maximum rank.
-    /// TODO: Make this not be an unnecessary Vec.
-    #[serde(flatten)]
-    pub levels: Levels,
+    pub levels: Vec<Levels>,
 }
 
 /// Contains the span of PvP levels a certain rank covers, as well as the required amount of PvP
@@ -34,35 +28,22 @@ pub struct Rank {
 #[derive(Debug, Deserialize, PartialEq)]
 pub struct Levels {
     /// The minimum PvP level required to be this rank.
-    min_rank: u32,
+    pub min_rank: u32,
     /// The maximum PvP level required to be this rank.
-    max_rank: u32,
+    pub max_rank: u32,
     /// Points needed to go from the given minimum rank to maximum rank.
-    points: u32,
+    pub points: u32,
 }
 
-impl Rank {
-    /// Retrieve all available rank ids.
-    pub fn get_all_ids(client: &Client) -> Result<u32, ApiError> {
-        client.request(ENDPOINT_URL)
-    }
-
-    /// Retrieve a certain rank's information by its id.
-    pub fn get_id(client: &Client, id: u32) -> Result<Rank, ApiError> {
-        let url = format!("{}?ids={}", ENDPOINT_URL, id);
-        client.request(&url)
-    }
+impl Endpoint for Rank {
+    const URL: &'static str = "/v2/pvp/ranks";
+}
 
-    /// Retrieve all ranks.
-    pub fn get_all_ranks(client: &Client) -> Result<Vec<Rank>, ApiError> {
-        let url = format!("{}?ids=all", ENDPOINT_URL);
-        client.request(&url)
-    }
+impl BulkEndpoint for Rank {
+    type Id = u32;
 
-    /// Retrive ranks' information by their ids.
-    pub fn get_ranks_by_ids(client: &Client, ids: Vec<String>) -> Result<Vec<Rank>, ApiError> {
-        let url = format!("{}?ids={}", ENDPOINT_URL, ids_to_string(ids));
-        client.request(&url)
+    fn id(&self) -> &Self::Id {
+        &self.id
     }
 }
 
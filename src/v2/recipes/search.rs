@@ -0,0 +1,36 @@
+use serde::Deserialize;
+
+const ENDPOINT_URL: &str = "/v2/recipes/search";
+
+/// Ids of the recipes matching a search, resolvable against `/v2/recipes` and
+/// `/v1/recipe_details`.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct RecipeSearch {
+    pub ids: Vec<u32>,
+}
+
+endpoints! {
+    impl RecipeSearch {
+        /// Find the recipes that use the item with the given id as an ingredient.
+        pub fn by_input / async by_input_async(client, item_id: u32) -> RecipeSearch {
+            request(format!("{}?input={}", ENDPOINT_URL, item_id))
+        }
+
+        /// Find the recipes that produce the item with the given id.
+        pub fn by_output / async by_output_async(client, item_id: u32) -> RecipeSearch {
+            request(format!("{}?output={}", ENDPOINT_URL, item_id))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::recipes::search::*;
+
+    #[test]
+    fn create_recipe_search() {
+        let search = serde_json::from_str::<RecipeSearch>("[7314, 7359]").unwrap();
+        assert_eq!(vec![7314, 7359], search.ids);
+    }
+}
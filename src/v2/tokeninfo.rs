@@ -1,7 +1,8 @@
 use serde::{Deserialize, Deserializer};
 
-use crate::client::Client;
-use crate::error::ApiError;
+use crate::endpoint::Endpoint;
+
+const ENDPOINT_URL: &str = "/v2/tokeninfo";
 
 /// Information about a supplied API key.
 #[derive(Debug, Deserialize, PartialEq)]
@@ -13,13 +14,37 @@ pub struct TokenInfo {
     pub name: String,
     /// Permissions that the API key has.
     pub permissions: Permissions,
+    /// Whether the token is an API key or a subtoken created from one.
+    #[serde(rename = "type")]
+    pub token_type: TokenType,
+    /// Timestamp of when the subtoken expires, only set for subtokens.
+    pub expires_at: Option<String>,
+    /// Timestamp of when the subtoken was created, only set for subtokens.
+    pub issued_at: Option<String>,
+    /// Endpoints the subtoken is limited to, only set for subtokens that were created with urls.
+    pub urls: Option<Vec<String>>,
+}
+
+/// Kinds of tokens the API accepts, available since schema version `2019-05-22T00:00:00.000Z`.
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum TokenType {
+    #[serde(rename = "APIKey")]
+    ApiKey,
+    Subtoken,
+}
+
+impl Endpoint for TokenInfo {
+    const URL: &'static str = ENDPOINT_URL;
+    const AUTHENTICATED: bool = true;
 }
 
-impl TokenInfo {
-    /// Returns a `TokenInfo` struct containing the id given, the key's name and what permissions are
-    /// set for the `Client`'s key.
-    pub fn get_tokeninfo(client: &Client) -> Result<TokenInfo, ApiError> {
-        client.authenticated_request("/v2/tokeninfo")
+endpoints! {
+    impl TokenInfo {
+        /// Returns a `TokenInfo` struct containing the id given, the key's name and what permissions
+        /// are set for the `Client`'s key.
+        pub fn get_tokeninfo / async get_tokeninfo_async(client) -> TokenInfo {
+            authenticated_request(ENDPOINT_URL)
+        }
     }
 }
 
@@ -80,9 +105,26 @@ impl<'de> Deserialize<'de> for Permissions {
 #[cfg(test)]
 mod tests {
     use crate::client::Client;
-    use crate::v2::tokeninfo::{TokenInfo, Permissions};
+    use crate::v2::tokeninfo::{TokenInfo, TokenType, Permissions};
     use std::env;
 
+    const JSON_SUBTOKEN: &str = r#"{
+        "id": "ABCDEFGH-1324-5678-9012-IJKLMNOPQRSTUVXYZABC-1234-5678-9012-ABCDEFGHIJKL",
+        "name": "Subtoken",
+        "permissions": ["account", "characters"],
+        "type": "Subtoken",
+        "expires_at": "2022-04-01T00:00:00.000Z",
+        "issued_at": "2022-03-01T00:00:00.000Z",
+        "urls": ["/v2/characters/Hello%20World"]
+    }"#;
+
+    #[test]
+    fn create_subtoken() {
+        let ti = serde_json::from_str::<TokenInfo>(JSON_SUBTOKEN).unwrap();
+        assert_eq!(TokenType::Subtoken, ti.token_type);
+        assert_eq!(Some(vec!["/v2/characters/Hello%20World".to_string()]), ti.urls);
+    }
+
     #[test]
     fn get_tokeninfo() {
         let api_key = env::var("GW2_TEST_KEY").expect("GW2_TEST_KEY environment variable is not set.");
@@ -0,0 +1,79 @@
+use serde::Deserialize;
+
+use crate::endpoint::{BulkEndpoint, Endpoint};
+
+/// A WvW ability, trained with WvW ability points.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Ability {
+    /// id of the ability.
+    pub id: u32,
+    /// Name of the ability.
+    pub name: String,
+    /// Description of the ability.
+    pub description: String,
+    /// Icon url for the ability.
+    #[serde(rename = "icon")]
+    pub icon_url: String,
+    /// The ranks of the ability, from the first to the last.
+    pub ranks: Vec<AbilityRank>,
+}
+
+/// A rank of a WvW ability.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct AbilityRank {
+    /// Ability points needed to train the rank.
+    pub cost: u32,
+    /// Description of what the rank does.
+    pub effect: String,
+}
+
+impl Ability {
+    /// Ability points needed to train the ability up to and including the given rank, starting
+    /// at 1. Rank 0 costs nothing, ranks past the last one of the ability give `None`.
+    pub fn cost_to_rank(&self, rank: usize) -> Option<u32> {
+        let ranks = self.ranks.get(..rank)?;
+        Some(ranks.iter().map(|rank| rank.cost).sum())
+    }
+}
+
+impl Endpoint for Ability {
+    const URL: &'static str = "/v2/wvw/abilities";
+}
+
+impl BulkEndpoint for Ability {
+    type Id = u32;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::wvw::abilities::*;
+
+    const JSON_ABILITY: &str = r#"
+    {
+      "id": 2,
+      "name": "Guard Killer",
+      "description": "Increases damage to guards.",
+      "icon": "https://render.guildwars2.com/file/985E5F6E1C8DD4CE1B9EBC81F5B0B3A3CE6A3D35/1029960.png",
+      "ranks": [
+        { "cost": 1, "effect": "Increases damage to guards by 4%." },
+        { "cost": 2, "effect": "Increases damage to guards by 8%." },
+        { "cost": 3, "effect": "Increases damage to guards by 12%." },
+        { "cost": 4, "effect": "Increases damage to guards by 16%." },
+        { "cost": 5, "effect": "Increases damage to guards by 20%." }
+      ]
+    }"#;
+
+    #[test]
+    fn create_ability() {
+        let ability = serde_json::from_str::<Ability>(JSON_ABILITY).unwrap();
+        assert_eq!(5, ability.ranks.len());
+        assert_eq!(Some(0), ability.cost_to_rank(0));
+        assert_eq!(Some(6), ability.cost_to_rank(3));
+        assert_eq!(Some(15), ability.cost_to_rank(5));
+        assert_eq!(None, ability.cost_to_rank(6));
+    }
+}
@@ -0,0 +1,349 @@
+use serde::Deserialize;
+
+use std::collections::HashMap;
+
+use crate::endpoint::{BulkEndpoint, Endpoint};
+use crate::utils::Team;
+use crate::v2::wvw::objectives::{MapType, ObjectiveType};
+
+const ENDPOINT_URL: &str = "/v2/wvw/matches";
+const STATS_ENDPOINT_URL: &str = "/v2/wvw/matches/stats";
+
+/// A WvW match between three teams of worlds.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Match {
+    /// id of the match, made up of the region and the tier, e.g. `1-1`.
+    pub id: String,
+    /// Timestamp of when the match started.
+    pub start_time: String,
+    /// Timestamp of when the match ends.
+    pub end_time: String,
+    /// Total scores of the teams.
+    pub scores: HashMap<Team, u32>,
+    /// The main world of each team.
+    pub worlds: HashMap<Team, u32>,
+    /// All of the worlds of each team, including the linked ones.
+    pub all_worlds: HashMap<Team, Vec<u32>>,
+    /// Total deaths of the teams.
+    pub deaths: HashMap<Team, u32>,
+    /// Total kills of the teams.
+    pub kills: HashMap<Team, u32>,
+    /// Victory points of the teams, which decide the outcome of the match.
+    pub victory_points: HashMap<Team, u32>,
+    /// The skirmishes of the match so far, from the first to the current one.
+    pub skirmishes: Vec<Skirmish>,
+    /// The maps of the match.
+    pub maps: Vec<Map>,
+}
+
+/// A two hour round of a match, whose scores award the victory points.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Skirmish {
+    /// id of the skirmish, counting up from 1.
+    pub id: u32,
+    /// Scores of the teams during the skirmish.
+    pub scores: HashMap<Team, u32>,
+    /// Scores of the teams on each of the maps during the skirmish.
+    pub map_scores: Vec<MapScores>,
+}
+
+/// Scores of the teams on a map.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct MapScores {
+    /// Which of the WvW maps the scores are for.
+    #[serde(rename = "type")]
+    pub map_type: MapType,
+    /// Scores of the teams on the map.
+    pub scores: HashMap<Team, u32>,
+}
+
+/// The state of a map of a match.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Map {
+    /// id of the map.
+    pub id: u32,
+    /// Which of the WvW maps it is.
+    #[serde(rename = "type")]
+    pub map_type: MapType,
+    /// Scores of the teams on the map.
+    pub scores: HashMap<Team, u32>,
+    /// Deaths of the teams on the map.
+    pub deaths: HashMap<Team, u32>,
+    /// Kills of the teams on the map.
+    pub kills: HashMap<Team, u32>,
+    /// Bonuses granted by the map to the team owning them.
+    #[serde(default)]
+    pub bonuses: Vec<Bonus>,
+    /// The objectives on the map.
+    pub objectives: Vec<MatchObjective>,
+}
+
+/// Describes a bonus given by a map and its current owner.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Bonus {
+    /// Shorthand name for the bonus, e.g. `Bloodlust`.
+    #[serde(rename = "type")]
+    pub bonus_type: String,
+    /// Current owner of the bonus.
+    pub owner: Team,
+}
+
+/// The state of an objective during a match.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct MatchObjective {
+    /// id of the objective, resolvable against `/v2/wvw/objectives`.
+    pub id: String,
+    /// Type of the objective.
+    #[serde(rename = "type")]
+    pub objective_type: ObjectiveType,
+    /// Current owner of the objective.
+    pub owner: Team,
+    /// Timestamp of when the objective last changed owner.
+    pub last_flipped: String,
+    /// id of the guild claiming the objective, if it is claimed.
+    pub claimed_by: Option<String>,
+    /// Timestamp of when the objective was claimed, if it is claimed.
+    pub claimed_at: Option<String>,
+    /// Points the objective awards its owner every tick.
+    pub points_tick: u32,
+    /// Points awarded for capturing the objective.
+    pub points_capture: u32,
+    /// Dolyaks delivered to the objective since it was captured, see
+    /// `v2::wvw::upgrades::Upgrade::tiers_reached`.
+    #[serde(default)]
+    pub yaks_delivered: u32,
+    /// ids of the guild upgrades slotted into the objective, resolvable against
+    /// `/v2/guild/upgrades`.
+    #[serde(default)]
+    pub guild_upgrades: Vec<u32>,
+}
+
+impl Match {
+    /// The team the world plays for in the match, including linked worlds.
+    pub fn team_of(&self, world_id: u32) -> Option<Team> {
+        self.all_worlds.iter()
+            .find(|(_, worlds)| worlds.contains(&world_id))
+            .map(|(team, _)| *team)
+    }
+
+    /// The current state of the objective, e.g. `38-6`.
+    pub fn objective(&self, id: &str) -> Option<&MatchObjective> {
+        self.maps.iter().flat_map(|map| &map.objectives).find(|objective| objective.id == id)
+    }
+
+    /// The map of the given type.
+    pub fn map(&self, map_type: MapType) -> Option<&Map> {
+        self.maps.iter().find(|map| map.map_type == map_type)
+    }
+}
+
+impl Endpoint for Match {
+    const URL: &'static str = ENDPOINT_URL;
+}
+
+impl BulkEndpoint for Match {
+    type Id = String;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+endpoints! {
+    impl Match {
+        /// Retrieve the match the world is currently playing in.
+        pub fn get_by_world / async get_by_world_async(client, world_id: u32) -> Match {
+            request(format!("{}?world={}", ENDPOINT_URL, world_id))
+        }
+    }
+}
+
+/// Kills and deaths of the teams of a match.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct MatchStats {
+    /// id of the match.
+    pub id: String,
+    /// Total deaths of the teams.
+    pub deaths: HashMap<Team, u32>,
+    /// Total kills of the teams.
+    pub kills: HashMap<Team, u32>,
+    /// Kills and deaths of the teams on each of the maps.
+    pub maps: Vec<MapStats>,
+}
+
+/// Kills and deaths of the teams on a map.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct MapStats {
+    /// id of the map.
+    pub id: u32,
+    /// Which of the WvW maps it is.
+    #[serde(rename = "type")]
+    pub map_type: MapType,
+    /// Deaths of the teams on the map.
+    pub deaths: HashMap<Team, u32>,
+    /// Kills of the teams on the map.
+    pub kills: HashMap<Team, u32>,
+}
+
+impl MatchStats {
+    /// Kills per death of the team, or `None` if it has not died yet.
+    pub fn kill_death_ratio(&self, team: Team) -> Option<f64> {
+        let kills = *self.kills.get(&team)?;
+        match self.deaths.get(&team) {
+            Some(&deaths) if deaths > 0 => Some(f64::from(kills) / f64::from(deaths)),
+            _ => None,
+        }
+    }
+}
+
+impl Endpoint for MatchStats {
+    const URL: &'static str = STATS_ENDPOINT_URL;
+}
+
+impl BulkEndpoint for MatchStats {
+    type Id = String;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+endpoints! {
+    impl MatchStats {
+        /// Retrieve the stats of the match the world is currently playing in.
+        pub fn get_by_world / async get_by_world_async(client, world_id: u32) -> MatchStats {
+            request(format!("{}?world={}", STATS_ENDPOINT_URL, world_id))
+        }
+
+        /// Retrieve the teams of a match that have stats, e.g. for `1-1`.
+        pub fn get_teams / async get_teams_async(client, match_id: &str) -> Vec<Team> {
+            request(format!("{}/{}/teams", STATS_ENDPOINT_URL, match_id))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::wvw::matches::*;
+
+    const JSON_MATCH: &str = r#"
+    {
+      "id": "1-1",
+      "start_time": "2024-05-03T18:00:00Z",
+      "end_time": "2024-05-10T18:00:00Z",
+      "scores": { "red": 12345, "blue": 23456, "green": 34567 },
+      "worlds": { "red": 1008, "blue": 1019, "green": 1017 },
+      "all_worlds": { "red": [1008, 1003], "blue": [1019, 1005], "green": [1017] },
+      "deaths": { "red": 800, "blue": 900, "green": 1000 },
+      "kills": { "red": 1000, "blue": 850, "green": 950 },
+      "victory_points": { "red": 30, "blue": 35, "green": 40 },
+      "skirmishes": [
+        {
+          "id": 1,
+          "scores": { "red": 150, "blue": 200, "green": 250 },
+          "map_scores": [
+            { "type": "Center", "scores": { "red": 50, "blue": 60, "green": 70 } },
+            { "type": "RedHome", "scores": { "red": 100, "blue": 140, "green": 180 } }
+          ]
+        }
+      ],
+      "maps": [
+        {
+          "id": 38,
+          "type": "Center",
+          "scores": { "red": 5000, "blue": 6000, "green": 7000 },
+          "bonuses": [],
+          "objectives": [
+            {
+              "id": "38-6",
+              "type": "Camp",
+              "owner": "Green",
+              "last_flipped": "2024-05-04T12:30:00Z",
+              "claimed_by": "4BBB52AA-D768-4FC6-8EDE-C299F2822F0F",
+              "claimed_at": "2024-05-04T12:31:00Z",
+              "points_tick": 2,
+              "points_capture": 2,
+              "guild_upgrades": [178],
+              "yaks_delivered": 24
+            },
+            {
+              "id": "38-15",
+              "type": "Spawn",
+              "owner": "Red",
+              "last_flipped": "2024-05-03T18:00:00Z",
+              "claimed_by": null,
+              "claimed_at": null,
+              "points_tick": 0,
+              "points_capture": 0
+            }
+          ],
+          "deaths": { "red": 200, "blue": 300, "green": 400 },
+          "kills": { "red": 350, "blue": 250, "green": 300 }
+        },
+        {
+          "id": 1099,
+          "type": "RedHome",
+          "scores": { "red": 7345, "blue": 17456, "green": 27567 },
+          "bonuses": [{ "type": "Bloodlust", "owner": "Blue" }],
+          "objectives": [],
+          "deaths": { "red": 600, "blue": 600, "green": 600 },
+          "kills": { "red": 650, "blue": 600, "green": 650 }
+        }
+      ]
+    }"#;
+
+    const JSON_STATS: &str = r#"
+    {
+      "id": "1-1",
+      "deaths": { "red": 800, "blue": 900, "green": 0 },
+      "kills": { "red": 1000, "blue": 450, "green": 950 },
+      "maps": [
+        {
+          "id": 38,
+          "type": "Center",
+          "deaths": { "red": 200, "blue": 300, "green": 0 },
+          "kills": { "red": 350, "blue": 250, "green": 300 }
+        }
+      ]
+    }"#;
+
+    #[test]
+    fn create_match() {
+        let wvw_match = serde_json::from_str::<Match>(JSON_MATCH).unwrap();
+        assert_eq!(Some(&40), wvw_match.victory_points.get(&Team::Green));
+        assert_eq!(MapType::RedHome, wvw_match.skirmishes[0].map_scores[1].map_type);
+        assert_eq!(Team::Blue, wvw_match.map(MapType::RedHome).unwrap().bonuses[0].owner);
+    }
+
+    #[test]
+    fn match_objectives() {
+        let wvw_match = serde_json::from_str::<Match>(JSON_MATCH).unwrap();
+        let camp = wvw_match.objective("38-6").unwrap();
+        assert_eq!(Team::Green, camp.owner);
+        assert_eq!(24, camp.yaks_delivered);
+        assert_eq!(Some("4BBB52AA-D768-4FC6-8EDE-C299F2822F0F"), camp.claimed_by.as_deref());
+
+        let spawn = wvw_match.objective("38-15").unwrap();
+        assert_eq!(ObjectiveType::Spawn, spawn.objective_type);
+        assert_eq!(None, spawn.claimed_by);
+        assert!(spawn.guild_upgrades.is_empty());
+        assert_eq!(None, wvw_match.objective("38-99"));
+    }
+
+    #[test]
+    fn team_of_world() {
+        let wvw_match = serde_json::from_str::<Match>(JSON_MATCH).unwrap();
+        assert_eq!(Some(Team::Red), wvw_match.team_of(1003));
+        assert_eq!(Some(Team::Green), wvw_match.team_of(1017));
+        assert_eq!(None, wvw_match.team_of(2001));
+    }
+
+    #[test]
+    fn kill_death_ratio() {
+        let stats = serde_json::from_str::<MatchStats>(JSON_STATS).unwrap();
+        assert_eq!(Some(1.25), stats.kill_death_ratio(Team::Red));
+        assert_eq!(Some(0.5), stats.kill_death_ratio(Team::Blue));
+        assert_eq!(None, stats.kill_death_ratio(Team::Green));
+        assert_eq!(MapType::Center, stats.maps[0].map_type);
+    }
+}
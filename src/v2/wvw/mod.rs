@@ -0,0 +1,5 @@
+pub mod abilities;
+pub mod matches;
+pub mod objectives;
+pub mod ranks;
+pub mod upgrades;
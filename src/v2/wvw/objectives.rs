@@ -0,0 +1,123 @@
+use serde::Deserialize;
+
+use crate::endpoint::{BulkEndpoint, Endpoint};
+use crate::geometry::{Point2, Point3};
+
+/// A WvW objective, such as a camp, tower or keep.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Objective {
+    /// id of the objective, made up of the map id and the objective's id on that map, e.g.
+    /// `38-6`.
+    pub id: String,
+    /// Name of the objective.
+    pub name: String,
+    /// Type of the objective.
+    #[serde(rename = "type")]
+    pub objective_type: ObjectiveType,
+    /// id of the map sector the objective is in, see `v1::map_floor::Sector`.
+    pub sector_id: u32,
+    /// id of the map the objective is on.
+    pub map_id: u32,
+    /// Which of the WvW maps the objective is on.
+    pub map_type: MapType,
+    /// Position of the objective in continent coordinates.
+    pub coord: Option<Point3>,
+    /// Position of the objective's label in continent coordinates.
+    pub label_coord: Option<Point2>,
+    /// Url of the objective's icon on the map.
+    pub marker: Option<String>,
+    /// Chat link to the objective.
+    pub chat_link: String,
+    /// id of the objective's upgrades, resolvable against `/v2/wvw/upgrades`.
+    pub upgrade_id: Option<u32>,
+}
+
+/// Possible types of WvW objectives.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ObjectiveType {
+    Camp,
+    Castle,
+    Keep,
+    Mercenary,
+    Resource,
+    Ruins,
+    Spawn,
+    Tower,
+    Generic,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Possible WvW maps.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MapType {
+    /// Borderlands
+    RedHome,
+    GreenHome,
+    BlueHome,
+    /// Eternal Battlegrounds
+    Center,
+    /// Edge of the Mists
+    EdgeOfTheMists,
+    #[serde(other)]
+    Unknown,
+}
+
+impl Endpoint for Objective {
+    const URL: &'static str = "/v2/wvw/objectives";
+}
+
+impl BulkEndpoint for Objective {
+    type Id = String;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::wvw::objectives::*;
+
+    const JSON_OBJECTIVE: &str = r#"
+    {
+      "id": "38-6",
+      "name": "Speldan Clearcut",
+      "sector_id": 834,
+      "type": "Camp",
+      "map_type": "Center",
+      "map_id": 38,
+      "upgrade_id": 7,
+      "coord": [9426.04, 14375.9, -74.6898],
+      "label_coord": [9410.14, 14339.3],
+      "marker": "https://render.guildwars2.com/file/015D365A08AAE105287A100AAE04529FDAE14155/102532.png",
+      "chat_link": "[&DAYAAAAmAAAA]"
+    }"#;
+
+    #[test]
+    fn create_objective() {
+        let objective = serde_json::from_str::<Objective>(JSON_OBJECTIVE).unwrap();
+        assert_eq!(ObjectiveType::Camp, objective.objective_type);
+        assert_eq!(MapType::Center, objective.map_type);
+        assert_eq!(Some(Point2::new(9426.04, 14375.9)), objective.coord.map(|coord| coord.xy()));
+        assert_eq!(Some(7), objective.upgrade_id);
+    }
+
+    #[test]
+    fn unknown_map_type() {
+        let json = JSON_OBJECTIVE.replace(r#""map_type": "Center""#, r#""map_type": "NewMap""#);
+        let objective = serde_json::from_str::<Objective>(&json).unwrap();
+        assert_eq!(MapType::Unknown, objective.map_type);
+    }
+
+    #[test]
+    fn objective_chat_link() {
+        use crate::chatlink::ChatLink;
+
+        let objective = serde_json::from_str::<Objective>(JSON_OBJECTIVE).unwrap();
+        match objective.chat_link.parse::<ChatLink>().unwrap() {
+            ChatLink::WvwObjective(link) => assert_eq!(objective.id, link.id()),
+            link => panic!("unexpected chat link {:?}", link),
+        }
+    }
+}
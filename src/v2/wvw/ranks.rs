@@ -0,0 +1,44 @@
+use serde::Deserialize;
+
+use crate::endpoint::{BulkEndpoint, Endpoint};
+
+/// A WvW rank, which gives an account a title from a certain WvW level on.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Rank {
+    /// id of the WvW rank.
+    pub id: u32,
+    /// Title of the rank, e.g. `Invader`.
+    pub title: String,
+    /// The minimum WvW level required to be this rank.
+    pub min_rank: u32,
+}
+
+impl Endpoint for Rank {
+    const URL: &'static str = "/v2/wvw/ranks";
+}
+
+impl BulkEndpoint for Rank {
+    type Id = u32;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::wvw::ranks::Rank;
+
+    #[test]
+    fn create_rank() {
+        let json_rank = r#"
+        {
+            "id": 2,
+            "title": "Invader",
+            "min_rank": 5
+        }"#;
+
+        let rank = serde_json::from_str::<Rank>(json_rank).unwrap();
+        assert_eq!(5, rank.min_rank);
+    }
+}
@@ -0,0 +1,112 @@
+use serde::Deserialize;
+
+use crate::endpoint::{BulkEndpoint, Endpoint};
+
+/// The upgrades an objective receives as dolyaks deliver supplies to it, see
+/// `v2::wvw::objectives::Objective::upgrade_id`.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Upgrade {
+    /// id of the upgrade.
+    pub id: u32,
+    /// The tiers of the upgrade, from the first to the last.
+    pub tiers: Vec<UpgradeTier>,
+}
+
+/// A tier of an objective's upgrades, unlocked once enough dolyaks have been delivered.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct UpgradeTier {
+    /// Name of the tier, e.g. `Secured`.
+    pub name: String,
+    /// Dolyaks that have to be delivered to the objective to unlock the tier.
+    pub yaks_required: u32,
+    /// The upgrades unlocked by the tier.
+    pub upgrades: Vec<UpgradeEffect>,
+}
+
+/// A single upgrade of a tier.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct UpgradeEffect {
+    /// Name of the upgrade.
+    pub name: String,
+    /// Description of the upgrade.
+    pub description: String,
+    /// Icon url for the upgrade.
+    #[serde(rename = "icon")]
+    pub icon_url: String,
+}
+
+impl Upgrade {
+    /// The tiers unlocked by the given number of delivered dolyaks, e.g. an objective's
+    /// `yaks_delivered` in `v2::wvw::matches::MatchObjective`.
+    pub fn tiers_reached(&self, yaks_delivered: u32) -> &[UpgradeTier] {
+        let reached = self.tiers.iter().take_while(|tier| tier.yaks_required <= yaks_delivered);
+        &self.tiers[..reached.count()]
+    }
+
+    /// The next tier to unlock, if there is one left.
+    pub fn next_tier(&self, yaks_delivered: u32) -> Option<&UpgradeTier> {
+        self.tiers.get(self.tiers_reached(yaks_delivered).len())
+    }
+}
+
+impl Endpoint for Upgrade {
+    const URL: &'static str = "/v2/wvw/upgrades";
+}
+
+impl BulkEndpoint for Upgrade {
+    type Id = u32;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::wvw::upgrades::*;
+
+    const JSON_UPGRADE: &str = r#"
+    {
+      "id": 1,
+      "tiers": [
+        {
+          "name": "Secured",
+          "yaks_required": 20,
+          "upgrades": [
+            {
+              "name": "Reinforced Walls",
+              "description": "Walls and gates are stronger.",
+              "icon": "https://render.guildwars2.com/file/4EE1C0DF6C37D2C8E6C4B4B8C04EC3C5D0F6E8AE/1358383.png"
+            }
+          ]
+        },
+        {
+          "name": "Reinforced",
+          "yaks_required": 60,
+          "upgrades": [
+            {
+              "name": "Fortified Walls",
+              "description": "Walls and gates are even stronger.",
+              "icon": "https://render.guildwars2.com/file/8A5E8BBE2D3C4A1F31DBE1A59E4B5C6D8F7E1A2B/1358384.png"
+            }
+          ]
+        },
+        {
+          "name": "Fortified",
+          "yaks_required": 140,
+          "upgrades": []
+        }
+      ]
+    }"#;
+
+    #[test]
+    fn upgrade_tiers() {
+        let upgrade = serde_json::from_str::<Upgrade>(JSON_UPGRADE).unwrap();
+        assert!(upgrade.tiers_reached(19).is_empty());
+        assert_eq!("Secured", upgrade.next_tier(19).unwrap().name);
+        assert_eq!(2, upgrade.tiers_reached(60).len());
+        assert_eq!("Fortified", upgrade.next_tier(60).unwrap().name);
+        assert_eq!(3, upgrade.tiers_reached(500).len());
+        assert_eq!(None, upgrade.next_tier(500));
+    }
+}
@@ -0,0 +1,231 @@
+//! Tests for the `async` feature, mocking the same json output as the blocking tests and checking
+//! that the generated `_async` endpoint functions agree with their blocking counterparts.
+#![cfg(feature = "async")]
+
+use httpmock::prelude::*;
+use similar_asserts::assert_eq;
+
+use gw2api::client::{AsyncClient, Client};
+use gw2api::error::{ApiError, ApiErrorKind};
+use gw2api::rate_limit::{RateLimiter, MAX_RATE_LIMIT_RETRIES};
+use gw2api::render::{RenderFile, RenderFormat};
+use gw2api::v1::build::Build;
+use gw2api::v1::colors::Colors;
+use gw2api::v1::files::Files;
+use gw2api::v1::map_floor::Floor;
+use gw2api::v2::commerce::transactions::{Transaction, TransactionList};
+use gw2api::v2::pvp::ranks::Rank;
+use gw2api::v2::tokeninfo::TokenInfo;
+
+use std::time::Duration;
+
+fn mock_server(endpoint: &str, json_path: &str) -> MockServer {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.path(endpoint.to_owned());
+        then.status(200)
+            .body_from_file(json_path.to_owned());
+    });
+    server
+}
+
+#[tokio::test]
+async fn get_build_async() {
+    let server = mock_server("/v1/build", "./tests/json/v1/build.json");
+    let client = AsyncClient::new().set_base_url(server.base_url());
+    let build = Build::get_build_async(&client).await.unwrap();
+
+    let blocking_client = Client::new().set_base_url(server.base_url());
+    assert_eq!(Build::get_build(&blocking_client).unwrap(), build);
+}
+
+#[tokio::test]
+async fn get_all_dyes_async() {
+    let server = mock_server("/v1/colors", "./tests/json/v1/colors.json");
+    let client = AsyncClient::new().set_base_url(server.base_url());
+    let colors = Colors::get_all_dyes_async(&client).await.unwrap();
+
+    let blocking_client = Client::new().set_base_url(server.base_url());
+    assert_eq!(Colors::get_all_dyes(&blocking_client).unwrap(), colors);
+}
+
+#[tokio::test]
+async fn get_map_floor_async() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v1/map_floor")
+            .query_param("continent_id", "1")
+            .query_param("floor", "1");
+        then.status(200)
+            .body_from_file("./tests/json/v1/map_floor.json");
+    });
+    let client = AsyncClient::new().set_base_url(server.base_url());
+    Floor::get_map_floor_async(&client, 1, 1).await.unwrap();
+    m.assert();
+}
+
+#[tokio::test]
+async fn authenticated_request_without_key() {
+    let client = AsyncClient::new().set_base_url("http://localhost:1".to_string());
+    let result: Result<TokenInfo, ApiError> = TokenInfo::get_tokeninfo_async(&client).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn get_many_async() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v2/pvp/ranks")
+            .query_param("ids", "1,2");
+        then.status(200)
+            .body("[]");
+    });
+    let client = AsyncClient::new().set_base_url(server.base_url());
+    let ranks = client.get_many::<Rank>(&[1, 2]).await.unwrap();
+    m.assert();
+    assert!(ranks.is_empty());
+}
+
+#[tokio::test]
+async fn get_many_chunked_async() {
+    let server = MockServer::start();
+    let ids: Vec<u32> = (1..=250).collect();
+    let mocks: Vec<_> = ids.chunks(200).map(|chunk| {
+        let query = chunk.iter().map(u32::to_string).collect::<Vec<_>>().join(",");
+        server.mock(|when, then| {
+            when.path("/v2/pvp/ranks").query_param("ids", &query);
+            then.status(206).body("[]");
+        })
+    }).collect();
+    let client = AsyncClient::new().set_base_url(server.base_url()).set_concurrent_requests(2);
+    let result = client.get_many_with_missing::<Rank>(&ids).await.unwrap();
+    for m in mocks {
+        m.assert();
+    }
+    assert_eq!(ids, result.missing);
+}
+
+#[tokio::test]
+async fn iterate_pages_async() {
+    use futures_util::TryStreamExt;
+
+    let server = MockServer::start();
+    let mocks: Vec<_> = (0..2).map(|page| {
+        server.mock(|when, then| {
+            when.path("/v2/pvp/ranks")
+                .query_param("page", page.to_string())
+                .query_param("page_size", "200");
+            then.status(200)
+                .header("X-Page-Total", "2")
+                .header("X-Result-Total", "0")
+                .body("[]");
+        })
+    }).collect();
+    let client = AsyncClient::new().set_base_url(server.base_url());
+    let pages: Vec<_> = client.pages::<Rank>(200).try_collect().await.unwrap();
+    for m in mocks {
+        m.assert();
+    }
+    assert_eq!(2, pages.len());
+}
+
+#[tokio::test]
+async fn iterate_transaction_pages_async() {
+    use futures_util::TryStreamExt;
+
+    let server = MockServer::start();
+    let mocks: Vec<_> = (0..2).map(|page| {
+        server.mock(|when, then| {
+            when.path("/v2/commerce/transactions/current/sells")
+                .query_param("page", page.to_string())
+                .query_param("page_size", "50")
+                .header("Authorization", "Bearer key");
+            then.status(200)
+                .header("X-Page-Total", "2")
+                .header("X-Result-Total", "0")
+                .body("[]");
+        })
+    }).collect();
+    let client = AsyncClient::new().set_base_url(server.base_url()).set_api_key("key".to_string());
+    let pages: Vec<_> = Transaction::transactions_async(&client, TransactionList::CurrentSells, 50)
+        .try_collect()
+        .await
+        .unwrap();
+    for m in mocks {
+        m.assert();
+    }
+    assert_eq!(2, pages.len());
+}
+
+#[tokio::test]
+async fn retry_too_many_requests_async() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v2/tokeninfo");
+        then.status(429).header("Retry-After", "0");
+    });
+    let client = AsyncClient::new()
+        .set_base_url(server.base_url())
+        .set_api_key("key".to_string())
+        .set_rate_limiter(RateLimiter::new(300, 20.0));
+
+    let err = TokenInfo::get_tokeninfo_async(&client).await.unwrap_err();
+    m.assert_hits(1 + MAX_RATE_LIMIT_RETRIES as usize);
+    assert!(matches!(err.kind(), ApiErrorKind::TooManyRequests(_)));
+}
+
+#[tokio::test]
+async fn request_times_out_async() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.path("/v1/build");
+        then.status(200).body(r#"{"build_id": 115267}"#).delay(Duration::from_secs(2));
+    });
+    let client = AsyncClient::new()
+        .set_base_url(server.base_url())
+        .set_timeout(Duration::from_millis(200));
+
+    let err = Build::get_build_async(&client).await.unwrap_err();
+    assert!(matches!(err.kind(), ApiErrorKind::AsyncClientError(_)));
+}
+
+#[tokio::test]
+async fn download_render_file_async() {
+    let server = mock_server("/v1/files", "./tests/json/v1/files.json");
+    let icon = server.mock(|when, then| {
+        when.path("/file/0F80D8D900E1F356B41F752594BEFCE52693DEB3/102442.png");
+        then.status(200).body([0x89, b'P', b'N', b'G']);
+    });
+    let client = AsyncClient::new()
+        .set_base_url(server.base_url())
+        .set_render_url(server.base_url());
+
+    let files = Files::get_all_files_async(&client).await.unwrap();
+    let png = files.map_stairs_up.download_async(&client, RenderFormat::Png).await.unwrap();
+    assert_eq!(vec![0x89, b'P', b'N', b'G'], png);
+    icon.assert_async().await;
+}
+
+#[tokio::test]
+async fn downloads_skip_api_rate_limit_and_headers_async() {
+    let server = MockServer::start_async().await;
+    let icon = server.mock(|when, then| {
+        when.path("/file/0F80D8D900E1F356B41F752594BEFCE52693DEB3/102442.png")
+            .matches(|request| {
+                request.headers.iter().flatten().all(|(name, _)| {
+                    !name.eq_ignore_ascii_case("x-schema-version")
+                        && !name.eq_ignore_ascii_case("accept-language")
+                })
+            });
+        then.status(200).body([0x89, b'P', b'N', b'G']);
+    });
+    let client = AsyncClient::new().set_rate_limiter(RateLimiter::new(1, 1.0));
+
+    let start = std::time::Instant::now();
+    let url = server.url("/file/0F80D8D900E1F356B41F752594BEFCE52693DEB3/102442.png");
+    for _ in 0..4 {
+        client.download(&url).await.unwrap();
+    }
+    assert!(start.elapsed() < Duration::from_secs(2));
+    icon.assert_hits_async(4).await;
+}
@@ -10,28 +10,44 @@ use std::fmt::Debug;
 
 const JSON_PATH: &str = "./tests/json/";
 
+/// Path to the json file containing the mocked output of the given endpoint.
+fn json_path(endpoint: &str) -> PathBuf {
+    // Coincidentally this just works out
+    let mut path = PathBuf::from(JSON_PATH);
+    path.push(endpoint.to_owned() + ".json");
+    path
+}
+
 pub fn mock_endpoint<T: DeserializeOwned + Debug + PartialEq>(endpoint: &str) -> T {
     let endpoint = endpoint.strip_prefix('/').unwrap().to_owned();
-    // Coincidentally this just works out
-    let mut path = PathBuf::from(JSON_PATH.clone());
-    path.push(endpoint.clone() + ".json");
+    let path = json_path(&endpoint);
 
     let json = read_to_string(&path).unwrap();
     let expected = serde_json::from_str::<T>(&json).unwrap();
 
+    let result = with_mocked_endpoint(&endpoint, |client| client.request(&endpoint).unwrap());
+
+    assert_eq!(expected, result);
+    result
+}
+
+/// Serves the json file of the given endpoint from a mock server, and hands a `Client` pointed
+/// at that server to `f`. Panics if `f` never requests the endpoint.
+pub fn with_mocked_endpoint<R>(endpoint: &str, f: impl FnOnce(&Client) -> R) -> R {
+    let endpoint = endpoint.trim_start_matches('/');
+    let path = json_path(endpoint);
+
     let server = MockServer::start();
     let m = server.mock(|when, then| {
-        when.path("/".to_owned() + &endpoint);
+        when.path("/".to_owned() + endpoint);
         then.status(200)
             .body_from_file(path.to_str().unwrap());
     });
 
     let client = Client::new().set_base_url(server.base_url());
-    let result = client.request(&endpoint).unwrap();
+    let result = f(&client);
 
     // Check that the server has received a request
     m.assert();
-
-    assert_eq!(expected, result);
     result
 }
@@ -6,6 +6,16 @@
 
 mod common;
 
+use httpmock::prelude::*;
+use httpmock::Regex;
+use similar_asserts::assert_eq;
+
+use gw2api::cache::{Cache, MemoryCache};
+use gw2api::client::{Client, Localisation};
+use gw2api::geometry::{Point2, Rect, Tile};
+use gw2api::rate_limit::RateLimiter;
+use gw2api::render::{RenderFile, RenderFormat};
+use gw2api::tiles::TileLayer;
 use gw2api::v1::build::Build;
 use gw2api::v1::colors::{Colors};
 use gw2api::v1::continents::Continents;
@@ -25,6 +35,9 @@ use gw2api::v1::wvw::match_details::Match;
 use gw2api::v1::wvw::matches::Matches;
 use gw2api::v1::wvw::objective_names::Objective;
 
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
 use crate::common::mock_endpoint;
 
 // v1/build.rs
@@ -45,6 +58,34 @@ fn get_all_continents() {
     mock_endpoint::<Continents>("/v1/continents");
 }
 
+#[test]
+fn download_continent_tiles() {
+    let server = MockServer::start();
+    let continents_mock = server.mock(|when, then| {
+        when.path("/v1/continents");
+        then.status(200).body_from_file("./tests/json/v1/continents.json");
+    });
+    let tiles_mock = server.mock(|when, then| {
+        when.path_matches(Regex::new(r"^/1/1/4/[23]/3\.jpg$").unwrap());
+        then.status(200).body([0xff, 0xd8, 0xff]);
+    });
+    let client = Client::new()
+        .set_base_url(server.base_url())
+        .set_tiles_url(server.base_url());
+
+    let continents = Continents::get_all_continents(&client).unwrap();
+    let tyria = &continents.continents[&1];
+    let rect = Rect::new(Point2::new(9856.0, 12800.0), Point2::new(13440.0, 14080.0));
+    let tiles = Tile::covering(rect, 4, tyria.max_zoom);
+    assert_eq!(vec![tyria.tile_at(rect.min, 4), tyria.tile_at(rect.max, 4)], tiles);
+
+    let downloaded = TileLayer::new(1, 1).download_all(&client, &tiles).unwrap();
+    assert_eq!(2, downloaded.len());
+    assert_eq!(vec![0xff, 0xd8, 0xff], downloaded[1].1);
+    continents_mock.assert();
+    tiles_mock.assert_hits(2);
+}
+
 // v1/event_details.rs
 #[test]
 fn get_all_events() {
@@ -59,6 +100,62 @@ fn get_all_files() {
     mock_endpoint::<Files>("/v1/files");
 }
 
+#[test]
+fn download_render_file() {
+    let server = MockServer::start();
+    let files_mock = server.mock(|when, then| {
+        when.path("/v1/files");
+        then.status(200).body_from_file("./tests/json/v1/files.json");
+    });
+    let icon = server.mock(|when, then| {
+        when.path("/file/0F80D8D900E1F356B41F752594BEFCE52693DEB3/102442.png");
+        then.status(200).body([0x89, b'P', b'N', b'G']);
+    });
+    let missing = server.mock(|when, then| {
+        when.path("/file/0F80D8D900E1F356B41F752594BEFCE52693DEB3/102442.jpg");
+        then.status(404);
+    });
+    let client = Client::new()
+        .set_base_url(server.base_url())
+        .set_render_url(server.base_url());
+
+    let files = Files::get_all_files(&client).unwrap();
+    let png = files.map_stairs_up.download(&client, RenderFormat::Png).unwrap();
+    assert_eq!(vec![0x89, b'P', b'N', b'G'], png);
+    let err = files.map_stairs_up.download(&client, RenderFormat::Jpg).unwrap_err();
+    assert_eq!(Some(404), err.status());
+    files_mock.assert();
+    icon.assert();
+    missing.assert();
+}
+
+#[test]
+fn downloads_skip_api_rate_limit_and_headers() {
+    let server = MockServer::start();
+    let icon = server.mock(|when, then| {
+        when.path("/file/0F80D8D900E1F356B41F752594BEFCE52693DEB3/102442.png")
+            .matches(|request| {
+                request.headers.iter().flatten().all(|(name, _)| {
+                    !name.eq_ignore_ascii_case("x-schema-version")
+                        && !name.eq_ignore_ascii_case("accept-language")
+                })
+            });
+        then.status(200).body([0x89, b'P', b'N', b'G']);
+    });
+    // Going through the limiter would take three seconds for the last three downloads.
+    let client = Client::new()
+        .set_render_url(server.base_url())
+        .set_rate_limiter(RateLimiter::new(1, 1.0));
+
+    let start = Instant::now();
+    let url = server.url("/file/0F80D8D900E1F356B41F752594BEFCE52693DEB3/102442.png");
+    for _ in 0..4 {
+        client.download(&url).unwrap();
+    }
+    assert!(start.elapsed() < Duration::from_secs(2));
+    icon.assert_hits(4);
+}
+
 // v1/guild_details.rs
 #[test]
 fn get_guild_by_id() {
@@ -86,7 +183,10 @@ fn get_back() {
 
 #[test]
 fn get_bag() {
-    mock_endpoint::<Item>("/v1/item_details/bag");
+    let item = mock_endpoint::<Item>("/v1/item_details/bag");
+    let bag = item.bag.unwrap();
+    assert!(bag.no_sell_or_sort);
+    assert_eq!(8, bag.size);
 }
 
 
@@ -107,7 +207,9 @@ fn get_consumable_others() {
 
 #[test]
 fn get_consumable_generic_with_effect() {
-    mock_endpoint::<Item>("/v1/item_details/consumable_generic_with_effect");
+    let item = mock_endpoint::<Item>("/v1/item_details/consumable_generic_with_effect");
+    let duration = item.consumable.unwrap().duration_ms;
+    assert_eq!(Some(Duration::from_secs(90 * 60)), duration);
 }
 
 #[test]
@@ -277,3 +379,106 @@ fn get_matches() {
 fn get_objective_names() {
     mock_endpoint::<Vec<Objective>>("/v1/wvw/objective_names");
 }
+
+// cache.rs
+/// Serves the json file of the endpoint with a `Cache-Control` header.
+fn mock_cacheable<'a>(server: &'a MockServer, endpoint: &str) -> httpmock::Mock<'a> {
+    server.mock(|when, then| {
+        when.path(endpoint);
+        then.status(200)
+            .header("Cache-Control", "public, max-age=300")
+            .body_from_file(format!("./tests/json{}.json", endpoint));
+    })
+}
+
+#[test]
+fn cache_static_data() {
+    let server = MockServer::start();
+    let colors = mock_cacheable(&server, "/v1/colors");
+    let files = mock_cacheable(&server, "/v1/files");
+    let client = Client::new().set_base_url(server.base_url()).set_cache(MemoryCache::new(10));
+
+    let dyes = Colors::get_all_dyes(&client).unwrap();
+    assert_eq!(dyes, Colors::get_all_dyes(&client).unwrap());
+    Files::get_all_files(&client).unwrap();
+    Files::get_all_files(&client).unwrap();
+    colors.assert_hits(1);
+    files.assert_hits(1);
+
+    // A client with another language has to request the dyes again
+    let client = client.set_lang(Localisation::German);
+    Colors::get_all_dyes(&client).unwrap();
+    colors.assert_hits(2);
+}
+
+#[test]
+fn cache_is_shared_between_clones() {
+    let server = MockServer::start();
+    let colors = mock_cacheable(&server, "/v1/colors");
+    let client = Client::new().set_base_url(server.base_url()).set_cache(MemoryCache::new(10));
+
+    Colors::get_all_dyes(&client).unwrap();
+    Colors::get_all_dyes(&client.clone()).unwrap();
+    colors.assert_hits(1);
+}
+
+#[test]
+fn cache_is_kept_apart_between_servers() {
+    let first = MockServer::start();
+    let second = MockServer::start();
+    let first_colors = mock_cacheable(&first, "/v1/colors");
+    let second_colors = mock_cacheable(&second, "/v1/colors");
+    let cache = Arc::new(MemoryCache::new(10));
+
+    let client = Client::new().set_base_url(first.base_url()).set_cache(cache.clone());
+    Colors::get_all_dyes(&client).unwrap();
+    let client = Client::new().set_base_url(second.base_url()).set_cache(cache);
+    Colors::get_all_dyes(&client).unwrap();
+    first_colors.assert_hits(1);
+    second_colors.assert_hits(1);
+}
+
+#[test]
+fn uncacheable_responses_are_requested_again() {
+    let server = MockServer::start();
+    let colors = server.mock(|when, then| {
+        when.path("/v1/colors");
+        then.status(200)
+            .header("Cache-Control", "no-cache")
+            .body_from_file("./tests/json/v1/colors.json");
+    });
+    let client = Client::new().set_base_url(server.base_url()).set_cache(MemoryCache::new(10));
+
+    Colors::get_all_dyes(&client).unwrap();
+    Colors::get_all_dyes(&client).unwrap();
+    colors.assert_hits(2);
+}
+
+#[test]
+fn new_build_invalidates_cache() {
+    let server = MockServer::start();
+    let colors = mock_cacheable(&server, "/v1/colors");
+    let mut build = server.mock(|when, then| {
+        when.path("/v2/build");
+        then.status(200).body(r#"{"id": 1}"#);
+    });
+    let cache = Arc::new(MemoryCache::new(10));
+    let client = Client::new().set_base_url(server.base_url()).set_cache(cache.clone());
+
+    gw2api::v2::build::Build::get_build(&client).unwrap();
+    Colors::get_all_dyes(&client).unwrap();
+    gw2api::v2::build::Build::get_build(&client).unwrap();
+    Colors::get_all_dyes(&client).unwrap();
+    colors.assert_hits(1);
+    assert_eq!(Some(1), cache.build_id());
+
+    build.delete();
+    server.mock(|when, then| {
+        when.path("/v2/build");
+        then.status(200).body(r#"{"id": 2}"#);
+    });
+    gw2api::v2::build::Build::get_build(&client).unwrap();
+    Colors::get_all_dyes(&client).unwrap();
+    colors.assert_hits(2);
+    assert_eq!(Some(2), cache.build_id());
+}
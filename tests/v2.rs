@@ -0,0 +1,986 @@
+//! Module for testing the v2 endpoints against mocked json output, in the same manner as the v1
+//! tests.
+
+mod common;
+
+use httpmock::prelude::*;
+use similar_asserts::assert_eq;
+
+use gw2api::attributes::Attribute;
+use gw2api::client::{Client, SCHEMA_VERSION};
+use gw2api::crafting::RecipeBook;
+use gw2api::coins::Coins;
+use gw2api::endpoint::Endpoint;
+use gw2api::utils::{Profession, Team};
+use gw2api::error::ApiErrorKind;
+use gw2api::rate_limit::{RateLimiter, MAX_RATE_LIMIT_RETRIES};
+use gw2api::retry::RetryPolicy;
+use gw2api::v2::account::Account;
+use gw2api::v2::account::achievements::AccountAchievements;
+use gw2api::v2::account::bank::Bank;
+use gw2api::v2::account::buildstorage::BuildStorage;
+use gw2api::v2::account::inventory::SharedInventory;
+use gw2api::v2::account::masteries::{AccountMasteries, MasteryPoints};
+use gw2api::v2::account::materials::Materials;
+use gw2api::v2::account::progress::*;
+use gw2api::v2::account::unlocks::*;
+use gw2api::v2::account::wallet::Wallet;
+use gw2api::v2::build::Build;
+use gw2api::v2::characters::{Character, CharacterCore};
+use gw2api::v2::commerce::delivery::Delivery;
+use gw2api::v2::commerce::exchange::{CoinsToGems, GemsToCoins};
+use gw2api::v2::commerce::listings::Listing;
+use gw2api::v2::commerce::prices::Price;
+use gw2api::v2::commerce::transactions::{Transaction, TransactionList};
+use gw2api::v2::colors::Color;
+use gw2api::v2::emblem::{EmblemBackground, EmblemForeground};
+use gw2api::v2::items::{Item, ItemDetails};
+use gw2api::v2::itemstats::ItemStat;
+use gw2api::v2::pvp::amulets::Amulet;
+use gw2api::v2::pvp::games::Game;
+use gw2api::v2::pvp::heroes::Hero;
+use gw2api::v2::pvp::ranks::Rank;
+use gw2api::v2::recipes::search::RecipeSearch;
+use gw2api::v2::wvw::abilities::Ability;
+use gw2api::v2::wvw::matches::{Match, MatchStats};
+use gw2api::v2::wvw::objectives::Objective;
+use gw2api::v2::wvw::ranks::Rank as WvwRank;
+use gw2api::v2::wvw::upgrades::Upgrade;
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::common::{mock_endpoint, with_mocked_endpoint};
+
+const JSON_AMULETS: &str = r#"
+[
+  {
+    "id": 4,
+    "name": "Assassin Amulet",
+    "icon": "https://render.guildwars2.com/file/02E9EFDEF9587130A25F17AC396913FBBE3C716D/455602.png",
+    "attributes": {
+      "Precision": 1200,
+      "Power": 900,
+      "CritDamage": 900
+    }
+  },
+  {
+    "id": 8,
+    "name": "Avatar Amulet",
+    "icon": "https://render.guildwars2.com/file/02E9EFDEF9587130A25F17AC396913FBBE3C716D/455602.png",
+    "attributes": {
+      "Precision": 900,
+      "Healing": 1200,
+      "Power": 900
+    }
+  }
+]"#;
+
+// v2/build.rs
+#[test]
+fn get_build() {
+    mock_endpoint::<Build>("/v2/build");
+    with_mocked_endpoint("/v2/build", |client| client.fetch::<Build>().unwrap());
+}
+
+// v2/pvp/amulets.rs
+#[test]
+fn get_amulet_ids() {
+    let expected = mock_endpoint::<Vec<u32>>("/v2/pvp/amulets");
+    let ids = with_mocked_endpoint("/v2/pvp/amulets", |client| client.get_ids::<Amulet>().unwrap());
+    assert_eq!(expected, ids);
+}
+
+#[test]
+fn get_amulet() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v2/pvp/amulets")
+            .query_param("id", "4");
+        then.status(200)
+            .body(r#"{"id": 4, "name": "Assassin Amulet", "icon": "", "attributes": {}}"#);
+    });
+    let client = Client::new().set_base_url(server.base_url());
+    let amulet = client.get::<Amulet>(&4).unwrap();
+    m.assert();
+    assert_eq!(4, amulet.id);
+}
+
+#[test]
+fn get_many_amulets() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v2/pvp/amulets")
+            .query_param("ids", "4,8");
+        then.status(200)
+            .body(JSON_AMULETS);
+    });
+    let client = Client::new().set_base_url(server.base_url());
+    let amulets = client.get_many::<Amulet>(&[4, 8]).unwrap();
+    m.assert();
+    assert_eq!(serde_json::from_str::<Vec<Amulet>>(JSON_AMULETS).unwrap(), amulets);
+}
+
+#[test]
+fn get_all_amulets() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v2/pvp/amulets")
+            .query_param("ids", "all");
+        then.status(200)
+            .body(JSON_AMULETS);
+    });
+    let client = Client::new().set_base_url(server.base_url());
+    assert_eq!(2, client.get_all::<Amulet>().unwrap().len());
+    m.assert();
+}
+
+// v2/pvp/heroes.rs
+#[test]
+fn get_hero_ids() {
+    let expected = mock_endpoint::<Vec<String>>("/v2/pvp/heroes");
+    let ids = with_mocked_endpoint("/v2/pvp/heroes", |client| client.get_ids::<Hero>().unwrap());
+    assert_eq!(expected, ids);
+}
+
+// v2/pvp/ranks.rs
+#[test]
+fn get_rank_ids() {
+    let expected = mock_endpoint::<Vec<u32>>("/v2/pvp/ranks");
+    let ids = with_mocked_endpoint("/v2/pvp/ranks", |client| client.get_ids::<Rank>().unwrap());
+    assert_eq!(expected, ids);
+}
+
+/// Builds a json list of minimal PvP ranks for the given ids.
+fn json_ranks(ids: impl Iterator<Item = u32>) -> String {
+    let ranks: Vec<String> = ids.map(|id| format!(
+        r#"{{"id": {id}, "finisher_id": 1, "name": "", "icon": "", "min_rank": 1, "max_rank": 1,
+            "levels": [{{"min_rank": 1, "max_rank": 1, "points": 1}}]}}"#)).collect();
+    format!("[{}]", ranks.join(","))
+}
+
+fn mock_rank_chunks(server: &MockServer) -> Vec<httpmock::Mock<'_>> {
+    // Ids 1-450 are requested, out of which every id divisible by 100 does not exist. The last
+    // chunk's ids do not exist at all, which the API answers with a 404.
+    let ids: Vec<u32> = (1..=450).rev().collect();
+    let chunks: Vec<&[u32]> = ids.chunks(200).collect();
+    let mut mocks = Vec::new();
+    for chunk in &chunks[..2] {
+        let query = chunk.iter().map(u32::to_string).collect::<Vec<_>>().join(",");
+        let body = json_ranks(chunk.iter().copied().filter(|id| id % 100 != 0));
+        mocks.push(server.mock(|when, then| {
+            when.path("/v2/pvp/ranks").query_param("ids", &query);
+            then.status(206).body(&body);
+        }));
+    }
+    let query = chunks[2].iter().map(u32::to_string).collect::<Vec<_>>().join(",");
+    mocks.push(server.mock(|when, then| {
+        when.path("/v2/pvp/ranks").query_param("ids", &query);
+        then.status(404).body(r#"{"text": "all ids provided are invalid"}"#);
+    }));
+    mocks
+}
+
+#[test]
+fn get_many_chunked() {
+    let server = MockServer::start();
+    let mocks = mock_rank_chunks(&server);
+    let client = Client::new().set_base_url(server.base_url());
+
+    let ids: Vec<u32> = (1..=450).rev().collect();
+    let result = client.get_many_with_missing::<Rank>(&ids).unwrap();
+    for m in mocks {
+        m.assert();
+    }
+
+    let expected: Vec<u32> = (51..=450).rev().filter(|id| id % 100 != 0).collect();
+    assert_eq!(expected, result.found.iter().map(|rank| rank.id).collect::<Vec<_>>());
+    let missing: Vec<u32> = (1..=450).rev().filter(|id| id % 100 == 0 || *id <= 50).collect();
+    assert_eq!(missing, result.missing);
+}
+
+#[test]
+fn get_many_chunked_in_parallel() {
+    let server = MockServer::start();
+    let mocks = mock_rank_chunks(&server);
+    let client = Client::new().set_base_url(server.base_url()).set_concurrent_requests(3);
+
+    let ids: Vec<u32> = (1..=450).rev().collect();
+    let ranks = client.get_many::<Rank>(&ids).unwrap();
+    for m in mocks {
+        m.assert();
+    }
+    assert_eq!(396, ranks.len());
+    assert_eq!(450, ranks[0].id);
+}
+
+/// Mocks three pages of two amulets each on the given server.
+fn mock_amulet_pages(server: &MockServer) -> Vec<httpmock::Mock<'_>> {
+    (0..3).map(|page| {
+        server.mock(|when, then| {
+            when.path("/v2/pvp/amulets")
+                .query_param("page", page.to_string())
+                .query_param("page_size", "2");
+            then.status(200)
+                .header("X-Page-Total", "3")
+                .header("X-Page-Size", "2")
+                .header("X-Result-Total", "6")
+                .header("X-Result-Count", "2")
+                .header("Link", format!("</v2/pvp/amulets?page={}&page_size=2>; rel=self", page))
+                .body(JSON_AMULETS);
+        })
+    }).collect()
+}
+
+#[test]
+fn get_page() {
+    let server = MockServer::start();
+    let _mocks = mock_amulet_pages(&server);
+    let client = Client::new().set_base_url(server.base_url());
+
+    let page = client.get_page::<Amulet>(1, 2).unwrap();
+    assert_eq!(1, page.page);
+    assert_eq!(3, page.page_total);
+    assert_eq!(6, page.result_total);
+    assert_eq!(Some("/v2/pvp/amulets?page=1&page_size=2".to_string()), page.links.current);
+    assert!(page.has_next());
+}
+
+#[test]
+fn iterate_pages() {
+    let server = MockServer::start();
+    let mocks = mock_amulet_pages(&server);
+    let client = Client::new().set_base_url(server.base_url());
+
+    let pages: Vec<_> = client.pages::<Amulet>(2).collect::<Result<_, _>>().unwrap();
+    for m in mocks {
+        m.assert();
+    }
+    assert_eq!(vec![0, 1, 2], pages.iter().map(|page| page.page).collect::<Vec<_>>());
+    assert_eq!(6, pages.into_iter().flat_map(|page| page.items).count());
+}
+
+/// Mocks the endpoint with the given status code, responding with its json file.
+fn mock_error<'a>(server: &'a MockServer, endpoint: &str, status: u16) -> httpmock::Mock<'a> {
+    server.mock(|when, then| {
+        when.path(endpoint);
+        then.status(status)
+            .body_from_file(format!("./tests/json{}.json", endpoint));
+    })
+}
+
+// v2/pvp/games.rs
+#[test]
+fn get_games_with_invalid_key() {
+    let server = MockServer::start();
+    let m = mock_error(&server, "/v2/pvp/games", 401);
+    let client = Client::new().set_base_url(server.base_url()).set_api_key("invalid".to_string());
+
+    let err = client.get_ids::<Game>().unwrap_err();
+    m.assert();
+    assert!(matches!(err.kind(), ApiErrorKind::Unauthorized(_)));
+    assert_eq!(Some(401), err.status());
+    assert_eq!(Some("Invalid access token"), err.text());
+}
+
+#[test]
+fn get_game_without_key() {
+    let client = Client::new().set_base_url("http://localhost:1".to_string());
+    let err = client.get_ids::<Game>().unwrap_err();
+    assert!(matches!(err.kind(), ApiErrorKind::ApiKeyNotSet));
+}
+
+#[test]
+fn get_invalid_id() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v2/pvp/amulets").query_param("id", "90909");
+        then.status(404).body(r#"{"text": "no such id"}"#);
+    });
+    let client = Client::new().set_base_url(server.base_url());
+
+    let err = client.get::<Amulet>(&90909).unwrap_err();
+    m.assert();
+    assert!(matches!(err.kind(), ApiErrorKind::NotFound(_)));
+    assert_eq!(Some("no such id"), err.text());
+}
+
+#[test]
+fn server_error() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v2/build");
+        then.status(503).body(r#"{"text": "API not active"}"#);
+    });
+    let client = Client::new().set_base_url(server.base_url());
+
+    let err = client.fetch::<Build>().unwrap_err();
+    m.assert();
+    assert!(matches!(err.kind(), ApiErrorKind::ServerError(_)));
+    assert_eq!(Some("API not active"), err.text());
+}
+
+#[test]
+fn malformed_response() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v2/build");
+        then.status(200).body(r#"{"build": 1}"#);
+    });
+    let client = Client::new().set_base_url(server.base_url());
+
+    let err = client.fetch::<Build>().unwrap_err();
+    m.assert();
+    assert!(matches!(err.kind(), ApiErrorKind::DeserializeError(_)));
+}
+
+// rate_limit.rs
+#[test]
+fn rate_limit_shared_between_threads() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v2/build");
+        then.status(200).body(r#"{"id": 115267}"#);
+    });
+    // Two requests in a burst, after which every request has to wait 100ms for a token
+    let client = Client::new()
+        .set_base_url(server.base_url())
+        .set_rate_limiter(RateLimiter::new(2, 10.0));
+
+    let start = Instant::now();
+    thread::scope(|scope| {
+        for _ in 0..3 {
+            let client = client.clone();
+            scope.spawn(move || {
+                client.fetch::<Build>().unwrap();
+                client.fetch::<Build>().unwrap();
+            });
+        }
+    });
+    m.assert_hits(6);
+    assert!(start.elapsed() >= Duration::from_millis(400));
+}
+
+#[test]
+fn retry_too_many_requests() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v2/build");
+        then.status(429)
+            .header("Retry-After", "0")
+            .body(r#"{"text": "too many requests"}"#);
+    });
+    let client = Client::new()
+        .set_base_url(server.base_url())
+        .set_rate_limiter(RateLimiter::new(300, 20.0));
+
+    let err = client.fetch::<Build>().unwrap_err();
+    m.assert_hits(1 + MAX_RATE_LIMIT_RETRIES as usize);
+    assert!(matches!(err.kind(), ApiErrorKind::TooManyRequests(_)));
+}
+
+#[test]
+fn no_retry_without_rate_limiter() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v2/build");
+        then.status(429).body(r#"{"text": "too many requests"}"#);
+    });
+    let client = Client::new().set_base_url(server.base_url());
+
+    let err = client.fetch::<Build>().unwrap_err();
+    m.assert_hits(1);
+    assert!(matches!(err.kind(), ApiErrorKind::TooManyRequests(_)));
+}
+
+// retry.rs
+#[test]
+fn retry_server_errors() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v2/build");
+        then.status(503).body(r#"{"text": "API not active"}"#);
+    });
+    let policy = RetryPolicy::new()
+        .set_max_attempts(3)
+        .set_backoff(Duration::from_millis(10), Duration::from_millis(20));
+    let client = Client::new().set_base_url(server.base_url()).set_retry_policy(policy);
+
+    let err = client.fetch::<Build>().unwrap_err();
+    m.assert_hits(3);
+    assert!(matches!(err.kind(), ApiErrorKind::ServerError(_)));
+}
+
+#[test]
+fn no_retry_on_client_errors() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v2/build");
+        then.status(400).body(r#"{"text": "bad request"}"#);
+    });
+    let client = Client::new()
+        .set_base_url(server.base_url())
+        .set_retry_policy(RetryPolicy::new().set_max_attempts(3));
+
+    client.fetch::<Build>().unwrap_err();
+    m.assert_hits(1);
+}
+
+#[test]
+fn request_times_out() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v2/build");
+        then.status(200).body(r#"{"id": 115267}"#).delay(Duration::from_secs(3));
+    });
+    let client = Client::new()
+        .set_base_url(server.base_url())
+        .set_timeout(Duration::from_secs(1));
+
+    let err = client.fetch::<Build>().unwrap_err();
+    m.assert();
+    assert!(matches!(err.kind(), ApiErrorKind::ClientError(_)));
+}
+
+// X-Schema-Version
+#[test]
+fn send_schema_version() {
+    let server = MockServer::start();
+    let default = server.mock(|when, then| {
+        when.path("/v2/build").header("X-Schema-Version", SCHEMA_VERSION);
+        then.status(200).body(r#"{"id": 1}"#);
+    });
+    let overridden = server.mock(|when, then| {
+        when.path("/v2/build").header("X-Schema-Version", "2019-12-19T00:00:00.000Z");
+        then.status(200).body(r#"{"id": 2}"#);
+    });
+    let client = Client::new().set_base_url(server.base_url());
+
+    assert_eq!(Build { id: 1 }, client.fetch::<Build>().unwrap());
+    let build = client.with_schema_version("2019-12-19T00:00:00.000Z").fetch::<Build>().unwrap();
+    assert_eq!(Build { id: 2 }, build);
+    assert_eq!(Some(SCHEMA_VERSION), client.schema_version());
+    default.assert();
+    overridden.assert();
+}
+
+// v2/account
+/// The account fixtures hold the response of the API to an invalid key, so check that every
+/// account endpoint is requested with the key and reports the rejection.
+fn assert_invalid_key<T: Endpoint + std::fmt::Debug>() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path(T::URL).header("Authorization", "Bearer invalid");
+        then.status(401)
+            .body_from_file(format!("./tests/json{}.json", T::URL));
+    });
+    let client = Client::new().set_base_url(server.base_url()).set_api_key("invalid".to_string());
+
+    let err = client.fetch::<T>().unwrap_err();
+    m.assert();
+    assert!(matches!(err.kind(), ApiErrorKind::Unauthorized(_)), "{}: {}", T::URL, err);
+    assert_eq!(Some("Invalid access token"), err.text());
+}
+
+#[test]
+fn get_account_with_invalid_key() {
+    assert_invalid_key::<Account>();
+    assert_invalid_key::<AccountAchievements>();
+    assert_invalid_key::<Bank>();
+    assert_invalid_key::<BuildStorage>();
+    assert_invalid_key::<SharedInventory>();
+    assert_invalid_key::<AccountMasteries>();
+    assert_invalid_key::<MasteryPoints>();
+    assert_invalid_key::<Materials>();
+    assert_invalid_key::<Wallet>();
+    assert_invalid_key::<AccountDailyCrafting>();
+    assert_invalid_key::<AccountDungeons>();
+    assert_invalid_key::<AccountLuck>();
+    assert_invalid_key::<AccountMapChests>();
+    assert_invalid_key::<AccountRaids>();
+    assert_invalid_key::<AccountWorldBosses>();
+    assert_invalid_key::<AccountDyes>();
+    assert_invalid_key::<AccountEmotes>();
+    assert_invalid_key::<AccountFinishers>();
+    assert_invalid_key::<AccountGliders>();
+    assert_invalid_key::<AccountHomeCats>();
+    assert_invalid_key::<AccountHomeNodes>();
+    assert_invalid_key::<AccountMailCarriers>();
+    assert_invalid_key::<AccountMinis>();
+    assert_invalid_key::<AccountMountSkins>();
+    assert_invalid_key::<AccountMountTypes>();
+    assert_invalid_key::<AccountNovelties>();
+    assert_invalid_key::<AccountOutfits>();
+    assert_invalid_key::<AccountPvpHeroes>();
+    assert_invalid_key::<AccountRecipes>();
+    assert_invalid_key::<AccountSkins>();
+    assert_invalid_key::<AccountTitles>();
+}
+
+#[test]
+fn get_account_sub_endpoints() {
+    mock_endpoint::<Vec<String>>("/v2/account/home");
+    mock_endpoint::<Vec<String>>("/v2/account/mounts");
+}
+
+#[test]
+fn get_account_wallet() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v2/account/wallet").header("Authorization", "Bearer key");
+        then.status(200).body(r#"[{"id": 1, "value": 1234567}, {"id": 4, "value": 800}]"#);
+    });
+    let client = Client::new().set_base_url(server.base_url()).set_api_key("key".to_string());
+
+    let wallet = Wallet::get_wallet(&client).unwrap();
+    m.assert();
+    assert_eq!(1234567, wallet.amount(1));
+    assert_eq!(800, wallet.amount(4));
+}
+
+// v2/characters
+#[test]
+fn get_characters_with_invalid_key() {
+    assert_invalid_key::<Character>();
+}
+
+#[test]
+fn get_characters() {
+    let server = MockServer::start();
+    let ids = server.mock(|when, then| {
+        when.path("/v2/characters");
+        then.status(200).body(r#"["Hello World", "Eir Stegalkin"]"#);
+    });
+    let core = server.mock(|when, then| {
+        when.path("/v2/characters/Hello%20World/core");
+        then.status(200).body(r#"{
+            "name": "Hello World",
+            "race": "Sylvari",
+            "gender": "Female",
+            "profession": "Mesmer",
+            "level": 80,
+            "age": 3600,
+            "created": "2022-03-01T00:00:00Z",
+            "last_modified": "2022-03-02T00:00:00Z",
+            "deaths": 12
+        }"#);
+    });
+    let client = Client::new().set_base_url(server.base_url()).set_api_key("key".to_string());
+
+    let names = client.get_ids::<Character>().unwrap();
+    assert_eq!(vec!["Hello World", "Eir Stegalkin"], names);
+    let character = CharacterCore::get_character_core(&client, &names[0]).unwrap();
+    assert_eq!(Profession::Mesmer, character.profession);
+    ids.assert();
+    core.assert();
+}
+
+// v2/commerce
+#[test]
+fn get_price_and_listing_ids() {
+    let expected = mock_endpoint::<Vec<u32>>("/v2/commerce/prices");
+    let ids = with_mocked_endpoint("/v2/commerce/prices", |client| {
+        client.get_ids::<Price>().unwrap()
+    });
+    assert_eq!(expected, ids);
+
+    let expected = mock_endpoint::<Vec<u32>>("/v2/commerce/listings");
+    let ids = with_mocked_endpoint("/v2/commerce/listings", |client| {
+        client.get_ids::<Listing>().unwrap()
+    });
+    assert_eq!(expected, ids);
+}
+
+#[test]
+fn get_prices() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v2/commerce/prices").query_param("ids", "19684,19709");
+        then.status(200).body(r#"[
+            {"id": 19684, "whitelisted": false,
+             "buys": {"quantity": 145975, "unit_price": 7018},
+             "sells": {"quantity": 126, "unit_price": 7286}},
+            {"id": 19709, "whitelisted": true,
+             "buys": {"quantity": 0, "unit_price": 0},
+             "sells": {"quantity": 5, "unit_price": 150}}
+        ]"#);
+    });
+    let client = Client::new().set_base_url(server.base_url());
+
+    let prices = client.get_many::<Price>(&[19684, 19709]).unwrap();
+    m.assert();
+    assert_eq!(268, prices[0].spread());
+    assert!(prices[1].whitelisted);
+}
+
+#[test]
+fn get_exchange_rate() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v2/commerce/exchange/coins").query_param("quantity", "100000");
+        then.status(200).body(r#"{"coins_per_gem": 2474, "quantity": 40}"#);
+    });
+    let client = Client::new().set_base_url(server.base_url());
+
+    let rate = CoinsToGems::get_quote(&client, Coins::from_copper(100000)).unwrap();
+    m.assert();
+    assert_eq!(CoinsToGems { coins_per_gem: Coins::from_copper(2474), quantity: 40 }, rate);
+}
+
+#[test]
+fn get_gems_to_coins_rate() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v2/commerce/exchange/gems").query_param("quantity", "100");
+        then.status(200).body(r#"{"coins_per_gem": 1858, "quantity": 185800}"#);
+    });
+    let client = Client::new().set_base_url(server.base_url());
+
+    let rate = GemsToCoins::get_quote(&client, 100).unwrap();
+    m.assert();
+    assert_eq!(Coins::from_parts(18, 58, 0), Some(rate.quantity));
+}
+
+#[test]
+fn get_exchange_rate_without_quantity() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v2/commerce/exchange/gems").query_param("quantity", "0");
+        then.status(400).body_from_file("./tests/json/v2/commerce/exchange/gems.json");
+    });
+    let client = Client::new().set_base_url(server.base_url());
+
+    let err = GemsToCoins::get_quote(&client, 0).unwrap_err();
+    m.assert();
+    assert!(matches!(err.kind(), ApiErrorKind::BadRequest(_)), "{}", err);
+    assert_eq!(Some("No ?quantity=<quantity> value specified."), err.text());
+}
+
+#[test]
+fn get_delivery_with_invalid_key() {
+    assert_invalid_key::<Delivery>();
+}
+
+#[test]
+fn get_transactions_with_invalid_key() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v2/commerce/transactions/current/buys")
+            .header("Authorization", "Bearer invalid");
+        then.status(401).body_from_file("./tests/json/v2/commerce/transactions.json");
+    });
+    let client = Client::new().set_base_url(server.base_url()).set_api_key("invalid".to_string());
+
+    let err = Transaction::get_transactions(&client, TransactionList::CurrentBuys, 0, 50)
+        .unwrap_err();
+    m.assert();
+    assert!(matches!(err.kind(), ApiErrorKind::Unauthorized(_)), "{}", err);
+}
+
+#[test]
+fn get_transaction_pages() {
+    let server = MockServer::start();
+    let transaction = |id: u64| format!(
+        r#"{{"id": {}, "item_id": 19684, "price": 7018, "quantity": 1,
+            "created": "2023-04-12T17:31:24+00:00", "purchased": "2023-04-12T18:02:51+00:00"}}"#,
+        id);
+    let first = server.mock(|when, then| {
+        when.path("/v2/commerce/transactions/history/sells")
+            .query_param("page", "0")
+            .query_param("page_size", "2")
+            .header("Authorization", "Bearer key");
+        then.status(200)
+            .header("X-Page-Total", "2")
+            .header("X-Page-Size", "2")
+            .header("X-Result-Total", "3")
+            .header("X-Result-Count", "2")
+            .body(format!("[{}, {}]", transaction(1), transaction(2)));
+    });
+    let second = server.mock(|when, then| {
+        when.path("/v2/commerce/transactions/history/sells")
+            .query_param("page", "1")
+            .query_param("page_size", "2");
+        then.status(200)
+            .header("X-Page-Total", "2")
+            .header("X-Page-Size", "2")
+            .header("X-Result-Total", "3")
+            .header("X-Result-Count", "1")
+            .body(format!("[{}]", transaction(3)));
+    });
+    let client = Client::new().set_base_url(server.base_url()).set_api_key("key".to_string());
+
+    let ids: Vec<u64> = Transaction::transactions(&client, TransactionList::HistorySells, 2)
+        .flat_map(|page| page.unwrap().items)
+        .map(|transaction| transaction.id)
+        .collect();
+    first.assert();
+    second.assert();
+    assert_eq!(vec![1, 2, 3], ids);
+}
+
+// v2/items.rs
+#[test]
+fn get_item_ids() {
+    let expected = mock_endpoint::<Vec<u32>>("/v2/items");
+    let ids = with_mocked_endpoint("/v2/items", |client| client.get_ids::<Item>().unwrap());
+    assert_eq!(expected, ids);
+}
+
+#[test]
+fn resolve_item_stat_choices() {
+    let server = MockServer::start();
+    let item_mock = server.mock(|when, then| {
+        when.path("/v2/items").query_param("id", "49371");
+        then.status(200).body(r#"{
+            "name": "Ad Infinitum",
+            "type": "Back",
+            "level": 80,
+            "rarity": "Legendary",
+            "vendor_value": 0,
+            "game_types": ["Activity", "Wvw", "Dungeon", "Pve"],
+            "flags": ["HideSuffix", "AccountBound", "NoSalvage", "NoSell", "AccountBindOnUse"],
+            "restrictions": [],
+            "id": 49371,
+            "chat_link": "[&AgHbwAAA]",
+            "details": {
+                "infusion_slots": [{"flags": ["Infusion"]}, {"flags": ["Infusion"]}],
+                "attribute_adjustment": 358.512,
+                "stat_choices": [161, 1130]
+            }
+        }"#);
+    });
+    let stats_mock = server.mock(|when, then| {
+        when.path("/v2/itemstats").query_param("ids", "161,1130");
+        then.status(200).body(r#"[
+            {"id": 161, "name": "Berserker's", "attributes": [
+                {"attribute": "Power", "multiplier": 0.35, "value": 0},
+                {"attribute": "Precision", "multiplier": 0.25, "value": 0},
+                {"attribute": "CritDamage", "multiplier": 0.25, "value": 0}]},
+            {"id": 1130, "name": "", "attributes": []}
+        ]"#);
+    });
+    let client = Client::new().set_base_url(server.base_url());
+
+    let item = client.get::<Item>(&49371).unwrap();
+    assert!(matches!(item.details, ItemDetails::Back(_)));
+    let choices = client.get_many::<ItemStat>(item.details.stat_choices()).unwrap();
+    item_mock.assert();
+    stats_mock.assert();
+    assert_eq!("Berserker's", choices[0].name);
+    let attributes = choices[0].attributes_for(item.details.attribute_adjustment());
+    assert_eq!(Some(&(Attribute::Power, 125)), attributes.first());
+}
+
+// v2/itemstats.rs
+#[test]
+fn get_itemstat_ids() {
+    let expected = mock_endpoint::<Vec<u32>>("/v2/itemstats");
+    let ids = with_mocked_endpoint("/v2/itemstats", |client| {
+        client.get_ids::<ItemStat>().unwrap()
+    });
+    assert_eq!(expected, ids);
+}
+
+// v2/colors.rs
+#[test]
+fn get_color_ids() {
+    let expected = mock_endpoint::<Vec<u32>>("/v2/colors");
+    let ids = with_mocked_endpoint("/v2/colors", |client| client.get_ids::<Color>().unwrap());
+    assert_eq!(expected, ids);
+}
+
+// v2/emblem.rs
+#[test]
+fn get_emblem_ids() {
+    let expected = mock_endpoint::<Vec<u32>>("/v2/emblem/backgrounds");
+    let ids = with_mocked_endpoint("/v2/emblem/backgrounds", |client| {
+        client.get_ids::<EmblemBackground>().unwrap()
+    });
+    assert_eq!(expected, ids);
+
+    let expected = mock_endpoint::<Vec<u32>>("/v2/emblem/foregrounds");
+    let ids = with_mocked_endpoint("/v2/emblem/foregrounds", |client| {
+        client.get_ids::<EmblemForeground>().unwrap()
+    });
+    assert_eq!(expected, ids);
+}
+
+// v2/wvw
+#[test]
+fn get_wvw_ids() {
+    let expected = mock_endpoint::<Vec<u32>>("/v2/wvw/abilities");
+    let ids = with_mocked_endpoint("/v2/wvw/abilities", |client| {
+        client.get_ids::<Ability>().unwrap()
+    });
+    assert_eq!(expected, ids);
+
+    let expected = mock_endpoint::<Vec<String>>("/v2/wvw/matches");
+    let ids = with_mocked_endpoint("/v2/wvw/matches", |client| client.get_ids::<Match>().unwrap());
+    assert_eq!(expected, ids);
+
+    let expected = mock_endpoint::<Vec<String>>("/v2/wvw/objectives");
+    let ids = with_mocked_endpoint("/v2/wvw/objectives", |client| {
+        client.get_ids::<Objective>().unwrap()
+    });
+    assert_eq!(expected, ids);
+
+    let expected = mock_endpoint::<Vec<u32>>("/v2/wvw/ranks");
+    let ids = with_mocked_endpoint("/v2/wvw/ranks", |client| client.get_ids::<WvwRank>().unwrap());
+    assert_eq!(expected, ids);
+
+    let expected = mock_endpoint::<Vec<u32>>("/v2/wvw/upgrades");
+    let ids = with_mocked_endpoint("/v2/wvw/upgrades", |client| {
+        client.get_ids::<Upgrade>().unwrap()
+    });
+    assert_eq!(expected, ids);
+}
+
+#[test]
+fn get_wvw_match_stats_teams() {
+    let teams = with_mocked_endpoint("/v2/wvw/matches/stats/1-1/teams", |client| {
+        MatchStats::get_teams(client, "1-1").unwrap()
+    });
+    assert_eq!(vec![Team::Red, Team::Blue, Team::Green], teams);
+}
+
+#[test]
+fn get_wvw_match_by_world() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v2/wvw/matches/stats").query_param("world", "1003");
+        then.status(200).body(r#"{
+            "id": "1-1",
+            "deaths": { "red": 800, "blue": 900, "green": 1000 },
+            "kills": { "red": 1000, "blue": 850, "green": 950 },
+            "maps": []
+        }"#);
+    });
+    let client = Client::new().set_base_url(server.base_url());
+    let stats = MatchStats::get_by_world(&client, 1003).unwrap();
+    m.assert();
+    assert_eq!("1-1", stats.id);
+    assert_eq!(Some(1.25), stats.kill_death_ratio(Team::Red));
+}
+
+// emblem.rs
+#[cfg(feature = "emblem")]
+#[test]
+fn render_guild_emblem() {
+    use gw2api::v1::colors::Colors;
+    use gw2api::v1::guild_details::{Emblem, Flag};
+    use image::{ImageFormat, Rgba, RgbaImage};
+    use std::io::Cursor;
+
+    let png = |pixel: [u8; 4]| {
+        let mut bytes = Vec::new();
+        RgbaImage::from_pixel(8, 8, Rgba(pixel))
+            .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+            .unwrap();
+        bytes
+    };
+    let server = MockServer::start();
+    let parts = [
+        server.mock(|when, then| {
+            when.path("/v2/emblem/backgrounds").query_param("id", "27");
+            then.status(200).body(r#"{
+                "id": 27,
+                "layers": ["https://render.guildwars2.com/file/BG/59597.png"]
+            }"#);
+        }),
+        server.mock(|when, then| {
+            when.path("/v2/emblem/foregrounds").query_param("id", "114");
+            then.status(200).body(r#"{
+                "id": 114,
+                "layers": [
+                    "https://render.guildwars2.com/file/FG/59575.png",
+                    "https://render.guildwars2.com/file/FG/59577.png",
+                    "https://render.guildwars2.com/file/FG/59579.png"
+                ]
+            }"#);
+        }),
+    ];
+    let layers = [
+        server.mock(|when, then| {
+            when.path("/file/BG/59597.png");
+            then.status(200).body(png([255, 255, 255, 255]));
+        }),
+        server.mock(|when, then| {
+            when.path("/file/FG/59577.png");
+            then.status(200).body(png([255, 255, 255, 0]));
+        }),
+        server.mock(|when, then| {
+            when.path("/file/FG/59579.png");
+            then.status(200).body(png([255, 255, 255, 255]));
+        }),
+    ];
+    let client = Client::new()
+        .set_base_url(server.base_url())
+        .set_render_url(server.base_url());
+    let colors: Colors =
+        serde_json::from_str(&std::fs::read_to_string("./tests/json/v1/colors.json").unwrap())
+            .unwrap();
+    let emblem = Emblem {
+        background_id: 27,
+        foreground_id: 114,
+        flags: vec![Flag::FlipBackgroundVertical],
+        background_color_id: 11,
+        foreground_primary_color_id: 584,
+        foreground_secondary_color_id: 64,
+    };
+
+    let image = gw2api::emblem::render(&client, &emblem, &colors).unwrap();
+    for m in parts.iter().chain(&layers) {
+        m.assert();
+    }
+    assert_eq!((8, 8), image.dimensions());
+    // The primary layer is transparent, so the secondary color covers the background.
+    assert_eq!(&Rgba([113, 73, 16, 255]), image.get_pixel(4, 4));
+}
+
+// v2/recipes/search.rs
+#[test]
+fn search_recipes_without_item() {
+    let server = MockServer::start();
+    let m = server.mock(|when, then| {
+        when.path("/v2/recipes/search").query_param("input", "0");
+        then.status(400).body_from_file("./tests/json/v2/recipes/search.json");
+    });
+    let client = Client::new().set_base_url(server.base_url());
+
+    let err = RecipeSearch::by_input(&client, 0).unwrap_err();
+    m.assert();
+    assert!(matches!(err.kind(), ApiErrorKind::BadRequest(_)), "{}", err);
+}
+
+// crafting.rs
+#[test]
+fn load_recipe_tree() {
+    let server = MockServer::start();
+    let search = |item_id: &str, body: &'static str| server.mock(|when, then| {
+        when.path("/v2/recipes/search").query_param("output", item_id);
+        then.status(200).body(body);
+    });
+    let searches = [search("19684", "[19]"), search("19700", "[]")];
+    let recipe = server.mock(|when, then| {
+        when.path("/v1/recipe_details").query_param("recipe_id", "19");
+        then.status(200).body(r#"{
+            "recipe_id": "19", "type": "Refinement", "output_item_id": "19684",
+            "output_item_count": "1", "min_rating": "225", "time_to_craft_ms": "1000",
+            "ingredients": [{ "item_id": "19700", "count": "2" }]
+        }"#);
+    });
+    let client = Client::new().set_base_url(server.base_url());
+
+    let mut book = RecipeBook::new();
+    book.load(&client, 19684).unwrap();
+    // Everything is known now, so loading again doesn't send any requests.
+    book.load(&client, 19684).unwrap();
+    for m in searches {
+        m.assert();
+    }
+    recipe.assert();
+
+    let tree = book.tree(19684, 3);
+    assert_eq!(Some(19), tree.recipe_id);
+    assert_eq!(vec![(19700, 6)], tree.raw_materials().into_iter().collect::<Vec<_>>());
+}